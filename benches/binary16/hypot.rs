@@ -0,0 +1,28 @@
+use crate::Narrow;
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement, T: Narrow>(
+    group: &mut BenchmarkGroup<M>,
+    name: &str,
+    f: impl Fn(T, T) -> T,
+) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || (T::narrow(rng.gen()), T::narrow(rng.gen())),
+            |(x, y)| f(x, y),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_hypot(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("binary16::hypot");
+    crate::bench!(bench, &mut group, metallic::f16::hypot);
+    crate::bench!(bench, &mut group, metallic::bf16::hypot);
+}
+
+criterion::criterion_group!(benches, bench_hypot);
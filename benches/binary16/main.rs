@@ -0,0 +1,47 @@
+mod cos;
+mod erf;
+mod exp;
+mod hypot;
+mod ln;
+mod log2;
+mod rsqrt;
+mod sin;
+mod sqrt;
+mod tanh;
+
+criterion::criterion_main!(
+    cos::benches,
+    erf::benches,
+    exp::benches,
+    hypot::benches,
+    ln::benches,
+    log2::benches,
+    rsqrt::benches,
+    sin::benches,
+    sqrt::benches,
+    tanh::benches,
+);
+
+#[macro_export]
+macro_rules! bench {
+    ($bench:expr, $criterion:expr, $f:expr) => {
+        $bench($criterion, stringify!($f), $f);
+    };
+}
+
+/// Rounding from `f32` to a 16-bit type
+trait Narrow {
+    fn narrow(x: f32) -> Self;
+}
+
+impl Narrow for half::f16 {
+    fn narrow(x: f32) -> Self {
+        Self::from_f32(x)
+    }
+}
+
+impl Narrow for half::bf16 {
+    fn narrow(x: f32) -> Self {
+        Self::from_f32(x)
+    }
+}
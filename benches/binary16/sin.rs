@@ -0,0 +1,22 @@
+use crate::Narrow;
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement, T: Narrow>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(T) -> T) {
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || T::narrow(rand::thread_rng().gen()),
+            &f,
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_sin(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("binary16::sin");
+    crate::bench!(bench, &mut group, metallic::f16::sin);
+    crate::bench!(bench, &mut group, metallic::bf16::sin);
+}
+
+criterion::criterion_group!(benches, bench_sin);
@@ -0,0 +1,27 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use metallic::Complex;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(
+    group: &mut BenchmarkGroup<M>,
+    name: &str,
+    f: impl Fn(Complex<f32>) -> f32,
+) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || Complex::new(rng.gen_range(-10.0..10.0), rng.gen_range(-10.0..10.0)),
+            &f,
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_abs(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("c32::abs");
+    crate::bench!(bench, &mut group, metallic::c32::abs);
+}
+
+criterion::criterion_group!(benches, bench_abs);
@@ -0,0 +1,50 @@
+mod abs;
+mod acos;
+mod acosh;
+mod arg;
+mod asin;
+mod asinh;
+mod atan;
+mod atanh;
+mod conj;
+mod cos;
+mod cosh;
+mod exp;
+mod ln;
+mod pow;
+mod proj;
+mod sin;
+mod sinh;
+mod sqrt;
+mod tan;
+mod tanh;
+
+criterion::criterion_main!(
+    abs::benches,
+    acos::benches,
+    acosh::benches,
+    arg::benches,
+    asin::benches,
+    asinh::benches,
+    atan::benches,
+    atanh::benches,
+    conj::benches,
+    cos::benches,
+    cosh::benches,
+    exp::benches,
+    ln::benches,
+    pow::benches,
+    proj::benches,
+    sin::benches,
+    sinh::benches,
+    sqrt::benches,
+    tan::benches,
+    tanh::benches,
+);
+
+#[macro_export]
+macro_rules! bench {
+    ($bench:expr, $criterion:expr, $f:expr) => {
+        $bench($criterion, stringify!($f), $f);
+    };
+}
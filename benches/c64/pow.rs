@@ -0,0 +1,31 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use metallic::Complex;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(
+    group: &mut BenchmarkGroup<M>,
+    name: &str,
+    f: impl Fn(Complex<f64>, Complex<f64>) -> Complex<f64>,
+) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || {
+                let z = Complex::new(rng.gen_range(-10.0..10.0), rng.gen_range(-10.0..10.0));
+                let w = Complex::new(rng.gen_range(-2.0..2.0), rng.gen_range(-2.0..2.0));
+                (z, w)
+            },
+            |(z, w)| f(z, w),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_pow(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("c64::pow");
+    crate::bench!(bench, &mut group, metallic::c64::pow);
+}
+
+criterion::criterion_group!(benches, bench_pow);
@@ -0,0 +1,21 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use metallic::f128::F128;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(F128) -> F128) {
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || F128::from(rand::thread_rng().gen_range(-10.0..10.0)),
+            &f,
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_cos(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f128::cos");
+    crate::bench!(bench, &mut group, metallic::f128::cos);
+}
+
+criterion::criterion_group!(benches, bench_cos);
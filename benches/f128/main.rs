@@ -0,0 +1,20 @@
+mod cos;
+mod exp;
+mod ln;
+mod pow;
+mod sin;
+
+criterion::criterion_main!(
+    cos::benches,
+    exp::benches,
+    ln::benches,
+    pow::benches,
+    sin::benches,
+);
+
+#[macro_export]
+macro_rules! bench {
+    ($bench:expr, $criterion:expr, $f:expr) => {
+        $bench($criterion, stringify!($f), $f);
+    };
+}
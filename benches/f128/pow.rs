@@ -0,0 +1,31 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use metallic::f128::F128;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(
+    group: &mut BenchmarkGroup<M>,
+    name: &str,
+    f: impl Fn(F128, F128) -> F128,
+) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || {
+                let x = F128::from(rng.gen_range(0.0..10.0));
+                let y = F128::from(rng.gen_range(-10.0..10.0));
+                (x, y)
+            },
+            |(x, y)| f(x, y),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_pow(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f128::pow");
+    crate::bench!(bench, &mut group, metallic::f128::pow);
+}
+
+criterion::criterion_group!(benches, bench_pow);
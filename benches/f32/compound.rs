@@ -0,0 +1,28 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(f32, i64) -> f32) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || (rng.gen_range(-0.5..0.5), rng.gen_range(-1000..1000)),
+            |(x, n)| f(x, n),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+/// [`core_math::powf`] with a rounded base
+fn powf(x: f32, n: i64) -> f32 {
+    core_math::powf(1.0 + x, n as f32)
+}
+
+fn bench_compound(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f32::compound");
+    crate::bench!(bench, &mut group, metallic::f32::compound);
+    crate::bench!(bench, &mut group, powf);
+}
+
+criterion::criterion_group!(benches, bench_compound);
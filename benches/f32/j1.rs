@@ -0,0 +1,21 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(f32) -> f32) {
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || rand::thread_rng().gen_range(-100.0..100.0),
+            &f,
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_j1(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f32::j1");
+    crate::bench!(bench, &mut group, metallic::f32::j1);
+    crate::bench!(bench, &mut group, libm::j1f);
+}
+
+criterion::criterion_group!(benches, bench_j1);
@@ -1,61 +1,131 @@
 mod acos;
 mod acosh;
+mod acospi;
 mod asin;
 mod asinh;
+mod asinpi;
 mod atan;
+mod atan2;
+mod atan2pi;
 mod atanh;
+mod atanpi;
 mod cbrt;
+mod compound;
 mod cos;
 mod cosh;
+mod cospi;
+mod erf;
+mod erfc;
+mod erfcx;
 mod exp;
 mod exp10;
+mod exp10m1;
 mod exp2;
+mod exp2m1;
 mod exp_m1;
+mod fmod;
 mod frexp;
 mod hypot;
+mod ilogb;
+mod j0;
+mod j1;
+mod jn;
 mod ldexp;
+mod lgamma;
 mod ln;
 mod ln_1p;
 mod log;
 mod log10;
+mod log10p1;
 mod log2;
+mod log2p1;
+mod modf;
+mod nextafter;
 mod powf;
+mod pown;
+mod powr;
+mod rcbrt;
+mod remainder;
+mod remquo;
+mod rootn;
 mod round;
+mod rsqrt;
 mod sin;
 mod sin_cos;
 mod sinh;
+mod sinpi;
 mod tan;
 mod tanh;
+mod tanpi;
+mod tgamma;
+mod y0;
+mod y1;
+mod yn;
 
 criterion::criterion_main!(
     acos::benches,
     acosh::benches,
+    acospi::benches,
     asin::benches,
     asinh::benches,
+    asinpi::benches,
     atan::benches,
+    atan2::benches,
+    atan2pi::benches,
     atanh::benches,
+    atanpi::benches,
     cbrt::benches,
+    compound::benches,
     cos::benches,
     cosh::benches,
+    cospi::benches,
+    erf::benches,
+    erfc::benches,
+    erfcx::benches,
     exp::benches,
     exp2::benches,
+    exp2m1::benches,
     exp10::benches,
+    exp10m1::benches,
     exp_m1::benches,
+    fmod::benches,
     frexp::benches,
     hypot::benches,
+    ilogb::benches,
+    j0::benches,
+    j1::benches,
+    jn::benches,
     ldexp::benches,
+    lgamma::benches,
     ln::benches,
     ln_1p::benches,
     log::benches,
     log10::benches,
+    log10p1::benches,
     log2::benches,
+    log2p1::benches,
+    modf::benches,
+    nextafter::benches,
     powf::benches,
+    pown::benches,
+    powr::benches,
+    rcbrt::benches,
+    remainder::benches,
+    remquo::benches,
+    rootn::benches,
     round::benches,
+    rsqrt::benches,
     sin::benches,
     sin_cos::benches,
     sinh::benches,
+    sinpi::benches,
     tan::benches,
     tanh::benches,
+    tanpi::benches,
+    tgamma::benches,
+    y0::benches,
+    y1::benches,
+    yn::benches,
 );
 
 #[macro_export]
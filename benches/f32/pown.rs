@@ -0,0 +1,28 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(f32, i64) -> f32) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || (rng.gen_range(-10.0..10.0), rng.gen_range(-20..20)),
+            |(x, n)| f(x, n),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+/// [`core_math::powf`] with an integer exponent
+fn powf(x: f32, n: i64) -> f32 {
+    core_math::powf(x, n as f32)
+}
+
+fn bench_pown(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f32::pown");
+    crate::bench!(bench, &mut group, metallic::f32::pown);
+    crate::bench!(bench, &mut group, powf);
+}
+
+criterion::criterion_group!(benches, bench_pown);
@@ -0,0 +1,26 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(f32) -> f32) {
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || rand::thread_rng().gen(),
+            &f,
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+/// The naive form rounding twice
+fn naive(x: f32) -> f32 {
+    1.0 / x.cbrt()
+}
+
+fn bench_rcbrt(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f32::rcbrt");
+    crate::bench!(bench, &mut group, metallic::f32::rcbrt);
+    crate::bench!(bench, &mut group, naive);
+}
+
+criterion::criterion_group!(benches, bench_rcbrt);
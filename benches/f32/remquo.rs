@@ -0,0 +1,27 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(
+    group: &mut BenchmarkGroup<M>,
+    name: &str,
+    f: impl Fn(f32, f32) -> (f32, i32),
+) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || (rng.gen(), rng.gen()),
+            |(x, y)| f(x, y),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_remquo(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f32::remquo");
+    crate::bench!(bench, &mut group, metallic::f32::remquo);
+    crate::bench!(bench, &mut group, libm::remquof);
+}
+
+criterion::criterion_group!(benches, bench_remquo);
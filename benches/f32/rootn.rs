@@ -0,0 +1,28 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(f32, i64) -> f32) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || (rng.gen_range(-100.0..100.0), rng.gen_range(-20..20)),
+            |(x, n)| f(x, n),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+/// [`core_math::powf`] with the reciprocal of an integer as the exponent
+fn powf(x: f32, n: i64) -> f32 {
+    core_math::powf(x, 1.0 / n as f32)
+}
+
+fn bench_rootn(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f32::rootn");
+    crate::bench!(bench, &mut group, metallic::f32::rootn);
+    crate::bench!(bench, &mut group, powf);
+}
+
+criterion::criterion_group!(benches, bench_rootn);
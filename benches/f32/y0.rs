@@ -0,0 +1,21 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(f32) -> f32) {
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || rand::thread_rng().gen_range(0.0..100.0),
+            &f,
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_y0(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f32::y0");
+    crate::bench!(bench, &mut group, metallic::f32::y0);
+    crate::bench!(bench, &mut group, libm::y0f);
+}
+
+criterion::criterion_group!(benches, bench_y0);
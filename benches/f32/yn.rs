@@ -0,0 +1,23 @@
+use criterion::measurement::Measurement;
+use criterion::BenchmarkGroup;
+use rand::Rng as _;
+
+fn bench<M: Measurement>(group: &mut BenchmarkGroup<M>, name: &str, f: impl Fn(i32, f32) -> f32) {
+    let rng = &mut rand::thread_rng();
+
+    group.bench_function(name, |bencher| {
+        bencher.iter_batched(
+            || (rng.gen_range(-20..20), rng.gen_range(0.0..100.0)),
+            |(n, x)| f(n, x),
+            criterion::BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_yn(criterion: &mut criterion::Criterion) {
+    let mut group = criterion.benchmark_group("f32::yn");
+    crate::bench!(bench, &mut group, metallic::f32::yn);
+    crate::bench!(bench, &mut group, libm::ynf);
+}
+
+criterion::criterion_group!(benches, bench_yn);
@@ -0,0 +1,155 @@
+/// Round `f64` to `f32` with round-to-odd
+///
+/// An inexact result has an odd significand, which keeps the sticky bit.
+/// Rounding it again to a format with at most 22 significant bits is then as
+/// correct as rounding the `f64` directly.
+#[inline]
+pub fn round_to_odd(x: f64) -> f32 {
+    let y = x as f32;
+    let bits = y.to_bits();
+
+    if x.is_nan() || f64::from(y) == x || bits & 1 == 1 {
+        return y;
+    }
+
+    // Step towards `x` to the odd neighbor
+    if f64::from(y).abs() > x.abs() {
+        f32::from_bits(bits - 1)
+    } else {
+        f32::from_bits(bits + 1)
+    }
+}
+
+/// Define correctly rounded functions for a 16-bit type from [`half`]
+///
+/// The functions are evaluated in `f32` and rounded to `$t`, which has at
+/// most 11 significant bits.  [`half::f16`] and [`half::bf16`] share the
+/// same implementation this way.
+macro_rules! functions {
+    ($t:ident) => {
+        use half::$t;
+
+        #[doc = concat!("Round a correctly rounded `f32` result to `", stringify!($t), "`")]
+        ///
+        /// Rounding twice is correct unless the `f32` is a tie between two
+        /// 16-bit values.  In that case, the result is recomputed by
+        /// `accurate` in `f64` and rounded to odd before the final rounding.
+        /// Neighbors of a tie round to different 16-bit values, which is how
+        /// the tie is detected.
+        #[inline]
+        fn round(y: f32, accurate: impl FnOnce() -> f64) -> $t {
+            let lower = $t::from_f32(crate::f32::next_down(y));
+            let upper = $t::from_f32(crate::f32::next_up(y));
+
+            if y.is_nan() || lower == upper {
+                $t::from_f32(y)
+            } else {
+                $t::from_f32(crate::binary16::round_to_odd(accurate()))
+            }
+        }
+
+        /// The exponential function
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn exp(x: $t) -> $t {
+            let x = x.to_f32();
+            round(crate::f32::exp(x), || crate::f64::exp(x.into()))
+        }
+
+        /// Natural logarithm
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn ln(x: $t) -> $t {
+            let x = x.to_f32();
+            round(crate::f32::ln(x), || crate::f64::ln(x.into()))
+        }
+
+        /// Base 2 logarithm
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn log2(x: $t) -> $t {
+            let x = x.to_f32();
+            round(crate::f32::log2(x), || crate::f64::log2(x.into()))
+        }
+
+        /// Hyperbolic tangent
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn tanh(x: $t) -> $t {
+            let x = x.to_f32();
+            round(crate::f32::tanh(x), || crate::f64::tanh(x.into()))
+        }
+
+        /// Sine
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn sin(x: $t) -> $t {
+            let x = x.to_f32();
+            round(crate::f32::sin(x), || crate::f64::sin(x.into()))
+        }
+
+        /// Cosine
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn cos(x: $t) -> $t {
+            let x = x.to_f32();
+            round(crate::f32::cos(x), || crate::f64::cos(x.into()))
+        }
+
+        /// Error function
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn erf(x: $t) -> $t {
+            let x = x.to_f32();
+            round(crate::f32::erf(x), || crate::f32::erf_wide(x))
+        }
+
+        /// Square root
+        ///
+        /// The result is correctly rounded.  Rounding twice is harmless here
+        /// because `f32` has more than twice as many significant bits.
+        #[must_use]
+        #[inline]
+        pub fn sqrt(x: $t) -> $t {
+            $t::from_f32(x.to_f32().sqrt())
+        }
+
+        /// Reciprocal of the square root
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn rsqrt(x: $t) -> $t {
+            let x = x.to_f32();
+            round(crate::f32::rsqrt(x), || 1.0 / f64::from(x).sqrt())
+        }
+
+        /// Hypotenuse of a right-angled triangle with sides `x` and `y`
+        ///
+        /// The result is correctly rounded.
+        #[must_use]
+        #[inline]
+        pub fn hypot(x: $t, y: $t) -> $t {
+            let (x, y) = (x.to_f32(), y.to_f32());
+            round(crate::f32::hypot(x, y), || {
+                crate::f64::hypot(x.into(), y.into())
+            })
+        }
+    };
+}
+
+pub(crate) use functions;
@@ -0,0 +1,460 @@
+use crate::Complex;
+
+/// Complex exponential of `x + iy` in `f64`
+///
+/// Special cases follow Annex G of the C standard.  Overflow and underflow are
+/// left to the final conversion to `f32`.
+fn exp_f64(x: f64, y: f64) -> (f64, f64) {
+    if y == 0.0 {
+        return (crate::f64::exp(x), y);
+    }
+
+    if x.is_infinite() && !y.is_finite() {
+        return if x < 0.0 {
+            (0.0, 0.0_f64.copysign(y))
+        } else {
+            (x, f64::NAN)
+        };
+    }
+
+    if !y.is_finite() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let (sin, cos) = crate::f64::sin_cos(y);
+    let r = crate::f64::exp(x);
+    (r * cos, r * sin)
+}
+
+/// ln |x + iy| in `f64`
+///
+/// The result is infinite if either part is infinite, even if the other part
+/// is NaN.
+fn ln_abs(x: f32, y: f32) -> f64 {
+    if x.is_infinite() || y.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    // Squares of `f32` are exact in `f64`.
+    let (x, y) = (x.abs().max(y.abs()), x.abs().min(y.abs()));
+    let (x, y) = (f64::from(x), f64::from(y));
+    let s = x * x + y * y;
+
+    if s < 0.5 {
+        return 0.5 * crate::f64::ln(s);
+    }
+
+    // Since x^2 >= 1/4, x^2 - 1 is exact.  Cancellation near the unit circle
+    // is then left to a single rounding.
+    0.5 * crate::f64::ln_1p((x * x - 1.0) + y * y)
+}
+
+/// Principal square root of finite `x + iy` in `f64`
+fn sqrt_f64(x: f64, y: f64) -> (f64, f64) {
+    if x == 0.0 && y == 0.0 {
+        return (0.0, y);
+    }
+
+    // The sum has no cancellation, and the division has no overflow.
+    let t = (0.5 * (x.abs() + crate::f64::hypot(x, y))).sqrt();
+    let u = y.abs() / (2.0 * t);
+
+    if x.is_sign_positive() {
+        (t, u.copysign(y))
+    } else {
+        (u, t.copysign(y))
+    }
+}
+
+/// Absolute value (`cabsf` in C)
+///
+/// This function is correctly rounded as [`crate::f32::hypot`].
+#[must_use]
+#[inline]
+pub fn abs(z: Complex<f32>) -> f32 {
+    crate::f32::hypot(z.re, z.im)
+}
+
+/// Argument in `-π..=π` (`cargf` in C)
+///
+/// This function is correctly rounded as [`crate::f32::atan2`].  The branch
+/// cut is on the negative real axis, where the sign of the imaginary part
+/// decides between `±π`.
+#[must_use]
+#[inline]
+pub fn arg(z: Complex<f32>) -> f32 {
+    crate::f32::atan2(z.im, z.re)
+}
+
+/// Exponential function (`cexpf` in C)
+///
+/// Both parts are evaluated in `f64` before rounding to `f32`.  Special cases
+/// follow Annex G of the C standard, e.g. `exp(-∞ ± i∞) = 0 ± i0` and
+/// `exp(+∞ + i∞) = ∞ + iNaN`.
+#[must_use]
+#[inline]
+pub fn exp(z: Complex<f32>) -> Complex<f32> {
+    let (re, im) = exp_f64(z.re.into(), z.im.into());
+    Complex::new(re as f32, im as f32)
+}
+
+/// Natural logarithm (`clogf` in C)
+///
+/// The imaginary part is [`arg`], so the branch cut is on the negative real
+/// axis.  The real part is accurate even near the unit circle.  Special cases
+/// follow Annex G of the C standard, e.g. `ln(-0 + i0) = -∞ + iπ`.
+#[must_use]
+#[inline]
+pub fn ln(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(ln_abs(z.re, z.im) as f32, arg(z))
+}
+
+/// Square root (`csqrtf` in C)
+///
+/// The result is in the right half-plane, and the branch cut is on the
+/// negative real axis.  Special cases follow Annex G of the C standard, e.g.
+/// `sqrt(x + i∞) = ∞ + i∞` even if `x` is NaN.
+#[must_use]
+#[inline]
+pub fn sqrt(z: Complex<f32>) -> Complex<f32> {
+    let (x, y) = (z.re, z.im);
+
+    if y.is_infinite() {
+        return Complex::new(f32::INFINITY, y);
+    }
+
+    if x.is_infinite() {
+        let d = if y.is_nan() { y } else { 0.0 };
+
+        return if x > 0.0 {
+            Complex::new(x, d.copysign(y))
+        } else {
+            Complex::new(d.abs(), f32::INFINITY.copysign(y))
+        };
+    }
+
+    let (re, im) = sqrt_f64(x.into(), y.into());
+    Complex::new(re as f32, im as f32)
+}
+
+/// Complex power `z` raised to `w` (`cpowf` in C)
+///
+/// This function computes `exp(w ln z)` in `f64`.  The result is accurate
+/// unless the imaginary part of `w ln z` is huge, but it is not exact for
+/// exact powers, e.g. `pow(i, 2)` has a tiny nonzero imaginary part.  As with
+/// real powers, `pow(z, 0) = 1` for any `z`, including NaN.
+#[must_use]
+#[inline]
+pub fn pow(z: Complex<f32>, w: Complex<f32>) -> Complex<f32> {
+    if w.re == 0.0 && w.im == 0.0 {
+        return Complex::new(1.0, 0.0);
+    }
+
+    let u = ln_abs(z.re, z.im);
+    let v = crate::f64::atan2(z.im.into(), z.re.into());
+    let (a, b) = (f64::from(w.re), f64::from(w.im));
+
+    // Skip the imaginary part of `w` if it is zero, so that it does not turn
+    // `ln 0 = -∞` into NaN.
+    let (re, im) = if b == 0.0 {
+        exp_f64(a * u, a * v)
+    } else {
+        exp_f64(a * u - b * v, a * v + b * u)
+    };
+
+    Complex::new(re as f32, im as f32)
+}
+
+/// Multiply by the imaginary unit
+const fn mul_i(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(-z.im, z.re)
+}
+
+/// Divide by the imaginary unit
+const fn div_i(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(z.im, -z.re)
+}
+
+/// Hyperbolic sine (`csinhf` in C)
+///
+/// Both parts are evaluated in `f64` before rounding to `f32`.  Special cases
+/// follow Annex G of the C standard, e.g. `sinh(+0 + i∞) = 0 + iNaN`.
+#[must_use]
+#[inline]
+pub fn sinh(z: Complex<f32>) -> Complex<f32> {
+    let (x, y) = (z.re, z.im);
+
+    if y == 0.0 {
+        return Complex::new(crate::f32::sinh(x), y);
+    }
+
+    if x.is_infinite() && !y.is_finite() {
+        return Complex::new(x, f32::NAN);
+    }
+
+    let (x, y) = (f64::from(x), f64::from(y));
+    let (sin, cos) = crate::f64::sin_cos(y);
+
+    let re = if x == 0.0 && !y.is_finite() {
+        x
+    } else {
+        crate::f64::sinh(x) * cos
+    };
+
+    Complex::new(re as f32, (crate::f64::cosh(x) * sin) as f32)
+}
+
+/// Hyperbolic cosine (`ccoshf` in C)
+///
+/// Both parts are evaluated in `f64` before rounding to `f32`.  Special cases
+/// follow Annex G of the C standard, e.g. `cosh(+0 + i∞) = NaN + i0`.
+#[must_use]
+#[inline]
+pub fn cosh(z: Complex<f32>) -> Complex<f32> {
+    let (x, y) = (z.re, z.im);
+
+    if y == 0.0 {
+        return Complex::new(crate::f32::cosh(x), 0.0_f32.copysign(x) * y);
+    }
+
+    if x.is_infinite() && !y.is_finite() {
+        return Complex::new(f32::INFINITY, f32::NAN);
+    }
+
+    let (x, y) = (f64::from(x), f64::from(y));
+    let (sin, cos) = crate::f64::sin_cos(y);
+
+    let im = if x == 0.0 && !y.is_finite() {
+        x * 0.0_f64.copysign(y)
+    } else {
+        crate::f64::sinh(x) * sin
+    };
+
+    Complex::new((crate::f64::cosh(x) * cos) as f32, im as f32)
+}
+
+/// Hyperbolic tangent (`ctanhf` in C)
+///
+/// This function uses Kahan's formula, which avoids cancellation near the
+/// poles.  Special cases follow Annex G of the C standard, e.g.
+/// `tanh(+∞ + iy) = 1 + i0 sin 2y`.
+#[must_use]
+#[inline]
+pub fn tanh(z: Complex<f32>) -> Complex<f32> {
+    let (x, y) = (z.re, z.im);
+
+    if y == 0.0 {
+        return Complex::new(crate::f32::tanh(x), y);
+    }
+
+    if x.is_infinite() {
+        let im = if y.is_finite() {
+            let (sin, cos) = crate::f64::sin_cos(y.into());
+            0.0_f32.copysign((sin * cos) as f32)
+        } else {
+            0.0_f32.copysign(y)
+        };
+        return Complex::new(1.0_f32.copysign(x), im);
+    }
+
+    if !y.is_finite() {
+        return Complex::new(if x == 0.0 { x } else { f32::NAN }, f32::NAN);
+    }
+
+    let (x, y) = (f64::from(x), f64::from(y));
+
+    // tanh(x) rounds to ±1 in `f64`.
+    if x.abs() > 22.0 {
+        let (sin, cos) = crate::f64::sin_cos(y);
+        let im = 4.0 * sin * cos * crate::f64::exp(-2.0 * x.abs());
+        return Complex::new(1.0_f32.copysign(x as f32), im as f32);
+    }
+
+    let t = crate::f64::tan(y);
+    let b = crate::mul_add(t, t, 1.0);
+    let s = crate::f64::sinh(x);
+    let r = crate::mul_add(s, s, 1.0).sqrt();
+    let d = crate::mul_add(b * s, s, 1.0);
+
+    Complex::new((b * r * s / d) as f32, (t / d) as f32)
+}
+
+/// Sine (`csinf` in C)
+///
+/// This function is defined as `-i sinh(iz)` by the C standard.
+#[must_use]
+#[inline]
+pub fn sin(z: Complex<f32>) -> Complex<f32> {
+    div_i(sinh(mul_i(z)))
+}
+
+/// Cosine (`ccosf` in C)
+///
+/// This function is defined as `cosh(iz)` by the C standard.
+#[must_use]
+#[inline]
+pub fn cos(z: Complex<f32>) -> Complex<f32> {
+    cosh(mul_i(z))
+}
+
+/// Tangent (`ctanf` in C)
+///
+/// This function is defined as `-i tanh(iz)` by the C standard.
+#[must_use]
+#[inline]
+pub fn tan(z: Complex<f32>) -> Complex<f32> {
+    div_i(tanh(mul_i(z)))
+}
+
+/// Arcsine of finite `x + iy` in `f64` by Kahan's formula
+///
+/// The square roots are chosen to avoid cancellation, so the result is
+/// accurate even near the branch cuts.
+fn asin_f64(x: f64, y: f64) -> (f64, f64) {
+    let (a, b) = sqrt_f64(1.0 - x, -y);
+    let (c, d) = sqrt_f64(1.0 + x, y);
+    let re = crate::f64::atan2(x, a * c - b * d);
+    let im = crate::f64::asinh(a * d - b * c);
+    (re, im)
+}
+
+/// Inverse hyperbolic sine (`casinhf` in C)
+///
+/// The branch cuts are on the imaginary axis outside `-i..=i`.  Special
+/// cases follow Annex G of the C standard, e.g. `asinh(+∞ + i∞) = ∞ + iπ/4`.
+#[must_use]
+#[inline]
+pub fn asinh(z: Complex<f32>) -> Complex<f32> {
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+    let (x, y) = (z.re, z.im);
+
+    if x.is_infinite() || y.is_infinite() {
+        let im = match (x.is_nan() || y.is_nan(), x.is_infinite(), y.is_infinite()) {
+            (true, _, _) => f32::NAN,
+            (false, true, true) => FRAC_PI_4,
+            (false, false, true) => FRAC_PI_2,
+            (false, _, false) => 0.0,
+        };
+        return Complex::new(f32::INFINITY.copysign(x), im.copysign(y));
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return Complex::new(f32::NAN, if y == 0.0 { y } else { f32::NAN });
+    }
+
+    // asinh(z) = i asin(-iz)
+    let (re, im) = asin_f64(y.into(), (-x).into());
+    Complex::new(-im as f32, re as f32)
+}
+
+/// Arcsine (`casinf` in C)
+///
+/// This function is defined as `-i asinh(iz)` by the C standard.  The branch
+/// cuts are on the real axis outside `-1..=1`.
+#[must_use]
+#[inline]
+pub fn asin(z: Complex<f32>) -> Complex<f32> {
+    div_i(asinh(mul_i(z)))
+}
+
+/// Arccosine (`cacosf` in C)
+///
+/// The real part is in `0..=π`, and the branch cuts are on the real axis
+/// outside `-1..=1`.  Special cases follow Annex G of the C standard, e.g.
+/// `acos(±0 + i0) = π/2 - i0`.
+#[must_use]
+#[inline]
+pub fn acos(z: Complex<f32>) -> Complex<f32> {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    let (x, y) = (z.re, z.im);
+
+    if x.is_infinite() || y.is_infinite() {
+        let re = match (x.is_nan() || y.is_nan(), y.is_infinite()) {
+            (true, _) => f64::NAN,
+            (false, true) if x.is_infinite() => FRAC_PI_2 - FRAC_PI_4.copysign(x.into()),
+            (false, true) => FRAC_PI_2,
+            (false, false) if x < 0.0 => PI,
+            (false, false) => 0.0,
+        };
+        return Complex::new(re as f32, f32::INFINITY.copysign(-y));
+    }
+
+    if x.is_nan() || y.is_nan() {
+        let re = if x == 0.0 { FRAC_PI_2 as f32 } else { f32::NAN };
+        return Complex::new(re, f32::NAN);
+    }
+
+    // Kahan's formula, see `asin_f64`
+    let (x, y) = (f64::from(x), f64::from(y));
+    let (a, b) = sqrt_f64(1.0 - x, -y);
+    let (c, d) = sqrt_f64(1.0 + x, y);
+    let re = 2.0 * crate::f64::atan2(a, c);
+    let im = crate::f64::asinh(c * b - d * a);
+    Complex::new(re as f32, im as f32)
+}
+
+/// Inverse hyperbolic cosine (`cacoshf` in C)
+///
+/// The real part is nonnegative, and the branch cut is on the real axis
+/// below 1.  This function is `±i acos(z)` with the sign chosen by the sign
+/// bit of the imaginary part of `acos(z)`, which also covers most special
+/// cases in Annex G.
+#[must_use]
+#[inline]
+pub fn acosh(z: Complex<f32>) -> Complex<f32> {
+    // acos(±0 + iNaN) = π/2 + iNaN is the only case with a finite real part
+    // but undefined sign in the imaginary part.
+    if z.re == 0.0 && z.im.is_nan() {
+        return Complex::new(f32::NAN, f32::NAN);
+    }
+
+    let w = acos(z);
+
+    if w.im.is_sign_negative() {
+        mul_i(w)
+    } else {
+        div_i(w)
+    }
+}
+
+/// Inverse hyperbolic tangent (`catanhf` in C)
+///
+/// The branch cuts are on the real axis outside `-1..=1`.  Special cases
+/// follow Annex G of the C standard, e.g. `atanh(1 + i0) = ∞ + i0`.
+#[must_use]
+#[inline]
+pub fn atanh(z: Complex<f32>) -> Complex<f32> {
+    use core::f32::consts::FRAC_PI_2;
+    let (x, y) = (z.re, z.im);
+
+    if x.is_infinite() || y.is_infinite() {
+        let im = if y.is_nan() { y } else { FRAC_PI_2.copysign(y) };
+        return Complex::new(0.0_f32.copysign(x), im);
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return Complex::new(if x == 0.0 { x } else { f32::NAN }, f32::NAN);
+    }
+
+    // The real part is odd in x, and the argument of `ln_1p` is nonnegative
+    // for nonnegative x.
+    let (a, y) = (f64::from(x.abs()), f64::from(y));
+    let re = 0.25 * crate::f64::ln_1p(4.0 * a / crate::mul_add(1.0 - a, 1.0 - a, y * y));
+    let im = 0.5 * crate::f64::atan2(2.0 * y, crate::mul_add(1.0 - a, 1.0 + a, -y * y));
+
+    Complex::new((re as f32).copysign(x), im as f32)
+}
+
+/// Arctangent (`catanf` in C)
+///
+/// This function is defined as `-i atanh(iz)` by the C standard.  The branch
+/// cuts are on the imaginary axis outside `-i..=i`.
+#[must_use]
+#[inline]
+pub fn atan(z: Complex<f32>) -> Complex<f32> {
+    div_i(atanh(mul_i(z)))
+}
@@ -0,0 +1,507 @@
+use crate::Complex;
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LN_2, PI};
+
+/// Threshold beyond which `|z|^2` dominates 1 in `f64`
+///
+/// Inverse trigonometric and hyperbolic functions switch to their asymptotic
+/// expansions above this threshold, where the next terms are below 2^-56
+/// relative to the result.
+const HUGE: f64 = 268_435_456.0;
+
+/// Multiply by the imaginary unit
+const fn mul_i(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(-z.im, z.re)
+}
+
+/// Divide by the imaginary unit
+const fn div_i(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(z.im, -z.re)
+}
+
+/// `exp(|x|) / 2 * t` without overflow in `exp(|x|)`
+///
+/// This function is used for `sinh` and `cosh` of a huge `|x|`.
+fn half_exp_mul(x: f64, t: f64) -> f64 {
+    let h = crate::f64::exp(0.5 * x.abs());
+    h * (0.5 * t) * h
+}
+
+/// Complex conjugate (`conj` in C)
+#[must_use]
+#[inline]
+pub const fn conj(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(z.re, -z.im)
+}
+
+/// Projection onto the Riemann sphere (`cproj` in C)
+///
+/// Every complex infinity, including those with a NaN part, is projected to
+/// `∞ ± i0`, where the sign of zero follows the imaginary part.  Other numbers
+/// are unchanged.
+#[must_use]
+#[inline]
+pub fn proj(z: Complex<f64>) -> Complex<f64> {
+    if z.re.is_infinite() || z.im.is_infinite() {
+        Complex::new(f64::INFINITY, 0.0_f64.copysign(z.im))
+    } else {
+        z
+    }
+}
+
+/// Absolute value (`cabs` in C)
+///
+/// This function is correctly rounded as [`crate::f64::hypot`].
+#[must_use]
+#[inline]
+pub fn abs(z: Complex<f64>) -> f64 {
+    crate::f64::hypot(z.re, z.im)
+}
+
+/// Argument in `-π..=π` (`carg` in C)
+///
+/// This function is correctly rounded as [`crate::f64::atan2`].  The branch
+/// cut is on the negative real axis, where the sign of the imaginary part
+/// decides between `±π`.
+#[must_use]
+#[inline]
+pub fn arg(z: Complex<f64>) -> f64 {
+    crate::f64::atan2(z.im, z.re)
+}
+
+/// Exponential function (`cexp` in C)
+///
+/// The magnitude is computed in halves if `exp(x)` alone overflows, so the
+/// result is finite as long as its parts are.  Special cases follow Annex G
+/// of the C standard, e.g. `exp(-∞ ± i∞) = 0 ± i0` and
+/// `exp(+∞ + i∞) = ∞ + iNaN`.
+#[must_use]
+#[inline]
+pub fn exp(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+
+    if y == 0.0 {
+        return Complex::new(crate::f64::exp(x), y);
+    }
+
+    if x.is_infinite() && !y.is_finite() {
+        return if x < 0.0 {
+            Complex::new(0.0, 0.0_f64.copysign(y))
+        } else {
+            Complex::new(x, f64::NAN)
+        };
+    }
+
+    if !y.is_finite() {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+
+    let (sin, cos) = crate::f64::sin_cos(y);
+
+    if x > f64::MAX_EXP as f64 * LN_2 {
+        let h = crate::f64::exp(0.5 * x);
+        return Complex::new(h * cos * h, h * sin * h);
+    }
+
+    let r = crate::f64::exp(x);
+    Complex::new(r * cos, r * sin)
+}
+
+/// Natural logarithm (`clog` in C)
+///
+/// The imaginary part is [`arg`], so the branch cut is on the negative real
+/// axis.  The real part is accurate near the unit circle and does not
+/// overflow for huge `|z|`.  Special cases follow Annex G of the C standard,
+/// e.g. `ln(-0 + i0) = -∞ + iπ`.
+#[must_use]
+#[inline]
+pub fn ln(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(crate::f64::ln_hypot(z.re, z.im), arg(z))
+}
+
+/// Principal square root of finite `x + iy`
+fn sqrt_finite(x: f64, y: f64) -> Complex<f64> {
+    if x == 0.0 && y == 0.0 {
+        return Complex::new(0.0, y);
+    }
+
+    // Scale by an even power of 2 to avoid overflow in the sum and loss of
+    // precision in subnormal numbers.
+    let m = x.abs().max(y.abs());
+    let (scale, unscale) = match m {
+        m if m > crate::exp2i(1020) => (crate::exp2i(-4), crate::exp2i(2)),
+        m if m < crate::exp2i(-1000) => (crate::exp2i(600), crate::exp2i(-300)),
+        _ => (1.0, 1.0),
+    };
+
+    let (a, b) = (x * scale, y * scale);
+    let t = (0.5 * (a.abs() + crate::f64::hypot(a, b))).sqrt() * unscale;
+    let u = y.abs() / (2.0 * t);
+
+    if x.is_sign_positive() {
+        Complex::new(t, u.copysign(y))
+    } else {
+        Complex::new(u, t.copysign(y))
+    }
+}
+
+/// Square root (`csqrt` in C)
+///
+/// The result is in the right half-plane, and the branch cut is on the
+/// negative real axis.  Special cases follow Annex G of the C standard, e.g.
+/// `sqrt(x + i∞) = ∞ + i∞` even if `x` is NaN.
+#[must_use]
+#[inline]
+pub fn sqrt(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+
+    if y.is_infinite() {
+        return Complex::new(f64::INFINITY, y);
+    }
+
+    if x.is_infinite() {
+        let d = if y.is_nan() { y } else { 0.0 };
+
+        return if x > 0.0 {
+            Complex::new(x, d.copysign(y))
+        } else {
+            Complex::new(d.abs(), f64::INFINITY.copysign(y))
+        };
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+
+    sqrt_finite(x, y)
+}
+
+/// Complex power `z` raised to `w` (`cpow` in C)
+///
+/// This function computes `exp(w ln z)`.  Without extra precision, the error
+/// grows with the magnitude of `w ln z`.  As with real powers, `pow(z, 0) = 1`
+/// for any `z`, including NaN.
+#[must_use]
+#[inline]
+pub fn pow(z: Complex<f64>, w: Complex<f64>) -> Complex<f64> {
+    if w.re == 0.0 && w.im == 0.0 {
+        return Complex::new(1.0, 0.0);
+    }
+
+    let Complex { re: u, im: v } = ln(z);
+    let (a, b) = (w.re, w.im);
+
+    // Skip the imaginary part of `w` if it is zero, so that it does not turn
+    // `ln 0 = -∞` into NaN.
+    if b == 0.0 {
+        exp(Complex::new(a * u, a * v))
+    } else {
+        exp(Complex::new(a * u - b * v, a * v + b * u))
+    }
+}
+
+/// Hyperbolic sine (`csinh` in C)
+///
+/// Special cases follow Annex G of the C standard, e.g.
+/// `sinh(+0 + i∞) = 0 + iNaN`.
+#[must_use]
+#[inline]
+pub fn sinh(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+
+    if y == 0.0 {
+        return Complex::new(crate::f64::sinh(x), y);
+    }
+
+    if x.is_infinite() && !y.is_finite() {
+        return Complex::new(x, f64::NAN);
+    }
+
+    let (sin, cos) = crate::f64::sin_cos(y);
+
+    if x.abs() > f64::MAX_EXP as f64 * LN_2 {
+        let cos = cos * 1.0_f64.copysign(x);
+        return Complex::new(half_exp_mul(x, cos), half_exp_mul(x, sin));
+    }
+
+    let re = if x == 0.0 && !y.is_finite() {
+        x
+    } else {
+        crate::f64::sinh(x) * cos
+    };
+
+    Complex::new(re, crate::f64::cosh(x) * sin)
+}
+
+/// Hyperbolic cosine (`ccosh` in C)
+///
+/// Special cases follow Annex G of the C standard, e.g.
+/// `cosh(+0 + i∞) = NaN + i0`.
+#[must_use]
+#[inline]
+pub fn cosh(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+
+    if y == 0.0 {
+        return Complex::new(crate::f64::cosh(x), 0.0_f64.copysign(x) * y);
+    }
+
+    if x.is_infinite() && !y.is_finite() {
+        return Complex::new(f64::INFINITY, f64::NAN);
+    }
+
+    let (sin, cos) = crate::f64::sin_cos(y);
+
+    if x.abs() > f64::MAX_EXP as f64 * LN_2 {
+        let sin = sin * 1.0_f64.copysign(x);
+        return Complex::new(half_exp_mul(x, cos), half_exp_mul(x, sin));
+    }
+
+    let im = if x == 0.0 && !y.is_finite() {
+        x * 0.0_f64.copysign(y)
+    } else {
+        crate::f64::sinh(x) * sin
+    };
+
+    Complex::new(crate::f64::cosh(x) * cos, im)
+}
+
+/// Hyperbolic tangent (`ctanh` in C)
+///
+/// This function uses Kahan's formula, which avoids cancellation near the
+/// poles.  Special cases follow Annex G of the C standard, e.g.
+/// `tanh(+∞ + iy) = 1 + i0 sin 2y`.
+#[must_use]
+#[inline]
+pub fn tanh(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+
+    if y == 0.0 {
+        return Complex::new(crate::f64::tanh(x), y);
+    }
+
+    if x.is_infinite() {
+        let im = if y.is_finite() {
+            let (sin, cos) = crate::f64::sin_cos(y);
+            0.0_f64.copysign(sin * cos)
+        } else {
+            0.0_f64.copysign(y)
+        };
+        return Complex::new(1.0_f64.copysign(x), im);
+    }
+
+    if !y.is_finite() {
+        return Complex::new(if x == 0.0 { x } else { f64::NAN }, f64::NAN);
+    }
+
+    // tanh(x) rounds to ±1.
+    if x.abs() > 22.0 {
+        let (sin, cos) = crate::f64::sin_cos(y);
+        let im = 4.0 * sin * cos * crate::f64::exp(-2.0 * x.abs());
+        return Complex::new(1.0_f64.copysign(x), im);
+    }
+
+    let t = crate::f64::tan(y);
+    let b = crate::mul_add(t, t, 1.0);
+    let s = crate::f64::sinh(x);
+    let r = crate::mul_add(s, s, 1.0).sqrt();
+    let d = crate::mul_add(b * s, s, 1.0);
+
+    Complex::new(b * r * s / d, t / d)
+}
+
+/// Sine (`csin` in C)
+///
+/// This function is defined as `-i sinh(iz)` by the C standard.
+#[must_use]
+#[inline]
+pub fn sin(z: Complex<f64>) -> Complex<f64> {
+    div_i(sinh(mul_i(z)))
+}
+
+/// Cosine (`ccos` in C)
+///
+/// This function is defined as `cosh(iz)` by the C standard.
+#[must_use]
+#[inline]
+pub fn cos(z: Complex<f64>) -> Complex<f64> {
+    cosh(mul_i(z))
+}
+
+/// Tangent (`ctan` in C)
+///
+/// This function is defined as `-i tanh(iz)` by the C standard.
+#[must_use]
+#[inline]
+pub fn tan(z: Complex<f64>) -> Complex<f64> {
+    div_i(tanh(mul_i(z)))
+}
+
+/// Inverse hyperbolic sine (`casinh` in C)
+///
+/// The branch cuts are on the imaginary axis outside `-i..=i`.  Kahan's
+/// formula keeps the result accurate near the branch cuts, and huge `|z|`
+/// falls back to `ln 2z`.  Special cases follow Annex G of the C standard,
+/// e.g. `asinh(+∞ + i∞) = ∞ + iπ/4`.
+#[must_use]
+#[inline]
+pub fn asinh(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+
+    if x.is_infinite() || y.is_infinite() {
+        let im = match (x.is_nan() || y.is_nan(), x.is_infinite(), y.is_infinite()) {
+            (true, _, _) => f64::NAN,
+            (false, true, true) => FRAC_PI_4,
+            (false, false, true) => FRAC_PI_2,
+            (false, _, false) => 0.0,
+        };
+        return Complex::new(f64::INFINITY.copysign(x), im.copysign(y));
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return Complex::new(f64::NAN, if y == 0.0 { y } else { f64::NAN });
+    }
+
+    // asinh(z) = ln 2z + O(z^-2), odd
+    if x.abs().max(y.abs()) > HUGE {
+        let sign = 1.0_f64.copysign(x);
+        let re = crate::f64::ln_hypot(x, y) + LN_2;
+        let im = crate::f64::atan2(y * sign, x.abs());
+        return Complex::new(re * sign, im * sign);
+    }
+
+    // asinh(z) = i asin(-iz), where asin is evaluated by Kahan's formula with
+    // square roots of 1 + iz and 1 - iz.
+    let s = sqrt_finite(1.0 + y, -x);
+    let t = sqrt_finite(1.0 - y, x);
+    let re = crate::f64::asinh(s.re * t.im - s.im * t.re);
+    let im = crate::f64::atan2(y, s.re * t.re - s.im * t.im);
+    Complex::new(re, im)
+}
+
+/// Arcsine (`casin` in C)
+///
+/// This function is defined as `-i asinh(iz)` by the C standard.  The branch
+/// cuts are on the real axis outside `-1..=1`.
+#[must_use]
+#[inline]
+pub fn asin(z: Complex<f64>) -> Complex<f64> {
+    div_i(asinh(mul_i(z)))
+}
+
+/// Arccosine (`cacos` in C)
+///
+/// The real part is in `0..=π`, and the branch cuts are on the real axis
+/// outside `-1..=1`.  Kahan's formula keeps the result accurate near the
+/// branch cuts, and huge `|z|` falls back to `-i ln 2z`.  Special cases follow
+/// Annex G of the C standard, e.g. `acos(±0 + i0) = π/2 - i0`.
+#[must_use]
+#[inline]
+pub fn acos(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+
+    if x.is_infinite() || y.is_infinite() {
+        let re = match (x.is_nan() || y.is_nan(), y.is_infinite()) {
+            (true, _) => f64::NAN,
+            (false, true) if x.is_infinite() => FRAC_PI_2 - FRAC_PI_4.copysign(x),
+            (false, true) => FRAC_PI_2,
+            (false, false) if x < 0.0 => PI,
+            (false, false) => 0.0,
+        };
+        return Complex::new(re, f64::INFINITY.copysign(-y));
+    }
+
+    if x.is_nan() || y.is_nan() {
+        let re = if x == 0.0 { FRAC_PI_2 } else { f64::NAN };
+        return Complex::new(re, f64::NAN);
+    }
+
+    if x.abs().max(y.abs()) > HUGE {
+        let re = crate::f64::atan2(y.abs(), x);
+        let im = crate::f64::ln_hypot(x, y) + LN_2;
+        return Complex::new(re, im.copysign(-y));
+    }
+
+    let s = sqrt_finite(1.0 - x, -y);
+    let t = sqrt_finite(1.0 + x, y);
+    let re = 2.0 * crate::f64::atan2(s.re, t.re);
+    let im = crate::f64::asinh(t.re * s.im - t.im * s.re);
+    Complex::new(re, im)
+}
+
+/// Inverse hyperbolic cosine (`cacosh` in C)
+///
+/// The real part is nonnegative, and the branch cut is on the real axis
+/// below 1.  This function is `±i acos(z)` with the sign chosen by the sign
+/// bit of the imaginary part of `acos(z)`, which also covers most special
+/// cases in Annex G.
+#[must_use]
+#[inline]
+pub fn acosh(z: Complex<f64>) -> Complex<f64> {
+    // acos(±0 + iNaN) = π/2 + iNaN is the only case with a finite real part
+    // but undefined sign in the imaginary part.
+    if z.re == 0.0 && z.im.is_nan() {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+
+    let w = acos(z);
+
+    if w.im.is_sign_negative() {
+        mul_i(w)
+    } else {
+        div_i(w)
+    }
+}
+
+/// Inverse hyperbolic tangent (`catanh` in C)
+///
+/// The branch cuts are on the real axis outside `-1..=1`.  Special cases
+/// follow Annex G of the C standard, e.g. `atanh(1 + i0) = ∞ + i0`.
+#[must_use]
+#[inline]
+pub fn atanh(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+
+    if x.is_infinite() || y.is_infinite() {
+        let im = if y.is_nan() { y } else { FRAC_PI_2.copysign(y) };
+        return Complex::new(0.0_f64.copysign(x), im);
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return Complex::new(if x == 0.0 { x } else { f64::NAN }, f64::NAN);
+    }
+
+    // The real part is odd in x, so it suffices to compute for |x|.
+    let a = x.abs();
+    let m = a.max(y.abs());
+
+    // atanh(z) = 1/z + iπ/2 + O(z^-3)
+    if m > HUGE * HUGE {
+        let (a, b) = (a / m, y / m);
+        let re = a / crate::mul_add(a, a, b * b) / m;
+        return Complex::new(re.copysign(x), FRAC_PI_2.copysign(y));
+    }
+
+    // ln |(1 + z) / (1 - z)| / 2, which is small if and only if the ratio is
+    // close to 1.  The denominator can underflow near z = 1.
+    let d = crate::mul_add(1.0 - a, 1.0 - a, y * y);
+    let r = 4.0 * a / d;
+
+    let re = if r < 1.0 {
+        0.25 * crate::f64::ln_1p(r)
+    } else {
+        0.5 * (crate::f64::ln_hypot(1.0 + a, y) - crate::f64::ln_hypot(1.0 - a, y))
+    };
+
+    let im = 0.5 * crate::f64::atan2(2.0 * y, crate::mul_add(1.0 - a, 1.0 + a, -y * y));
+    Complex::new(re.copysign(x), im)
+}
+
+/// Arctangent (`catan` in C)
+///
+/// This function is defined as `-i atanh(iz)` by the C standard.  The branch
+/// cuts are on the imaginary axis outside `-i..=i`.
+#[must_use]
+#[inline]
+pub fn atan(z: Complex<f64>) -> Complex<f64> {
+    div_i(atanh(mul_i(z)))
+}
@@ -0,0 +1,30 @@
+/// Complex number in Cartesian form
+///
+/// The layout is compatible with C's complex types.  For example,
+/// `Complex<f32>` is interchangeable with `float _Complex` in FFI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T> {
+    /// Real part
+    pub re: T,
+
+    /// Imaginary part
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Create a complex number from its real and imaginary parts
+    #[must_use]
+    #[inline]
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+const _: () = {
+    use core::mem::{align_of, size_of};
+    assert!(size_of::<Complex<f32>>() == 2 * size_of::<f32>());
+    assert!(align_of::<Complex<f32>>() == align_of::<f32>());
+    assert!(size_of::<Complex<f64>>() == 2 * size_of::<f64>());
+    assert!(align_of::<Complex<f64>>() == align_of::<f64>());
+};
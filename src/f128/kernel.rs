@@ -0,0 +1,472 @@
+//! Kernels of [`F128`](super::F128) functions in [`Wide`]
+//!
+//! Functions here take and return [`Wide`] numbers with `N` words.  Unless
+//! stated otherwise, each result has a relative error below
+//! 2<sup>8-64N</sup>.
+use super::wide::{leading_zeros, shl, Wide};
+use super::F128;
+
+/// ln(2) to 576 bits
+const LN_2: [u64; 9] = [
+    0xB172_17F7_D1CF_79AB,
+    0xC9E3_B398_03F2_F6AF,
+    0x40F3_4326_7298_B62D,
+    0x8A0D_175B_8BAA_FA2B,
+    0xE7B8_7620_6DEB_AC98,
+    0x5595_52FB_4AFA_1B10,
+    0xED2E_AE35_C138_2144,
+    0x2757_3B29_1169_B825,
+    0x3E96_CA16_224A_E8C5,
+];
+
+/// π/2 to 576 bits with exponent 0
+const FRAC_PI_2: [u64; 9] = [
+    0xC90F_DAA2_2168_C234,
+    0xC4C6_628B_80DC_1CD1,
+    0x2902_4E08_8A67_CC74,
+    0x020B_BEA6_3B13_9B22,
+    0x514A_0879_8E34_04DD,
+    0xEF95_19B3_CD3A_431B,
+    0x302B_0A6D_F25F_1437,
+    0x4FE1_356D_6D51_C245,
+    0xE485_B576_625E_7EC6,
+];
+
+/// 2/π to 17408 bits
+///
+/// Binary128 numbers can be as large as 2<sup>16384</sup>, so the table is
+/// much longer than the one for `f64`.  Only a window of it is used for each
+/// reduction.
+static FRAC_2_PI: [u64; 272] = [
+    0xA2F9_836E_4E44_1529,
+    0xFC27_57D1_F534_DDC0,
+    0xDB62_9599_3C43_9041,
+    0xFE51_63AB_DEBB_C561,
+    0xB724_6E3A_424D_D2E0,
+    0x0649_2EEA_09D1_921C,
+    0xFE1D_EB1C_B129_A73E,
+    0xE882_35F5_2EBB_4484,
+    0xE99C_7026_B45F_7E41,
+    0x3991_D639_8353_39F4,
+    0x9C84_5F8B_BDF9_283B,
+    0x1FF8_97FF_DE05_980F,
+    0xEF2F_118B_5A0A_6D1F,
+    0x6D36_7ECF_27CB_09B7,
+    0x4F46_3F66_9E5F_EA2D,
+    0x7527_BAC7_EBE5_F17B,
+    0x3D07_39F7_8A52_92EA,
+    0x6BFB_5FB1_1F8D_5D08,
+    0x5603_3046_FC7B_6BAB,
+    0xF0CF_BC20_9AF4_361D,
+    0xA9E3_9161_5EE6_1B08,
+    0x6599_855F_14A0_6840,
+    0x8DFF_D880_4D73_2731,
+    0x0606_1556_CA73_A8C9,
+    0x60E2_7BC0_8C6B_47C4,
+    0x19C3_67CD_DCE8_092A,
+    0x8359_C476_8B96_1CA6,
+    0xDDAF_44D1_5719_053E,
+    0xA5FF_0705_3F7E_33E8,
+    0x32C2_DE4F_9832_7DBB,
+    0xC33D_26EF_6B1E_5EF8,
+    0x9F3A_1F35_CAF2_7F1D,
+    0x87F1_2190_7C7C_246A,
+    0xFA6E_D577_2D30_433B,
+    0x15C6_14B5_9D19_C3C2,
+    0xC4AD_414D_2C5D_000C,
+    0x467D_862D_71E3_9AC6,
+    0x9B00_6233_7CD2_B497,
+    0xA7B4_D555_37F6_3ED7,
+    0x1810_A3FC_764D_2A9D,
+    0x64AB_D770_F87C_6357,
+    0xB07A_E715_1756_49C0,
+    0xD9D6_3B38_84A7_CB23,
+    0x2477_8AD6_2354_5AB9,
+    0x1F00_1B0A_F1DF_CE19,
+    0xFF31_9F6A_1E66_6157,
+    0x9947_FBAC_D87F_7EB7,
+    0x6522_89E8_3260_BFE6,
+    0xCDC4_EF09_366C_D43F,
+    0x5DD7_DE16_DE3B_5892,
+    0x9BDE_2822_D2E8_8628,
+    0x4D58_E232_CAC6_16E3,
+    0x08CB_7DE0_50C0_17A7,
+    0x1DF3_5BE0_1834_132E,
+    0x6212_8301_4883_5B8E,
+    0xF57F_B0AD_F2E9_1E43,
+    0x4A48_D367_10D8_DDAA,
+    0x425F_AECE_616A_A428,
+    0x0AB4_99D3_F2A6_067F,
+    0x775C_83C2_A388_3C61,
+    0x7873_8A5A_8CAF_BDD7,
+    0x6F63_A62D_CBBF_F4EF,
+    0x818D_67C1_2645_CA55,
+    0x36D9_CAD2_A828_8D61,
+    0xC277_C912_1426_049B,
+    0x4612_C459_C444_C5C8,
+    0x91B2_4DF3_1700_AD43,
+    0xD4E5_4929_10D5_FDFC,
+    0xBE00_CC94_1EEE_CE70,
+    0xF53E_1380_F1EC_C3E7,
+    0xB328_F8C7_9405_933E,
+    0x71C1_B309_2EF3_450B,
+    0x9C12_887B_20AB_9FB5,
+    0x2EC2_9247_2F32_7B6D,
+    0x550C_90A7_721F_E76B,
+    0x96CB_314A_1679_E279,
+    0x4189_DFF4_9794_E884,
+    0xE6E2_9731_996B_ED88,
+    0x365F_5F0E_FDBB_B49A,
+    0x486C_A467_4272_7132,
+    0x5D8D_B815_9F09_E5BC,
+    0x2531_8D39_74F7_1C05,
+    0x3001_0C0D_6808_4B58,
+    0xEE2C_90AA_4702_E774,
+    0x24D6_BDA6_7DF7_7248,
+    0x6EEF_169F_A694_8EF6,
+    0x91B4_5153_D1F2_0ACF,
+    0x3398_207E_4BF5_6863,
+    0xB25F_3EDD_035D_407F,
+    0x8985_2952_55C0_6437,
+    0x10D8_6D32_4832_754C,
+    0x5BD4_714E_6E54_45C1,
+    0x090B_69F5_2AD5_6614,
+    0x9D07_2750_045D_DB3B,
+    0xB4C5_76EA_17F9_877D,
+    0x6B49_BA27_1D29_6996,
+    0xACCC_C654_14AD_6AE2,
+    0x9089_D988_5072_2CBE,
+    0xA404_9407_7770_30F3,
+    0x27FC_00A8_71EA_49C2,
+    0x663D_E064_83DD_9797,
+    0x3FA3_FD94_438C_860D,
+    0xDE41_319D_3992_8C70,
+    0xDDE7_B717_3BDF_082B,
+    0x3715_A080_5C93_805A,
+    0x9211_10D8_E80F_AF80,
+    0x6C4B_FFDB_0F90_3876,
+    0x1859_15A5_62BB_CB61,
+    0xB989_C7BD_4010_04F2,
+    0xD227_7549_F6B6_EBBB,
+    0x22DB_AA14_0A2F_2689,
+    0x7683_6433_3B09_1A94,
+    0x0EAA_3A51_C2A3_1DAE,
+    0xEDAF_1226_5C4D_C26D,
+    0x9C7A_2D97_56C0_833F,
+    0x03F6_F009_8C40_2B99,
+    0x316D_07B4_3915_200C,
+    0x5BC3_D8C4_92F5_4BAD,
+    0xC6A5_CA4E_CD37_A736,
+    0xA9E6_9492_AB68_42DD,
+    0xDE63_19EF_8C76_528B,
+    0x6837_DBFC_ABA1_AE31,
+    0x15DF_A1AE_00DA_FB0C,
+    0x664D_64B7_05ED_3065,
+    0x29BF_5657_3AFF_47B9,
+    0xF96A_F3BE_75DF_9328,
+    0x3080_ABF6_8C66_15CB,
+    0x0406_22FA_1DE4_D9A4,
+    0xB33D_8F1B_5709_CD36,
+    0xE942_4EA4_BE13_B523,
+    0x331A_AAF0_A865_4FA5,
+    0xC1D2_0F3F_0BCD_785B,
+    0x76F9_2304_8B7B_7217,
+    0x8953_A6C6_E26E_6F00,
+    0xEBEF_584A_9BB7_DAC4,
+    0xBA66_AACF_CF76_1D02,
+    0xD12D_F1B1_C199_8C77,
+    0xADC3_DA48_86A0_5DF7,
+    0xF480_C62F_F0AC_9AEC,
+    0xDDBC_5C3F_6DDE_D01F,
+    0xC790_B6DB_2A3A_25A3,
+    0x9AAF_0093_53AD_0457,
+    0xB6B4_2D29_7E80_4BA7,
+    0x07DA_0EAA_76A1_597B,
+    0x2A12_162D_B7DC_FDE5,
+    0xFAFE_DB89_FDBE_896C,
+    0x76E4_FCA9_0670_803E,
+    0x156E_85FF_87FD_073E,
+    0x2833_6761_8618_2AEA,
+    0xBD4D_AFE7_B36E_6D8F,
+    0x3967_955B_BF31_48D7,
+    0x8416_DF30_432D_C735,
+    0x6125_CE70_C9B8_CB30,
+    0xFD6C_BFA2_00A4_E46C,
+    0x05A0_DD5A_476F_21D2,
+    0x1262_845C_B949_6170,
+    0xE056_6B01_5299_3755,
+    0x50B7_D51E_C4F1_335F,
+    0x6E13_E430_5DA9_2E85,
+    0xC3B2_1D36_32A1_A4B7,
+    0x08D4_B1EA_21F7_16E4,
+    0x698F_77FF_2780_030C,
+    0x2D40_8DA0_CD4F_99A5,
+    0x20D3_A2B3_0A5D_2F42,
+    0xF9B4_CBDA_11D0_BE7D,
+    0xC1DB_9BBD_17AB_81A2,
+    0xCA5C_6A08_1755_2E55,
+    0x0027_F014_7F86_07E1,
+    0x640B_148D_4196_DEBE,
+    0x872A_FDDA_B625_6B34,
+    0x897B_FEF3_059E_BFB9,
+    0x4F6A_68A8_2A4A_5AC4,
+    0x4FBC_F82D_985A_D795,
+    0xC7F4_8D4D_0DA6_3A20,
+    0x5F57_A4B1_3F14_9538,
+    0x8001_20CC_86DD_71B6,
+    0xDEC9_F560_BF11_654D,
+    0x6B07_01AC_B08C_D0C0,
+    0xB248_5551_0EFB_1EC3,
+    0x7295_3B06_A335_40C0,
+    0x7BDC_06CC_45E0_FA29,
+    0x4EC8_CAD6_41F3_E8DE,
+    0x647C_D864_9B31_BED9,
+    0xC397_A4D4_5877_C5E3,
+    0x6913_DAF0_3C3A_BA46,
+    0x1846_5F75_55F5_BDD2,
+    0xC692_6E5D_2EAC_ED44,
+    0x0E42_3E1C_87C4_61E9,
+    0xFD29_F3D6_E7CA_7C22,
+    0x3591_6FC5_E008_8DD7,
+    0xFFE2_6A6E_C6FD_B0C1,
+    0x0893_745D_7CB2_AD6B,
+    0x9D6E_CD7B_723E_6A11,
+    0xC6A9_CFF7_DF73_29BA,
+    0xC9B5_5100_B70D_B2E2,
+    0x24BA_7460_7DE5_8AD8,
+    0x742C_150D_0C18_8194,
+    0x667E_1629_0176_7A9F,
+    0xBEFD_FDEF_4556_367E,
+    0xD913_D9EC_B9BA_8BFC,
+    0x97C4_27A8_31C3_6EF1,
+    0x36C5_9456_A8D8_B5A8,
+    0xB40E_CCCF_2D89_1234,
+    0x576F_8956_2CE3_CE99,
+    0xB920_D6AA_5E6B_9C2A,
+    0x3ECC_5F11_4A0B_FDFB,
+    0xF4E1_6D3B_8E2C_86E2,
+    0x84D4_E9A9_B4FC_D1EE,
+    0xEFC9_352E_6139_2F44,
+    0x2138_C8D9_1B0A_FC81,
+    0x6A4A_FBD8_1C2F_84B4,
+    0x538C_994E_CC22_54DC,
+    0x552A_D6C6_C096_190B,
+    0xB870_1A64_9569_605A,
+    0x26EE_523F_0F11_7F11,
+    0xB5F4_F5CB_FC2D_BC34,
+    0xEEBC_34CC_5DE8_605E,
+    0xDD9B_8E67_EF33_92B8,
+    0x17C9_9B58_61BC_57E1,
+    0xC683_5110_3ED8_4871,
+    0xDDDD_1C2D_A118_AF46,
+    0x2C21_D7F3_5998_7AD9,
+    0xC054_9EFA_864F_FC06,
+    0x56AE_79E5_3622_8922,
+    0xAD38_DC93_67AA_E855,
+    0x3826_829B_E7CA_A40D,
+    0x51B1_3399_0ED7_A948,
+    0x0569_F0B2_65A7_887F,
+    0x974C_8836_D1F9_B392,
+    0x214A_827B_21CF_98DC,
+    0x9F40_5547_DC3A_74E1,
+    0x42EB_67DF_9DFE_5FD4,
+    0x5EA4_677B_7AAC_BAA2,
+    0xF655_2388_2B55_BA41,
+    0x086E_5986_2A21_8347,
+    0x39E6_E389_D49E_E540,
+    0xFB49_E956_FFCA_0F1C,
+    0x8A59_C52B_FA94_C5C1,
+    0xD3CF_C50F_AE5A_DB86,
+    0xC547_6243_853B_8621,
+    0x9479_2C87_6110_7B4C,
+    0x2A1A_2C80_12BF_4390,
+    0x2688_893C_78E4_C4A8,
+    0x7BDB_E5C2_3AC4_EAF4,
+    0x268A_67F7_BF92_0D2B,
+    0xA365_B193_3D0B_7CBD,
+    0xDC51_A463_DD27_DDE1,
+    0x6919_949A_9529_A828,
+    0xCE68_B4ED_0920_9F44,
+    0xCA98_4E63_8270_237C,
+    0x7E32_B90F_8EF5_A7E7,
+    0x5614_08F1_212A_9DB5,
+    0x4D7E_6F51_19A5_ABF9,
+    0xB5D6_DF82_61DD_9602,
+    0x3616_9F3A_C4A1_A283,
+    0x6DED_727A_8D39_A9B8,
+    0x825C_326B_5B27_46ED,
+    0x3400_7700_D255_F4FC,
+    0x4D59_0180_71E0_E13F,
+    0x89B2_95F3_64A8_F1AE,
+    0xA74B_38FC_4CEA_B2BB,
+    0x4727_0BAB_C3A7_34BA,
+    0x6052_DD34_F856_3AEB,
+    0x7E8A_31BB_3658_95B7,
+    0x47F7_A994_C3AA_D392,
+    0x251E_7F3E_D897_4EBB,
+    0xA94F_D8AE_01E6_61B4,
+    0x393D_8EA5_23AA_3306,
+    0x8E16_33B5_3BB1_881D,
+    0x3A9D_4013_D0CC_1BE5,
+    0xF862_E73B_F28F_39B5,
+    0xBF0B_C235_2274_7EA2,
+];
+
+/// One in [`Wide`]
+#[inline]
+fn one<const N: usize>() -> Wide<N> {
+    Wide::from_u128(false, 1)
+}
+
+/// ln(2) in [`Wide`]
+#[inline]
+fn ln_2<const N: usize>() -> Wide<N> {
+    Wide::truncate(-1, &LN_2)
+}
+
+/// exp(x) - 1 for |x| ≤ 0.35
+///
+/// The argument is reduced by 2<sup>16</sup> for the Taylor series, and the
+/// result is recovered with exp(2x) - 1 = (exp(x) - 1)(exp(x) + 1).  The
+/// recovery hardly amplifies the relative error.
+fn exp_m1<const N: usize>(x: Wide<N>) -> Wide<N> {
+    let x = x.scale(-16);
+    let one = one();
+    let two = one.scale(1);
+    let y = (2..=4 * N as u64 + 2)
+        .rev()
+        .fold(one, |y, n| one + (y * x).div_small(n));
+
+    (0..16).fold(y * x, |y, _| y * (y + two))
+}
+
+/// exp(x) for |x| < 11500
+///
+/// The result has a relative error below 2<sup>24-64N</sup> because the
+/// absolute error of the reduced argument scales with |x|.
+#[inline]
+pub fn exp<const N: usize>(x: Wide<N>) -> Wide<N> {
+    let n = (x.approx() * core::f64::consts::LOG2_E).round_ties_even() as i64;
+    let r = x - ln_2() * n;
+    (one() + exp_m1(r)).scale(n)
+}
+
+/// ln(x) for a positive normal or subnormal `x`
+///
+/// Let x = m 2<sup>e</sup> where m ∈ [1/√2, √2).  Then ln(m) is solved by
+/// Newton's method y ← y + m exp(-y) - 1 from an `f64` approximation.  The
+/// number of correct bits doubles with each iteration.
+pub fn ln<const N: usize>(x: F128) -> Wide<N> {
+    let x = Wide::<N>::from(x);
+    let e = x.exponent + i64::from(x.significand[0] >= 0xB504_F333_F9DE_6484);
+    let m = x.scale(-e);
+    let d = m - one();
+
+    let mut y = Wide::from(F128::from(crate::f64::ln_1p(d.approx())));
+    let mut bits = 50;
+
+    while bits < 64 * N + 8 {
+        y = y + (d + m * exp_m1(-y));
+        bits *= 2;
+    }
+
+    ln_2() * e + y
+}
+
+/// sin(x) for |x| ≤ π/4
+#[inline]
+pub fn sin<const N: usize>(x: Wide<N>) -> Wide<N> {
+    let one = one();
+    let x2 = x * x;
+    let terms = 7 * N as u64 + 4;
+    let y = (1..=terms)
+        .rev()
+        .fold(one, |y, k| one - (x2 * y).div_small(2 * k * (2 * k + 1)));
+
+    x * y
+}
+
+/// cos(x) for |x| ≤ π/4
+#[inline]
+pub fn cos<const N: usize>(x: Wide<N>) -> Wide<N> {
+    let one = one();
+    let x2 = x * x;
+    let terms = 7 * N as u64 + 4;
+
+    (1..=terms)
+        .rev()
+        .fold(one, |y, k| one - (x2 * y).div_small((2 * k - 1) * (2 * k)))
+}
+
+/// Number of words of 2/π used in each reduction
+const WINDOW: usize = 13;
+
+/// Reduce a positive finite `x` by π/2 with Payne–Hanek reduction
+///
+/// This function returns `(n, y)` where `x = n * π/2 + y` and `|y| ≤ π/4`.
+/// Only `n mod 4` is meaningful.
+///
+/// The significand of `x` is multiplied by a window of 2/π that begins where
+/// previous bits would only contribute multiples of 4.  The window leaves
+/// about 700 significant bits, minus those lost to cancellation when `x` is
+/// close to a multiple of π/2.
+pub fn rem_pio2<const N: usize>(x: F128) -> (u64, Wide<N>) {
+    // x = m * 2^e
+    let (_, e, m) = x.decompose();
+    let e = e - i64::from(super::EXP_SHIFT);
+
+    // Bits of 2/π to skip
+    let skip = (e - 2).max(0) as usize;
+    let (q, r) = (skip / 64, skip % 64);
+
+    let window: [u64; WINDOW] = core::array::from_fn(|i| match r {
+        0 => FRAC_2_PI[q + i],
+        _ => FRAC_2_PI[q + i] << r | FRAC_2_PI[q + i + 1] >> (64 - r),
+    });
+
+    // Product of `m` and the window in 15 words
+    let mut product = [0; WINDOW + 2];
+
+    for (i, w) in [(m >> 64) as u64, m as u64].into_iter().enumerate().rev() {
+        let mut carry = 0;
+
+        for (j, &v) in window.iter().enumerate().rev() {
+            let t = u128::from(w) * u128::from(v) + u128::from(product[i + j + 1]) + carry;
+            product[i + j + 1] = t as u64;
+            carry = t >> 64;
+        }
+        product[i] = carry as u64;
+    }
+
+    // Align the last 2 integral bits to the top, discarding the others.
+    let point = 64 * WINDOW as i64 + skip as i64 - e;
+    let mut product = shl(product, (64 * product.len() as i64 - point - 2) as u32);
+    let mut n = product[0] >> 62;
+    product[0] &= (1 << 62) - 1;
+
+    // Round to the nearest quadrant
+    let sign = product[0] >> 61 == 1;
+
+    if sign {
+        n += 1;
+        let mut borrow = false;
+
+        for word in product.iter_mut().rev() {
+            let (difference, b1) = 0u64.overflowing_sub(*word);
+            let (difference, b2) = difference.overflowing_sub(u64::from(borrow));
+            (*word, borrow) = (difference, b1 || b2);
+        }
+        product[0] &= (1 << 62) - 1;
+    }
+
+    let shift = leading_zeros(&product);
+    let product = shl(product, shift);
+
+    let y = Wide {
+        sign,
+        exponent: 1 - i64::from(shift),
+        significand: core::array::from_fn(|i| product[i]),
+    };
+
+    (n & 3, y * Wide::truncate(0, &FRAC_PI_2))
+}
@@ -0,0 +1,511 @@
+#![allow(clippy::pedantic)]
+#![warn(clippy::unreadable_literal)]
+
+mod kernel;
+mod wide;
+use core::cmp::Ordering;
+use core::num::FpCategory;
+use core::ops::Neg;
+use wide::Wide;
+
+/// Explicitly stored significand bits in [`F128`]
+///
+/// This constant is usually used as a shift to access the exponent bits.
+pub const EXP_SHIFT: u32 = F128::MANTISSA_DIGITS - 1;
+
+/// IEEE 754 binary128 floating-point number
+///
+/// This is a stand-in for the primitive `f128` until it is stable.  It is a
+/// storage type: arithmetic is not provided, but conversions from and to `f64`
+/// are.  The functions in this module are correctly rounded.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct F128(u128);
+
+impl F128 {
+    /// Number of significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 113;
+
+    /// One greater than the minimum possible normal power of 2 exponent
+    pub const MIN_EXP: i32 = -16381;
+
+    /// Maximum possible power of 2 exponent
+    pub const MAX_EXP: i32 = 16384;
+
+    /// Machine epsilon, 2<sup>-112</sup>
+    pub const EPSILON: Self = Self(0x3F8F << EXP_SHIFT);
+
+    /// Smallest positive normal number, 2<sup>-16382</sup>
+    pub const MIN_POSITIVE: Self = Self(1 << EXP_SHIFT);
+
+    /// Largest finite number
+    pub const MAX: Self = Self((0x7FFF << EXP_SHIFT) - 1);
+
+    /// Smallest finite number
+    pub const MIN: Self = Self(Self::MAX.0 | Self::SIGN);
+
+    /// Infinity (∞)
+    pub const INFINITY: Self = Self(0x7FFF << EXP_SHIFT);
+
+    /// Negative infinity (−∞)
+    pub const NEG_INFINITY: Self = Self(Self::INFINITY.0 | Self::SIGN);
+
+    /// Not a Number (NaN)
+    pub const NAN: Self = Self(0xFFFF << (EXP_SHIFT - 1));
+
+    /// Sign bit
+    const SIGN: u128 = 1 << 127;
+
+    /// Zero
+    const ZERO: Self = Self(0);
+
+    /// One
+    const ONE: Self = Self(0x3FFF << EXP_SHIFT);
+
+    /// Raw transmutation from `u128`
+    #[must_use]
+    #[inline]
+    pub const fn from_bits(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// Raw transmutation to `u128`
+    #[must_use]
+    #[inline]
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// Check if the number is NaN
+    #[must_use]
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & !Self::SIGN > Self::INFINITY.0
+    }
+
+    /// Check if the number is positive or negative infinity
+    #[must_use]
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & !Self::SIGN == Self::INFINITY.0
+    }
+
+    /// Check if the number is neither infinite nor NaN
+    #[must_use]
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        self.0 & !Self::SIGN < Self::INFINITY.0
+    }
+
+    /// Check if the sign bit is set, including -0.0 and NaNs with that bit
+    #[must_use]
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & Self::SIGN != 0
+    }
+
+    /// Check if the sign bit is clear, including +0.0 and NaNs without that bit
+    #[must_use]
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+
+    /// Absolute value
+    #[must_use]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self(self.0 & !Self::SIGN)
+    }
+
+    /// Floating-point category of the number
+    #[must_use]
+    #[inline]
+    pub const fn classify(self) -> FpCategory {
+        match self.0 & !Self::SIGN {
+            0 => FpCategory::Zero,
+            x if x < Self::MIN_POSITIVE.0 => FpCategory::Subnormal,
+            x if x < Self::INFINITY.0 => FpCategory::Normal,
+            x if x == Self::INFINITY.0 => FpCategory::Infinite,
+            _ => FpCategory::Nan,
+        }
+    }
+
+    /// Break a finite number into `(sign, exponent, significand)`
+    ///
+    /// The value is `(-1)^sign * significand * 2^(exponent - EXP_SHIFT)`.
+    /// Subnormal numbers are normalized to have the implicit bit set, so the
+    /// exponent is the same as an unbiased IEEE 754 exponent.  The significand
+    /// of zero is zero.
+    #[inline]
+    fn decompose(self) -> (bool, i64, u128) {
+        let sign = self.is_sign_negative();
+        let magnitude = self.abs().0;
+        let mask = (1 << EXP_SHIFT) - 1;
+
+        if magnitude < Self::MIN_POSITIVE.0 {
+            let shift = magnitude.leading_zeros().saturating_sub(15);
+            return (sign, -16382 - i64::from(shift), magnitude << shift);
+        }
+
+        let exponent = (magnitude >> EXP_SHIFT) as i64 - 16383;
+        (sign, exponent, magnitude & mask | 1 << EXP_SHIFT)
+    }
+
+    /// Correctly rounded conversion to `f64`
+    ///
+    /// Overflow and gradual underflow are handled.  Ties are broken to even.
+    #[must_use]
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        let sign = if self.is_sign_negative() { -1.0 } else { 1.0 };
+
+        match self.classify() {
+            FpCategory::Nan => return f64::NAN,
+            FpCategory::Infinite => return sign * f64::INFINITY,
+            FpCategory::Zero => return sign * 0.0,
+            _ => (),
+        }
+
+        let (_, exponent, significand) = self.decompose();
+        let min_exp = i64::from(f64::MIN_EXP) - 1;
+
+        // Number of bits to discard, more for subnormal numbers
+        let shift =
+            i64::from(Self::MANTISSA_DIGITS - f64::MANTISSA_DIGITS) + (min_exp - exponent).max(0);
+
+        if shift > i64::from(Self::MANTISSA_DIGITS) {
+            return sign * 0.0;
+        }
+
+        let kept = significand >> shift;
+        let rest = significand & ((1 << shift) - 1);
+        let tie = 1 << (shift - 1);
+        let carry = rest > tie || (rest == tie && kept & 1 == 1);
+        let kept = kept + u128::from(carry);
+
+        // The implicit bit of a normal number adds one to the exponent field.
+        let bits = match exponent - min_exp {
+            e @ 0.. => kept + ((e as u128) << crate::f64::EXP_SHIFT),
+            _ => kept,
+        };
+
+        sign * f64::from_bits(bits.min(f64::INFINITY.to_bits().into()) as u64)
+    }
+}
+
+impl From<f64> for F128 {
+    /// Exact conversion from `f64`
+    #[inline]
+    fn from(x: f64) -> Self {
+        let sign = u128::from(x.is_sign_negative()) << 127;
+        let magnitude = x.abs().to_bits();
+
+        let bits = match x.classify() {
+            FpCategory::Nan => Self::NAN.0,
+            FpCategory::Infinite => Self::INFINITY.0,
+            FpCategory::Zero => 0,
+            FpCategory::Normal => {
+                let bias = (16383 - 1023) << EXP_SHIFT;
+                bias + (u128::from(magnitude) << (EXP_SHIFT - crate::f64::EXP_SHIFT))
+            }
+            FpCategory::Subnormal => {
+                let shift = magnitude.leading_zeros() - 11;
+                let mask = (1 << crate::f64::EXP_SHIFT) - 1;
+                let exponent = 16383 - 1022 - u128::from(shift);
+                let significand = u128::from(magnitude << shift & mask);
+                exponent << EXP_SHIFT | significand << (EXP_SHIFT - crate::f64::EXP_SHIFT)
+            }
+        };
+
+        Self(sign | bits)
+    }
+}
+
+impl Neg for F128 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0 ^ Self::SIGN)
+    }
+}
+
+impl PartialEq for F128 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for F128 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+
+        // Map to integers in the same order, where both zeros become 0.
+        let key = |x: &Self| match x.abs().0 as i128 {
+            m if x.is_sign_negative() => -m,
+            m => m,
+        };
+
+        Some(key(self).cmp(&key(other)))
+    }
+}
+
+/// Round a result in [`Wide`] with Ziv's strategy
+///
+/// The result is first computed with 4 words.  If its error bounds round to
+/// different [`F128`]s, it is recomputed with 8 words.
+#[inline]
+fn round(fast: impl FnOnce() -> Wide<4>, accurate: impl FnOnce() -> Wide<8>) -> F128 {
+    let (lower, upper) = fast().bounds();
+
+    if lower.0 == upper.0 {
+        lower
+    } else {
+        accurate().round()
+    }
+}
+
+/// The exponential function
+///
+/// The result is correctly rounded.
+#[must_use]
+#[inline]
+pub fn exp(x: F128) -> F128 {
+    match x.to_f64() {
+        x if x.is_nan() => F128::NAN,
+        11357.0.. => F128::INFINITY,
+        ..-11435.0 => F128::ZERO,
+        _ => round(|| kernel::exp(Wide::from(x)), || kernel::exp(Wide::from(x))),
+    }
+}
+
+/// Natural logarithm
+///
+/// The result is correctly rounded.
+#[must_use]
+#[inline]
+pub fn ln(x: F128) -> F128 {
+    match x.classify() {
+        FpCategory::Nan => x,
+        FpCategory::Zero => F128::NEG_INFINITY,
+        _ if x.is_sign_negative() => F128::NAN,
+        FpCategory::Infinite => x,
+        _ => round(|| kernel::ln(x), || kernel::ln(x)),
+    }
+}
+
+/// Sine or cosine of a finite `x` in [`Wide`]
+///
+/// Cosine is evaluated if `cos` is true.  The quadrant is shifted by 1 for
+/// cosine, so cos(x) = sin(x + π/2).
+#[inline]
+fn sin_cos<const N: usize>(x: F128, cos: bool) -> Wide<N> {
+    let (sign, magnitude) = (x.is_sign_negative() && !cos, x.abs());
+
+    let (n, y) = if magnitude.to_f64() < 0.78 {
+        (0, Wide::from(magnitude))
+    } else {
+        kernel::rem_pio2(magnitude)
+    };
+
+    let y = match (n + u64::from(cos)) & 3 {
+        0 => kernel::sin(y),
+        1 => kernel::cos(y),
+        2 => -kernel::sin(y),
+        _ => -kernel::cos(y),
+    };
+
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Sine
+///
+/// The result is correctly rounded.
+#[must_use]
+#[inline]
+pub fn sin(x: F128) -> F128 {
+    match x.classify() {
+        FpCategory::Nan | FpCategory::Infinite => F128::NAN,
+        FpCategory::Zero => x,
+        _ => round(|| sin_cos(x, false), || sin_cos(x, false)),
+    }
+}
+
+/// Cosine
+///
+/// The result is correctly rounded.
+#[must_use]
+#[inline]
+pub fn cos(x: F128) -> F128 {
+    match x.classify() {
+        FpCategory::Nan | FpCategory::Infinite => F128::NAN,
+        _ => round(|| sin_cos(x, true), || sin_cos(x, true)),
+    }
+}
+
+/// Compute `x^y` exactly if it is a dyadic rational with a short significand
+///
+/// - `x`: a positive finite number
+///
+/// This function is the counterpart of [`crate::f64`]'s `exact_pow`.  If
+/// `x^y` is a dyadic rational, `y = n / 2^k` and the odd part of the
+/// significand of `x` is a perfect (2<sup>k</sup>)th power.  Powers of 2
+/// admit larger `k` because their results can still be ties in the subnormal
+/// range.
+fn exact_pow(x: F128, y: F128) -> Option<Wide<4>> {
+    // x = w * 2^exponent, where w is odd
+    let (_, exponent, w) = x.decompose();
+    let zeros = w.trailing_zeros();
+    let exponent = exponent - i64::from(EXP_SHIFT - zeros);
+    let mut w = w >> zeros;
+
+    // y = n / 2^k, where n is odd unless k = 0
+    let (sign, shift, n) = y.decompose();
+    let shift = shift - i64::from(EXP_SHIFT - n.trailing_zeros());
+    let n = n >> n.trailing_zeros();
+
+    if n > 1 << 20 {
+        return None;
+    }
+
+    let (n, k) = match shift {
+        0..=20 => (n << shift, 0),
+        -16..0 => (n, -shift),
+        _ => return None,
+    };
+
+    if n > 1 << 20 {
+        return None;
+    }
+
+    for _ in 0..k {
+        let root = w.isqrt();
+
+        if root * root != w {
+            return None;
+        }
+        w = root;
+    }
+
+    let n = if sign { -(n as i64) } else { n as i64 };
+
+    if n < 0 && w != 1 {
+        return None;
+    }
+
+    let exponent = exponent * n;
+
+    if exponent & ((1 << k) - 1) != 0 {
+        return None;
+    }
+
+    let significand = w.checked_pow(n.unsigned_abs() as u32)?;
+    Some(Wide::from_u128(false, significand).scale(exponent >> k))
+}
+
+/// Power function
+///
+/// Special cases follow Annex F of the C standard.  The result is correctly
+/// rounded.  Exact results are checked before recomputing hard cases with more
+/// words, because ties cannot be resolved by approximation.
+#[must_use]
+#[inline]
+pub fn pow(x: F128, y: F128) -> F128 {
+    #[inline]
+    fn magnitude(x: F128, y: F128) -> F128 {
+        match x.classify() {
+            FpCategory::Nan => F128::NAN,
+            FpCategory::Infinite => match y.partial_cmp(&F128::ZERO) {
+                Some(Ordering::Greater) => F128::INFINITY,
+                Some(Ordering::Less) => F128::ZERO,
+                Some(Ordering::Equal) => F128::ONE,
+                None => F128::NAN,
+            },
+            FpCategory::Zero => match y.partial_cmp(&F128::ZERO) {
+                Some(Ordering::Greater) => F128::ZERO,
+                Some(Ordering::Less) => F128::INFINITY,
+                Some(Ordering::Equal) => F128::ONE,
+                None => F128::NAN,
+            },
+            _ => match x {
+                x if x == F128::ONE => F128::ONE,
+                x if x.is_sign_negative() => F128::NAN,
+                _ if y.is_nan() => F128::NAN,
+                x if y.is_infinite() => {
+                    if (x < F128::ONE) == y.is_sign_negative() {
+                        F128::INFINITY
+                    } else {
+                        F128::ZERO
+                    }
+                }
+                _ => finite(x, y),
+            },
+        }
+    }
+
+    #[inline]
+    fn finite(x: F128, y: F128) -> F128 {
+        let z = kernel::ln::<4>(x) * Wide::from(y);
+
+        // Check the rough product first because it can overflow.
+        if z.approx() > 11357.0 {
+            return F128::INFINITY;
+        }
+
+        if z.approx() < -11435.0 {
+            return F128::ZERO;
+        }
+
+        let (lower, upper) = kernel::exp(z).bounds();
+
+        if lower.0 == upper.0 {
+            return lower;
+        }
+
+        if let Some(z) = exact_pow(x, y) {
+            return z.round();
+        }
+
+        kernel::exp(kernel::ln::<8>(x) * Wide::from(y)).round()
+    }
+
+    /// Check if `x` is an integer, where infinities are even integers
+    #[inline]
+    fn parity(x: F128) -> Option<bool> {
+        let (_, exponent, significand) = x.decompose();
+
+        match exponent {
+            _ if x.is_nan() => None,
+            _ if x.is_infinite() || significand == 0 => Some(false),
+            ..0 => None,
+            0..=112 => {
+                let shift = EXP_SHIFT - exponent as u32;
+                let fraction = significand & ((1 << shift) - 1);
+                (fraction == 0).then_some(significand >> shift & 1 == 1)
+            }
+            _ => Some(false),
+        }
+    }
+
+    if y.abs().0 == 0 {
+        return F128::ONE;
+    }
+
+    if x.is_sign_negative() {
+        if let Some(odd) = parity(y) {
+            let z = magnitude(-x, y);
+            return if odd { -z } else { z };
+        }
+    }
+
+    magnitude(x, y)
+}
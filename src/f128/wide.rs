@@ -0,0 +1,402 @@
+use super::F128;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Floating-point number with a significand of `N` 64-bit words
+///
+/// The value is `(-1)^sign * significand * 2^(exponent + 1 - 64N)`, where the
+/// words of the significand are stored from the most significant.  A nonzero
+/// significand is normalized to have its highest bit set, so the exponent is
+/// the same as an unbiased IEEE 754 exponent.
+///
+/// This type generalizes [`crate::f64`]'s 128-bit `Wide` to any number of
+/// words.  Arithmetic truncates instead of rounding, so each operation has a
+/// relative error below 2<sup>1-64N</sup>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wide<const N: usize> {
+    pub sign: bool,
+    pub exponent: i64,
+    pub significand: [u64; N],
+}
+
+/// Shift words to the left by `shift` bits, filling with zeros
+#[inline]
+pub fn shl<const N: usize>(words: [u64; N], shift: u32) -> [u64; N] {
+    let (skip, bits) = ((shift / 64) as usize, shift % 64);
+
+    core::array::from_fn(|i| {
+        let hi = words.get(i + skip).copied().unwrap_or(0);
+        let lo = words.get(i + skip + 1).copied().unwrap_or(0);
+
+        match bits {
+            0 => hi,
+            _ => hi << bits | lo >> (64 - bits),
+        }
+    })
+}
+
+/// Shift words to the right by `shift` bits, discarding the lowest bits
+#[inline]
+fn shr<const N: usize>(words: [u64; N], shift: u32) -> [u64; N] {
+    let (skip, bits) = ((shift / 64) as usize, shift % 64);
+
+    core::array::from_fn(|i| {
+        let word = |j: usize| j.checked_sub(skip).map_or(0, |j| words[j]);
+
+        match bits {
+            0 => word(i),
+            _ => word(i) >> bits | i.checked_sub(1).map_or(0, |i| word(i) << (64 - bits)),
+        }
+    })
+}
+
+/// Number of leading zeros of all the words
+#[inline]
+pub fn leading_zeros(words: &[u64]) -> u32 {
+    words
+        .iter()
+        .position(|&w| w != 0)
+        .map_or(64 * words.len() as u32, |i| {
+            64 * i as u32 + words[i].leading_zeros()
+        })
+}
+
+/// Bit `n` from the least significant bit of all the words
+#[inline]
+fn bit(words: &[u64], n: usize) -> bool {
+    words[words.len() - 1 - n / 64] >> (n % 64) & 1 == 1
+}
+
+/// Check if any bit below bit `n` is set
+#[inline]
+fn any_below(words: &[u64], n: usize) -> bool {
+    let i = words.len() - 1 - n / 64;
+    words[i] & ((1 << (n % 64)) - 1) != 0 || words[i + 1..].iter().any(|&w| w != 0)
+}
+
+impl<const N: usize> Wide<N> {
+    /// Zero in [`Wide`]
+    pub const ZERO: Self = Self {
+        sign: false,
+        exponent: 0,
+        significand: [0; N],
+    };
+
+    /// Construct a positive number from the leading words of a constant
+    ///
+    /// This is how constants with up to 9 words are truncated to `N` words.
+    pub const fn truncate(exponent: i64, words: &[u64; 9]) -> Self {
+        let mut significand = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            significand[i] = words[i];
+            i += 1;
+        }
+
+        Self {
+            sign: false,
+            exponent,
+            significand,
+        }
+    }
+
+    /// Normalize the significand, adjusting the exponent
+    #[inline]
+    fn normalize(sign: bool, exponent: i64, significand: [u64; N]) -> Self {
+        let shift = leading_zeros(&significand);
+
+        if shift == 64 * N as u32 {
+            return Self::ZERO;
+        }
+
+        Self {
+            sign,
+            exponent: exponent - i64::from(shift),
+            significand: shl(significand, shift),
+        }
+    }
+
+    /// Check if the number is zero
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.significand[0] == 0
+    }
+
+    /// Exact conversion from an integer
+    #[inline]
+    pub fn from_u128(sign: bool, n: u128) -> Self {
+        const { assert!(N >= 2) };
+
+        let mut significand = [0; N];
+        significand[0] = (n >> 64) as u64;
+        significand[1] = n as u64;
+        Self::normalize(sign, 127, significand)
+    }
+
+    /// Multiply by 2<sup>`n`</sup>
+    #[inline]
+    pub fn scale(self, n: i64) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        Self {
+            exponent: self.exponent + n,
+            ..self
+        }
+    }
+
+    /// Divide by a positive integer
+    #[inline]
+    pub fn div_small(self, n: u64) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+
+        let n = u128::from(n);
+        let mut remainder = 0;
+        let mut quotient = [0; N];
+
+        for (q, &w) in quotient.iter_mut().zip(&self.significand) {
+            let dividend = remainder << 64 | u128::from(w);
+            *q = (dividend / n) as u64;
+            remainder = dividend % n;
+        }
+
+        // The quotient has at most 64 leading zeros, which are filled with
+        // the next word of the quotient.
+        let shift = leading_zeros(&quotient);
+        let next = ((remainder << 64) / n) as u64;
+        let mut significand = shl(quotient, shift);
+
+        if shift > 0 {
+            significand[N - 1] |= next >> (64 - shift);
+        }
+
+        Self {
+            exponent: self.exponent - i64::from(shift),
+            significand,
+            ..self
+        }
+    }
+
+    /// Rough conversion to `f64` by truncation
+    #[inline]
+    pub fn approx(self) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let x = self.significand[0] as f64 * crate::exp2i(self.exponent - 63);
+
+        if self.sign {
+            -x
+        } else {
+            x
+        }
+    }
+
+    /// Correctly rounded conversion to [`F128`]
+    ///
+    /// Overflow and gradual underflow are handled.  The significand beyond
+    /// `N` words is considered zero, so ties are broken to even.
+    #[inline]
+    pub fn round(self) -> F128 {
+        let sign = u128::from(self.sign) << 127;
+
+        if self.is_zero() {
+            return F128::from_bits(sign);
+        }
+
+        // Exponent of the least normal number
+        let min_exp = i64::from(F128::MIN_EXP) - 1;
+        let digits = i64::from(F128::MANTISSA_DIGITS);
+        let size = 64 * N as i64;
+
+        // Number of bits to discard, more for subnormal numbers
+        let shift = size - digits + (min_exp - self.exponent).max(0);
+
+        if shift > size {
+            return F128::from_bits(sign);
+        }
+
+        let kept = shr(self.significand, shift as u32);
+        let kept = u128::from(kept[N - 2]) << 64 | u128::from(kept[N - 1]);
+        let (half, sticky) = match shift {
+            0 => (false, false),
+            _ => {
+                let n = (shift - 1) as usize;
+                (bit(&self.significand, n), any_below(&self.significand, n))
+            }
+        };
+        let carry = half && (sticky || kept & 1 == 1);
+        let kept = kept + u128::from(carry);
+
+        // The implicit bit of a normal number adds one to the exponent field.
+        let bits = match self.exponent - min_exp {
+            e @ 0.. => kept + ((e as u128) << super::EXP_SHIFT),
+            _ => kept,
+        };
+
+        F128::from_bits(sign | bits.min(F128::INFINITY.to_bits()))
+    }
+
+    /// Round bounds of a result with a relative error below 2<sup>64-64N</sup>
+    ///
+    /// The result is correctly rounded if both bounds round to the same
+    /// [`F128`].  Otherwise, it has to be recomputed with more words.
+    #[inline]
+    pub fn bounds(self) -> (F128, F128) {
+        let error = self.scale(64 - 64 * N as i64);
+        ((self - error).round(), (self + error).round())
+    }
+}
+
+impl<const N: usize> From<F128> for Wide<N> {
+    /// Exact conversion from a finite [`F128`]
+    #[inline]
+    fn from(x: F128) -> Self {
+        const { assert!(N >= 2) };
+
+        let (sign, exponent, significand) = x.decompose();
+        let mut words = [0; N];
+        words[0] = (significand >> 64) as u64;
+        words[1] = significand as u64;
+        Self::normalize(sign, exponent + 127 - i64::from(super::EXP_SHIFT), words)
+    }
+}
+
+impl<const N: usize> Neg for Wide<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            sign: !self.sign,
+            ..self
+        }
+    }
+}
+
+impl<const N: usize> Add for Wide<N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        if other.is_zero() {
+            return self;
+        }
+
+        if self.is_zero() {
+            return other;
+        }
+
+        let (a, b) = if (self.exponent, self.significand) >= (other.exponent, other.significand) {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let same_sign = a.sign == b.sign;
+        let b = match u32::try_from(a.exponent - b.exponent) {
+            Ok(shift) if shift < 64 * N as u32 => shr(b.significand, shift),
+            _ => return a,
+        };
+
+        let mut significand = a.significand;
+
+        if same_sign {
+            let mut carry = false;
+
+            for (x, y) in significand.iter_mut().zip(b).rev() {
+                let (sum, c1) = x.overflowing_add(y);
+                let (sum, c2) = sum.overflowing_add(u64::from(carry));
+                (*x, carry) = (sum, c1 || c2);
+            }
+
+            if carry {
+                significand = shr(significand, 1);
+                significand[0] |= 1 << 63;
+                return Self {
+                    exponent: a.exponent + 1,
+                    significand,
+                    ..a
+                };
+            }
+
+            return Self { significand, ..a };
+        }
+
+        let mut borrow = false;
+
+        for (x, y) in significand.iter_mut().zip(b).rev() {
+            let (difference, b1) = x.overflowing_sub(y);
+            let (difference, b2) = difference.overflowing_sub(u64::from(borrow));
+            (*x, borrow) = (difference, b1 || b2);
+        }
+
+        Self::normalize(a.sign, a.exponent, significand)
+    }
+}
+
+impl<const N: usize> Sub for Wide<N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const N: usize> Mul for Wide<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        const { assert!(N <= 8) };
+
+        if self.is_zero() || other.is_zero() {
+            return Self::ZERO;
+        }
+
+        // Schoolbook multiplication into 2N words
+        let mut product = [0; 16];
+
+        for i in (0..N).rev() {
+            let mut carry = 0;
+
+            for j in (0..N).rev() {
+                let t = u128::from(self.significand[i]) * u128::from(other.significand[j])
+                    + u128::from(product[i + j + 1])
+                    + carry;
+
+                product[i + j + 1] = t as u64;
+                carry = t >> 64;
+            }
+            product[i] = carry as u64;
+        }
+
+        let sign = self.sign != other.sign;
+        let exponent = self.exponent + other.exponent;
+
+        // The product of normalized significands has at most 1 leading zero.
+        if product[0] >> 63 == 1 {
+            Self {
+                sign,
+                exponent: exponent + 1,
+                significand: core::array::from_fn(|i| product[i]),
+            }
+        } else {
+            Self {
+                sign,
+                exponent,
+                significand: core::array::from_fn(|i| product[i] << 1 | product[i + 1] >> 63),
+            }
+        }
+    }
+}
+
+impl<const N: usize> Mul<i64> for Wide<N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, n: i64) -> Self {
+        self * Self::from_u128(n < 0, n.unsigned_abs().into())
+    }
+}
@@ -0,0 +1 @@
+crate::binary16::functions!(f16);
@@ -0,0 +1,604 @@
+//! Accurate paths of [`crate::f32`] functions
+//!
+//! The functions in this module are called when the fast path in `f64` is
+//! too close to a tie.  They reuse the [`Double`] and [`wide::Wide`]
+//! arithmetic of [`crate::f64`].
+
+use crate::f64::kernel::{self, Double};
+use crate::f64::{
+    cbrt, exact_pow, exp10_split, exp2_split, exp_split, ln_1p_double, ln_double, normalize, wide,
+    Magnitude, LOG10_E, LOG2_E,
+};
+
+/// Correctly rounded `1/√x` in `f32` for a positive finite `f32`
+///
+/// This function is the accurate path of [`crate::f32::rsqrt`].  A step of
+/// Newton's method with the residual in [`Double`] doubles the correct bits
+/// of the approximation in `f64`.  The result is never a tie because the
+/// square of a 25-bit odd significand is odd.
+#[cold]
+pub(crate) fn rsqrt_f32(x: f32) -> f32 {
+    let x = f64::from(x);
+    let y = 1.0 / x.sqrt();
+    let e = -(kernel::two_product(y, y) * x - 1.0).0;
+    kernel::ldexp_f32(kernel::fast_two_sum(y, 0.5 * y * e), 0)
+}
+
+/// Correctly rounded `1/∛x` in `f32` for a nonzero finite `f32`
+///
+/// This function is the accurate path of [`crate::f32::rcbrt`] and works
+/// like [`rsqrt_f32`].
+#[cold]
+pub(crate) fn rcbrt_f32(x: f32) -> f32 {
+    let x = f64::from(x);
+    let y = 1.0 / cbrt(x);
+    let e = -(kernel::two_product(y, y) * y * x - 1.0).0;
+    kernel::ldexp_f32(kernel::fast_two_sum(y, y * e / 3.0), 0)
+}
+
+/// Correctly rounded `2^n * (1 + y) - 1` in `f32`
+///
+/// - `n`: an integer not exceeding 2<sup>7</sup> in magnitude
+/// - `y`: the result of [`exp2_split`] or [`exp10_split`]
+#[inline]
+fn exp_m1_f32(n: f64, y: Double) -> f32 {
+    if n == 0.0 {
+        return kernel::ldexp_f32(y, 0);
+    }
+
+    let scale = crate::exp2i(n as i64);
+    let Double(hi, lo) = kernel::two_sum(scale, -1.0);
+    kernel::ldexp_f32(kernel::sum(hi, y * scale + lo), 0)
+}
+
+/// Correctly rounded `2^x - 1` in `f32` for a finite `f32`
+///
+/// This function is the accurate path of [`crate::f32::exp2m1`].
+#[cold]
+pub(crate) fn exp2m1_f32(x: f32) -> f32 {
+    let (n, y) = exp2_split(x.into());
+    exp_m1_f32(n, y)
+}
+
+/// Correctly rounded `10^x - 1` in `f32` for a finite `f32`
+///
+/// This function is the accurate path of [`crate::f32::exp10m1`].
+#[cold]
+pub(crate) fn exp10m1_f32(x: f32) -> f32 {
+    let (n, y) = exp10_split(x.into());
+    exp_m1_f32(n, y)
+}
+
+/// Correctly rounded `log2(1 + x)` in `f32` for a finite `x > -1`
+///
+/// This function is the accurate path of [`crate::f32::log2p1`].
+#[cold]
+pub(crate) fn log2p1_f32(x: f32) -> f32 {
+    kernel::ldexp_f32(ln_1p_double(x.into()) * LOG2_E, 0)
+}
+
+/// Correctly rounded `log10(1 + x)` in `f32` for a finite `x > -1`
+///
+/// This function is the accurate path of [`crate::f32::log10p1`].
+#[cold]
+pub(crate) fn log10p1_f32(x: f32) -> f32 {
+    kernel::ldexp_f32(ln_1p_double(x.into()) * LOG10_E, 0)
+}
+
+/// Correctly rounded sine and cosine in `f32` for a finite `f32`
+///
+/// This function is the accurate path of trigonometric functions in
+/// [`crate::f32`].  The results are rounded from [`Double`]s with
+/// [`kernel::ldexp_f32`] to avoid double rounding.
+#[cfg(not(feature = "core-math"))]
+#[cold]
+pub(crate) fn sin_cos_f32(x: f32) -> (f32, f32) {
+    let (q, y) = kernel::rem_pio2(f64::from(x).abs());
+    let s = kernel::sin(y);
+    let c = kernel::cos(y);
+
+    let (s, c) = match q & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+
+    let s = if x.is_sign_negative() { -s } else { s };
+    (kernel::ldexp_f32(s, 0), kernel::ldexp_f32(c, 0))
+}
+
+/// Correctly rounded tangent in `f32` for a finite `f32`
+///
+/// This function is like [`sin_cos_f32`] but for the tangent.
+#[cfg(not(feature = "core-math"))]
+#[cold]
+pub(crate) fn tan_f32(x: f32) -> f32 {
+    let (q, y) = kernel::rem_pio2(f64::from(x).abs());
+    let s = kernel::sin(y);
+    let c = kernel::cos(y);
+
+    let y = match q & 1 {
+        0 => s / c,
+        _ => -(c / s),
+    };
+
+    kernel::ldexp_f32(if x.is_sign_negative() { -y } else { y }, 0)
+}
+
+/// Sine and cosine of `x` half-turns in [`Double`] for a finite `f32`
+///
+/// The argument must be below 2<sup>25</sup> in magnitude.
+#[inline]
+fn sin_cos_pi_double(x: f32) -> (Double, Double) {
+    let x = f64::from(x);
+    let n = (2.0 * x).round_ties_even();
+    let y = kernel::PI * (x - 0.5 * n);
+    let (s, c) = (kernel::sin(y), kernel::cos(y));
+
+    match n as i64 & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Correctly rounded sin(πx) and cos(πx) in `f32` for a finite `f32`
+///
+/// This function is the accurate path of π-scaled trigonometric functions in
+/// [`crate::f32`].  The argument must be below 2<sup>25</sup> in magnitude.
+#[cold]
+pub(crate) fn sin_cos_pi_f32(x: f32) -> (f32, f32) {
+    let (s, c) = sin_cos_pi_double(x);
+    (kernel::ldexp_f32(s, 0), kernel::ldexp_f32(c, 0))
+}
+
+/// Correctly rounded tan(πx) in `f32` for a finite `f32`
+///
+/// This function is like [`sin_cos_pi_f32`] but for the tangent.  The argument
+/// must not be a half-integer, where the tangent has a pole.
+#[cold]
+pub(crate) fn tan_pi_f32(x: f32) -> f32 {
+    let (s, c) = sin_cos_pi_double(x);
+    kernel::ldexp_f32(s / c, 0)
+}
+
+/// Absolute value of `atan2` in [`Double`] for finite nonzero `f32`s
+///
+/// The angle is computed like [`crate::f64::atan2`].  If `|y / x|` is tiny
+/// and `x` is positive, the quotient is nudged toward zero for correct
+/// rounding.
+#[inline]
+fn atan2_double(y: f32, x: f32) -> Double {
+    let (a, b) = (f64::from(y.abs()), f64::from(x.abs()));
+
+    if a < b * crate::exp2i(-60) && x.is_sign_positive() {
+        // atan(r) = r - r^3 / 3 is slightly less than r.
+        let r = Double::from(a) / b.into();
+        Double(r.0, r.1 - r.0 * crate::exp2i(-120))
+    } else if x.is_sign_negative() {
+        kernel::PI - kernel::atan2(a.into(), b.into())
+    } else {
+        kernel::atan2(a.into(), b.into())
+    }
+}
+
+/// Correctly rounded `atan2` in `f32` for finite nonzero `f32`s
+///
+/// This function is the accurate path of [`crate::f32::atan2`].  The angle is
+/// rounded with [`kernel::ldexp_f32`].
+#[cold]
+pub(crate) fn atan2_f32(y: f32, x: f32) -> f32 {
+    let angle = atan2_double(y, x);
+    kernel::ldexp_f32(if y.is_sign_negative() { -angle } else { angle }, 0)
+}
+
+/// Correctly rounded `atan2pi` in `f32` for finite nonzero `f32`s
+///
+/// This function is the accurate path of [`crate::f32::atan2pi`].
+#[cold]
+pub(crate) fn atan2pi_f32(y: f32, x: f32) -> f32 {
+    let angle = atan2_double(y, x) / kernel::PI;
+    kernel::ldexp_f32(if y.is_sign_negative() { -angle } else { angle }, 0)
+}
+
+/// Correctly rounded `asinpi` in `f32` for `f32`s in `-1..=1`
+///
+/// This function is the accurate path of [`crate::f32::asinpi`].
+#[cold]
+pub(crate) fn asin_pi_f32(x: f32) -> f32 {
+    let s = f64::from(x.abs());
+    let c = kernel::sqrt(kernel::two_sum(1.0, -s) * kernel::two_sum(1.0, s));
+    let angle = kernel::atan2(s.into(), c) / kernel::PI;
+    kernel::ldexp_f32(if x.is_sign_negative() { -angle } else { angle }, 0)
+}
+
+/// Correctly rounded `acospi` in `f32` for `f32`s in `-1..=1`
+///
+/// This function is the accurate path of [`crate::f32::acospi`].
+#[cold]
+pub(crate) fn acos_pi_f32(x: f32) -> f32 {
+    let s = f64::from(x.abs());
+    let c = kernel::sqrt(kernel::two_sum(1.0, -s) * kernel::two_sum(1.0, s));
+    let angle = kernel::atan2(c, s.into());
+    let angle = if x.is_sign_negative() {
+        kernel::PI - angle
+    } else {
+        angle
+    };
+    kernel::ldexp_f32(angle / kernel::PI, 0)
+}
+
+/// Correctly rounded `atanpi` in `f32` for a non-NaN `f32`
+///
+/// This function is the accurate path of [`crate::f32::atanpi`].
+#[cold]
+pub(crate) fn atan_pi_f32(x: f32) -> f32 {
+    let angle = kernel::atan2(f64::from(x.abs()).into(), 1.0.into()) / kernel::PI;
+    kernel::ldexp_f32(if x.is_sign_negative() { -angle } else { angle }, 0)
+}
+
+/// Round `exp(z)` to `f32` if it is far enough from a tie
+///
+/// - `z`: a [`Double`] with an absolute error below 2<sup>-90</sup>
+///
+/// If the rounding cannot be determined, return `None` so that the caller
+/// can check for exact results and then take the [`wide`] path.
+#[inline]
+fn exp_f32(z: Double) -> Option<f32> {
+    // Far beyond the range of `f32`
+    if z.0 > 100.0 {
+        return Some(f32::INFINITY);
+    }
+
+    if z.0 < -120.0 {
+        return Some(0.0);
+    }
+
+    // Error bound of exp(z) relative to `w`, which is close to 1
+    let error = crate::exp2i(-85);
+
+    let n = (z.0 * core::f64::consts::LOG2_E).round_ties_even();
+    let w = kernel::sum(1.0, kernel::exp_m1(kernel::reduce_ln_2(z, n)));
+    let lower = kernel::ldexp_f32(kernel::fast_two_sum(w.0, w.1 - error), n as i64);
+    let upper = kernel::ldexp_f32(kernel::fast_two_sum(w.0, w.1 + error), n as i64);
+
+    (lower == upper).then_some(lower)
+}
+
+/// Correctly rounded `x^y` in `f32` for an `f32` base
+///
+/// - `x`: a positive finite number
+/// - `y`: a finite number
+///
+/// This function is the accurate path of [`crate::f32::powf`], which is
+/// called when the fast path is too close to a tie.  Rounding
+/// [`crate::f64::pow`]`(x, y)` to `f32` would round twice, so its steps are
+/// repeated with [`kernel::ldexp_f32`] and [`wide::Wide::round_f32`].
+#[cold]
+pub(crate) fn pow_f32(x: f32, y: f64) -> f32 {
+    let x = f64::from(x);
+
+    let Magnitude::Normalized(i) = normalize(x).1 else {
+        return f32::NAN;
+    };
+
+    let (n, z) = kernel::ln(i, 0.0);
+    let z = kernel::join_ln(n, z);
+
+    // Far beyond the range of `f32`, also preventing overflow of `f64`
+    if z.0 * y > 100.0 {
+        return f32::INFINITY;
+    }
+
+    if z.0 * y < -120.0 {
+        return 0.0;
+    }
+
+    if let Some(z) = exp_f32(z * y) {
+        return z;
+    }
+
+    if let Some((z, n)) = exact_pow(x, y) {
+        return kernel::ldexp_f32(z, n);
+    }
+
+    wide::exp(wide::ln(i) * wide::Wide::from(y)).round_f32()
+}
+
+/// Correctly rounded `x^(1/n)` in `f32`
+///
+/// - `x`: a positive finite number
+/// - `n`: a nonzero integer
+///
+/// This function is the accurate path of [`crate::f32::rootn`].  Exact
+/// results are never ties because a root of an `f32` having 25 significant
+/// bits would have too many bits when raised to the `n`th power.
+#[cold]
+pub(crate) fn rootn_f32(x: f32, n: i64) -> f32 {
+    let Magnitude::Normalized(i) = normalize(x.into()).1 else {
+        return f32::NAN;
+    };
+
+    let z = ln_double(f64::from(x).into()) / Double::from(n as f64);
+
+    exp_f32(z).unwrap_or_else(|| {
+        let z = wide::ln(i).div_small(n.unsigned_abs().into());
+        wide::exp(if n < 0 { -z } else { z }).round_f32()
+    })
+}
+
+/// Correctly rounded `(1 + x)^n` in `f32`
+///
+/// - `x`: a finite number above -1
+///
+/// This function is the accurate path of [`crate::f32::compound`].  The
+/// exponent `n` is split into two parts because it can exceed 2<sup>53</sup>
+/// and still matter for tiny `x`.
+#[cold]
+pub(crate) fn compound_f32(x: f32, n: i64) -> f32 {
+    let x = f64::from(x);
+    let hi = n as f64;
+
+    #[allow(clippy::cast_possible_truncation)]
+    let y = kernel::fast_two_sum(hi, (i128::from(n) - hi as i128) as f64);
+    let z = ln_1p_double(x);
+
+    // Far beyond the range of `f32`, also preventing overflow of `f64`
+    if z.0 * hi > 100.0 {
+        return f32::INFINITY;
+    }
+
+    if z.0 * hi < -120.0 {
+        return 0.0;
+    }
+
+    if let Some(z) = exp_f32(z * y) {
+        return z;
+    }
+
+    let base = kernel::two_sum(1.0, x);
+
+    if base.1 == 0.0 && y.1 == 0.0 {
+        if let Some((z, n)) = exact_pow(base.0, hi) {
+            return kernel::ldexp_f32(z, n);
+        }
+    }
+
+    wide::exp(wide::ln_1p(x.into()) * wide::Wide::from(y)).round_f32()
+}
+
+/// 2/√π in [`Double`]
+const FRAC_2_SQRT_PI: Double = Double(core::f64::consts::FRAC_2_SQRT_PI, 1.533_545_961_316_588e-17);
+
+/// `exp(x^2) * erf(x)` in [`Double`] for an `f32` `x` in `0..=3`
+///
+/// The series `2/√π * Σ 2^n x^(2n+1) / (2n+1)!!` has only positive terms, so
+/// it is free from the cancellation in the Maclaurin series of `erf`.
+#[inline]
+fn erf_scaled(x: f64) -> Double {
+    // Exact because `x` is an `f32`
+    let y = 2.0 * x * x;
+
+    let mut term = Double::from(x);
+    let mut sum = term;
+    let mut k = 3.0;
+
+    while term.0 > sum.0 * crate::exp2i(-110) {
+        term = term * y / k.into();
+        sum = sum + term;
+        k += 2.0;
+    }
+
+    sum * FRAC_2_SQRT_PI
+}
+
+/// `exp(x^2) * erfc(x)` in [`Double`] for `x >= 3`
+///
+/// The continued fraction
+///
+/// ```text
+///           exp(-x^2)             1
+/// erfc(x) = --------- * -------------------------
+///              √π             1/2
+///                       x + ---------------------
+///                                    1
+///                           x + -----------------
+///                                       3/2
+///                               x + -------------
+///                                   x + ...
+/// ```
+///
+/// is evaluated from the bottom, deep enough for a relative error below
+/// 2<sup>-100</sup>.
+#[inline]
+fn erfcx_double(x: f64) -> Double {
+    /// 1/√π in [`Double`]
+    const FRAC_1_SQRT_PI: Double = Double(0.564_189_583_547_756_3, 7.667_729_806_582_94e-18);
+
+    let mut k = (270.0 / x).floor() + 8.0;
+    let mut y = Double::from(x);
+
+    while k > 0.0 {
+        y = Double::from(0.5 * k) / y + x;
+        k -= 1.0;
+    }
+
+    FRAC_1_SQRT_PI / y
+}
+
+/// Correctly rounded `erf` in `f32` for `f32`s within `-4..=4`
+///
+/// This function is the accurate path of [`crate::f32::erf`].
+#[cold]
+pub(crate) fn erf_f32(x: f32) -> f32 {
+    let s = f64::from(x.abs());
+    let (n, e) = exp_split((-s * s).into());
+
+    let y = if s <= 3.0 {
+        kernel::ldexp_f32(erf_scaled(s) * e, n)
+    } else {
+        let z = erfcx_double(s) * e * crate::exp2i(n);
+        kernel::ldexp_f32(kernel::sum(1.0, -z), 0)
+    };
+
+    y.copysign(x)
+}
+
+/// `erf` in `f64` for `f32`s within `-4..=4`
+///
+/// This function is the accurate path of [`crate::f16::erf`] and
+/// [`crate::bf16::erf`], where the `f32` result is a tie.
+#[cfg(feature = "half")]
+#[cold]
+pub(crate) fn erf_wide(x: f32) -> f64 {
+    let s = f64::from(x.abs());
+    let (n, e) = exp_split((-s * s).into());
+
+    let y = if s <= 3.0 {
+        (erf_scaled(s) * e).0 * crate::exp2i(n)
+    } else {
+        let z = erfcx_double(s) * e * crate::exp2i(n);
+        kernel::sum(1.0, -z).0
+    };
+
+    y.copysign(x.into())
+}
+
+/// Correctly rounded `erfc` in `f32` for `f32`s within `-4..=10.1`
+///
+/// This function is the accurate path of [`crate::f32::erfc`].
+#[cold]
+pub(crate) fn erfc_f32(x: f32) -> f32 {
+    let s = f64::from(x.abs());
+    let (n, e) = exp_split((-s * s).into());
+
+    if x >= 3.0 {
+        return kernel::ldexp_f32(erfcx_double(s) * e, n);
+    }
+
+    if x > -3.0 {
+        let z = erf_scaled(s) * e * crate::exp2i(n);
+        let z = if x.is_sign_negative() { z } else { -z };
+        return kernel::ldexp_f32(kernel::sum(1.0, z), 0);
+    }
+
+    let z = erfcx_double(s) * e * crate::exp2i(n);
+    kernel::ldexp_f32(kernel::sum(2.0, -z), 0)
+}
+
+/// Correctly rounded `erfcx` in `f32` for finite `f32`s not below -9.4
+///
+/// This function is the accurate path of [`crate::f32::erfcx`].
+#[cold]
+pub(crate) fn erfcx_f32(x: f32) -> f32 {
+    let s = f64::from(x.abs());
+
+    if x >= 3.0 {
+        return kernel::ldexp_f32(erfcx_double(s), 0);
+    }
+
+    let (n, e) = exp_split((s * s).into());
+    let e = e * crate::exp2i(n);
+
+    let z = if x > -3.0 {
+        let z = erf_scaled(s);
+        e + if x.is_sign_negative() { z } else { -z }
+    } else {
+        e * 2.0 - erfcx_double(s)
+    };
+
+    kernel::ldexp_f32(z, 0)
+}
+
+/// Natural logarithm of the gamma function in [`Double`] for `x >= 20`
+///
+/// The Stirling series is summed up to the term of B<sub>30</sub>, where the
+/// absolute error is below 2<sup>-105</sup>.
+#[inline]
+fn ln_gamma_stirling(x: Double) -> Double {
+    /// ln(2π) / 2 in [`Double`]
+    const HALF_LN_2PI: Double = Double(0.918_938_533_204_672_8, -3.878_294_158_067_241_4e-17);
+
+    /// B<sub>2k</sub> / (2k (2k - 1)) for k = 1, 2, ..., 15
+    const C: [Double; 15] = [
+        Double(8.333_333_333_333_333e-2, 4.625_929_269_271_485e-18),
+        Double(-2.777_777_777_777_778e-3, 1.060_108_790_874_715_4e-19),
+        Double(7.936_507_936_507_937e-4, 6.883_823_317_368_282e-22),
+        Double(-5.952_380_952_380_953e-4, 5.369_382_187_547_26e-20),
+        Double(8.417_508_417_508_417e-4, 3.687_017_488_923_769_4e-20),
+        Double(-1.917_526_917_526_917_6e-3, 1.067_570_277_687_247_5e-19),
+        Double(6.410_256_410_256_41e-3, 2.224_004_456_380_521_7e-19),
+        Double(-2.955_065_359_477_124_2e-2, 4.861_760_957_508_855e-19),
+        Double(1.796_443_723_688_305_7e-1, -6.401_600_482_710_946e-19),
+        Double(-1.392_432_216_905_901_1, 1.583_705_698_923_030_3e-17),
+        Double(13.402_864_044_168_393, -6.154_114_101_993_966e-16),
+        Double(-156.848_284_626_002_03, 9.391_823_141_715_389e-15),
+        Double(2_193.103_333_333_333_5, -1.333_925_562_600_294_8e-13),
+        Double(-36_108.771_253_724_99, 5.897_583_353_514_365e-13),
+        Double(691_472.268_851_313, 2.558_529_630_515_8e-11),
+    ];
+
+    let recip = Double::from(1.0) / x;
+    let square = recip * recip;
+    let series = C
+        .iter()
+        .rev()
+        .fold(Double::from(0.0), |y, &c| y * square + c)
+        * recip;
+
+    (x - 0.5) * ln_double(x) - x + HALF_LN_2PI + series
+}
+
+/// Natural logarithm of `|Γ(x)|` in [`Double`] for a non-integer `x > -50`
+///
+/// The argument is shifted up to `w >= 20` with `Γ(x) = Γ(w) / p`, where `p`
+/// is the product of `x`, `x + 1`, ..., `w - 1`.  Every shifted argument is an
+/// exact [`Double`], so there is no error in the shifts.  This function returns
+/// `ln |Γ(x)|` and the sign of `p`, which is also the sign of `Γ(x)`.
+#[inline]
+fn ln_gamma_double(x: Double) -> (Double, bool) {
+    let mut w = x;
+    let mut p = Double::from(1.0);
+
+    while w.0 < 20.0 {
+        p = p * w;
+        w = w + 1.0;
+    }
+
+    let negative = p.0 < 0.0;
+    let p = if negative { -p } else { p };
+    (ln_gamma_stirling(w) - ln_double(p), negative)
+}
+
+/// Correctly rounded `tgamma` in `f32` for non-integer `f32`s within
+/// `-50..=36` or positive integers
+///
+/// This function is the accurate path of [`crate::f32::tgamma`].
+#[cold]
+pub(crate) fn tgamma_f32(x: f32) -> f32 {
+    let (y, negative) = ln_gamma_double(f64::from(x).into());
+    let (n, y) = exp_split(y);
+    kernel::ldexp_f32(if negative { -y } else { y }, n)
+}
+
+/// Correctly rounded `lgamma` in `f32` for positive `f32`s and negative
+/// non-integer `f32`s
+///
+/// This function is the accurate path of [`crate::f32::lgamma`].  Arguments
+/// below -20 are reflected with `Γ(x) Γ(1 - x) = π / sin(πx)`.
+#[cold]
+pub(crate) fn lgamma_f32(x: f32) -> f32 {
+    /// ln(π) in [`Double`]
+    const LN_PI: Double = Double(1.144_729_885_849_400_2, 1.026_595_116_270_782_6e-17);
+
+    if x >= -20.0 {
+        return kernel::ldexp_f32(ln_gamma_double(f64::from(x).into()).0, 0);
+    }
+
+    let s = sin_cos_pi_double(x).0;
+    let s = if s.0 < 0.0 { -s } else { s };
+    let (y, _) = ln_gamma_double(kernel::two_sum(1.0, -f64::from(x)));
+    kernel::ldexp_f32(LN_PI - ln_double(s) - y, 0)
+}
@@ -2,8 +2,8 @@
 #[inline]
 pub const fn fast_ldexp(x: f64, n: i64) -> f64 {
     const SHIFT: u32 = f64::MANTISSA_DIGITS - 1;
-    let wrapped: i64 = unsafe { core::mem::transmute(x) };
-    unsafe { core::mem::transmute(wrapped + (n << SHIFT)) }
+    let wrapped = x.to_bits() as i64;
+    f64::from_bits((wrapped + (n << SHIFT)) as u64)
 }
 
 /// Polynomial approximation of restriction of `(exp(x) - 1) / x`
@@ -56,6 +56,265 @@ pub fn atanh(x: f64) -> f64 {
     crate::mul_add(y, x, x)
 }
 
+/// Rational approximation of `atan(x) / x` restricted to `-1..=1`
+///
+/// In geometry, this function returns the slope of the line between the origin
+/// and the point `(x, atan(x))` on the graph of the arctangent function.
+#[inline]
+pub fn atan_slope(x: f64) -> f64 {
+    fast_polynomial::rational_array(
+        x * x,
+        &[
+            3.300_049_005_002_112e-1,
+            8.269_936_280_545_194e-1,
+            7.536_692_262_484_512e-1,
+            3.041_250_192_035_205_3e-1,
+            5.258_546_450_061_43e-2,
+            3.092_811_576_351_314e-3,
+            2.668_044_628_603_543_2e-5,
+        ],
+        &[
+            3.300_049_005_002_111_4e-1,
+            9.369_952_615_545_891e-1,
+            1.0,
+            4.972_028_574_382_380_6e-1,
+            1.155_090_051_164_766_6e-1,
+            1.090_224_520_186_812_4e-2,
+            2.732_269_307_955_130_4e-4,
+        ],
+    )
+}
+
+/// Arctangent of an `f64` for the [`crate::f32`] module
+///
+/// Arguments outside `-1..=1` are reduced with `atan(x) = ±π/2 - atan(1/x)`.
+#[inline]
+pub fn atan(x: f64) -> f64 {
+    if x.abs() > 1.0 {
+        use core::f64::consts::FRAC_PI_2;
+        let recip = x.recip();
+        crate::mul_add(-recip, atan_slope(recip), FRAC_PI_2.copysign(x))
+    } else {
+        x * atan_slope(x)
+    }
+}
+
+/// Arcsine restricted to `-1..=1`
+#[inline]
+pub fn asin(x: f64) -> f64 {
+    let sin = x.abs();
+
+    if sin < 0.5 {
+        let y = x * x;
+        let y = y * crate::poly(
+            y,
+            &[
+                0.166_666_666_666_669_9,
+                0.074_999_999_996_942_23,
+                0.044_642_857_621_259_74,
+                0.030_381_915_298_596_9,
+                0.022_373_067_117_079_332,
+                0.017_336_338_079_820_927,
+                0.014_148_729_679_087_69,
+                0.010_245_724_097_753_366,
+                0.015_594_752_512_270_386,
+                -0.007_104_188_100_086_482_5,
+                0.028_097_370_567_441_11,
+            ],
+        );
+
+        return crate::mul_add(y, x, x);
+    }
+
+    let y = crate::poly(
+        sin,
+        &[
+            -1.570_795_268_727_950_5,
+            2.145_844_720_538_429_6e-1,
+            -8.891_815_130_471_556e-2,
+            5.019_724_059_139_869e-2,
+            -3.183_089_187_114_656e-2,
+            2.021_070_346_378_044_8e-2,
+            -1.159_335_145_410_863_3e-2,
+            5.441_837_134_625_65e-3,
+            -1.883_759_349_016_505e-3,
+            4.174_581_850_783_569e-4,
+            -4.385_109_488_852_58e-5,
+        ],
+    );
+
+    crate::mul_add((1.0 - sin).sqrt(), y, core::f64::consts::FRAC_PI_2).copysign(x)
+}
+
+/// Arccosine restricted to `-1..=1`
+#[inline]
+pub fn acos(x: f64) -> f64 {
+    let y = crate::poly(
+        x.abs(),
+        &[
+            1.570_796_326_794_895_7,
+            -2.146_018_366_019_891_2e-1,
+            8.904_862_249_163_578e-2,
+            -5.079_281_229_679_732e-2,
+            3.368_124_006_642_692e-2,
+            -2.437_373_657_488_884e-2,
+            1.866_733_156_855_094_8e-2,
+            -1.485_707_820_782_702e-2,
+            1.209_263_142_298_289e-2,
+            -9.833_619_072_388_472e-3,
+            7.685_589_578_218_092e-3,
+            -5.463_397_714_481_38e-3,
+            3.307_640_446_073_071_4e-3,
+            -1.585_712_772_872_508_6e-3,
+            5.515_942_277_755_394e-4,
+            -1.219_755_299_410_277_6e-4,
+            1.275_454_772_275_258_2e-5,
+        ],
+    ) * (1.0 - x.abs()).sqrt();
+
+    if x.is_sign_positive() {
+        y
+    } else {
+        core::f64::consts::PI - y
+    }
+}
+
+/// Polynomial approximation of `erf(x) / x` restricted to `-1..=1`
+#[inline]
+pub fn erf_slope(x: f64) -> f64 {
+    crate::poly(
+        x * x,
+        &[
+            core::f64::consts::FRAC_2_SQRT_PI,
+            -3.761_263_890_318_354_3e-1,
+            1.128_379_167_094_500_6e-1,
+            -2.686_617_064_323_777e-2,
+            5.223_977_607_116_422_5e-3,
+            -8.548_325_975_389_692e-4,
+            1.205_529_490_483_970_7e-4,
+            -1.492_473_690_741_966e-5,
+            1.644_742_470_331_736_2e-6,
+            -1.620_848_380_187_170_5e-7,
+            1.372_006_454_677_768_6e-8,
+            -7.795_898_827_002_142e-10,
+        ],
+    )
+}
+
+/// Scaled complementary error function `exp(x^2) * erfc(x)` for `x >= 1`
+///
+/// The half-line is mapped onto `-0.5..=1` by `y = (x - 3) / (x + 3)`, where
+/// `(1 + 2x) * erfcx(x)` is approximated by a polynomial in `y`.  This
+/// function also works for huge `x` but not for infinity.
+#[inline]
+pub fn erfcx(x: f64) -> f64 {
+    let y = crate::poly(
+        (x - 3.0) / (x + 3.0),
+        &[
+            1.253_008_058_269_729_8,
+            -1.356_211_061_245_772_8e-1,
+            -4.756_229_435_354_963e-2,
+            1.296_451_587_022_835_5e-1,
+            -1.192_736_634_084_846_6e-1,
+            6.830_802_735_823_34e-2,
+            -2.377_051_510_444_066e-2,
+            2.529_391_698_900_136_3e-3,
+            1.888_692_883_226_197_9e-3,
+            -7.798_331_289_708_187e-4,
+            -1.051_781_241_234_271_8e-4,
+            1.251_194_461_248_095e-4,
+            5.016_980_509_942_449_4e-6,
+            -2.022_970_040_150_319e-5,
+            -8.152_135_932_705_829e-7,
+            3.829_580_753_643_228e-6,
+            2.023_119_280_786_983_6e-7,
+            -9.483_724_003_340_061e-7,
+            2.680_619_743_279_258e-7,
+            -1.466_760_956_524_082_6e-8,
+        ],
+    );
+    y / crate::mul_add(2.0, x, 1.0)
+}
+
+/// Polynomial approximation of `(1/Γ(1 + x) - 1) / x` restricted to
+/// `-0.5..=0.5`
+///
+/// In geometry, this function returns the slope of the secant line between the
+/// points `(0, 1)` and `(x, 1/Γ(1 + x))` on the graph of the reciprocal gamma
+/// function shifted by 1.
+#[inline]
+pub fn recip_gamma_slope(x: f64) -> f64 {
+    crate::poly(
+        x,
+        &[
+            5.772_156_649_015_329e-1,
+            -6.558_780_715_202_536e-1,
+            -4.200_263_503_409_53e-2,
+            1.665_386_113_822_489_6e-1,
+            -4.219_773_455_553_454e-2,
+            -9.621_971_526_040_088e-3,
+            7.218_943_246_239_921e-3,
+            -1.165_167_626_827_000_8e-3,
+            -2.152_416_660_527_446_6e-4,
+            1.280_506_239_587_225_2e-4,
+            -2.013_493_363_870_309_3e-5,
+            -1.252_278_906_195_395_9e-6,
+            1.133_440_415_683_063_4e-6,
+            -2.008_902_942_654_642_6e-7,
+            5.012_871_942_505_546e-9,
+        ],
+    )
+}
+
+/// Natural logarithm of the gamma function for `x >= 8`
+///
+/// This function evaluates the Stirling series
+/// `(x - 1/2) ln(x) - x + ln(2π) / 2 + 1 / 12x - ...`, whose tail is
+/// approximated by a polynomial in `1/x^2`.
+#[inline]
+pub fn ln_gamma(x: f64) -> f64 {
+    /// ln(2π) / 2
+    const HALF_LN_2PI: f64 = 0.918_938_533_204_672_8;
+
+    let recip = x.recip();
+    let tail = recip
+        * crate::poly(
+            recip * recip,
+            &[
+                8.333_333_333_333_333e-2,
+                -2.777_777_777_773_988e-3,
+                7.936_507_897_524_059e-4,
+                -5.952_365_845_057_049e-4,
+                8.414_700_852_503_147e-4,
+                -1.890_382_993_887_715e-3,
+                5.065_905_360_156_132e-3,
+            ],
+        );
+
+    let ln = core::f64::consts::LN_2 * log2(x);
+    crate::mul_add(x - 0.5, ln, -x) + (HALF_LN_2PI + tail)
+}
+
+/// Compute `ln(1 + x)` for `x > -1` accurately especially for small `x`
+#[inline]
+pub fn ln_1p(x: f64) -> f64 {
+    use crate::f64::EXP_SHIFT;
+    use core::f64::consts::{FRAC_1_SQRT_2, LN_2};
+
+    #[allow(clippy::cast_possible_wrap)]
+    let i = (1.0 + x).to_bits() as i64;
+
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = (i - FRAC_1_SQRT_2.to_bits() as i64) >> EXP_SHIFT;
+
+    #[allow(clippy::cast_sign_loss)]
+    let y = f64::from_bits((i - (exponent << EXP_SHIFT)) as u64);
+    let z = if exponent == 0 { x } else { y - 1.0 };
+
+    #[allow(clippy::cast_precision_loss)]
+    crate::mul_add(-LN_2, -exponent as f64, 2.0 * atanh(z / (z + 2.0)))
+}
+
 /// Base 2 logarithm for a finite positive `f64`
 #[inline]
 pub fn log2(x: f64) -> f64 {
@@ -79,63 +338,181 @@ pub fn log2(x: f64) -> f64 {
     )
 }
 
-/// [`f64::exp2`] with precision of `f32`
+/// Sines of multiples of π/64
 ///
-/// This function is especially useful for computing `x`<sup>`y`</sup> when
-/// combined with [`log2`].
-#[inline]
-pub fn exp2(x: f64) -> f64 {
-    if x < (f64::MIN_EXP - 1).into() {
-        return 0.0;
-    }
-
-    if x > f64::MAX_EXP.into() {
-        return f64::INFINITY;
-    }
-
-    let n = x.round_ties_even();
-    let x = crate::poly(
-        x - n,
-        &[
-            1.0,
-            6.931_471_880_289_533e-1,
-            2.402_265_108_421_173_5e-1,
-            5.550_357_105_498_874_4e-2,
-            9.618_030_771_171_498e-3,
-            1.339_086_685_300_951e-3,
-            1.546_973_499_989_028_8e-4,
-        ],
-    );
-
-    #[allow(clippy::cast_possible_truncation)]
-    return fast_ldexp(x, n as i64);
-}
+/// The cosine of a multiple of π/64 is also here with an offset of 32.
+/// Exact zeros make sure that sine and cosine near their zeros are computed
+/// without cancellation.
+const SIN_PI_64: [f64; 128] = [
+    0.0,
+    0.049_067_674_327_418_015,
+    0.098_017_140_329_560_6,
+    0.146_730_474_455_361_75,
+    0.195_090_322_016_128_28,
+    0.242_980_179_903_263_9,
+    0.290_284_677_254_462_4,
+    0.336_889_853_392_220_05,
+    0.382_683_432_365_089_8,
+    0.427_555_093_430_282_1,
+    0.471_396_736_825_997_64,
+    0.514_102_744_193_221_8,
+    0.555_570_233_019_602_2,
+    0.595_699_304_492_433_4,
+    0.634_393_284_163_645_5,
+    0.671_558_954_847_018_4,
+    core::f64::consts::FRAC_1_SQRT_2,
+    0.740_951_125_354_959_1,
+    0.773_010_453_362_737,
+    0.803_207_531_480_644_9,
+    0.831_469_612_302_545_2,
+    0.857_728_610_000_272_1,
+    0.881_921_264_348_355,
+    0.903_989_293_123_443_3,
+    0.923_879_532_511_286_7,
+    0.941_544_065_183_020_8,
+    0.956_940_335_732_208_8,
+    0.970_031_253_194_544,
+    0.980_785_280_403_230_4,
+    0.989_176_509_964_781,
+    0.995_184_726_672_196_9,
+    0.998_795_456_205_172_4,
+    1.0,
+    0.998_795_456_205_172_4,
+    0.995_184_726_672_196_9,
+    0.989_176_509_964_781,
+    0.980_785_280_403_230_4,
+    0.970_031_253_194_544,
+    0.956_940_335_732_208_8,
+    0.941_544_065_183_020_8,
+    0.923_879_532_511_286_7,
+    0.903_989_293_123_443_3,
+    0.881_921_264_348_355,
+    0.857_728_610_000_272_1,
+    0.831_469_612_302_545_2,
+    0.803_207_531_480_644_9,
+    0.773_010_453_362_737,
+    0.740_951_125_354_959_1,
+    core::f64::consts::FRAC_1_SQRT_2,
+    0.671_558_954_847_018_4,
+    0.634_393_284_163_645_5,
+    0.595_699_304_492_433_4,
+    0.555_570_233_019_602_2,
+    0.514_102_744_193_221_8,
+    0.471_396_736_825_997_64,
+    0.427_555_093_430_282_1,
+    0.382_683_432_365_089_8,
+    0.336_889_853_392_220_05,
+    0.290_284_677_254_462_4,
+    0.242_980_179_903_263_9,
+    0.195_090_322_016_128_28,
+    0.146_730_474_455_361_75,
+    0.098_017_140_329_560_6,
+    0.049_067_674_327_418_015,
+    0.0,
+    -0.049_067_674_327_418_015,
+    -0.098_017_140_329_560_6,
+    -0.146_730_474_455_361_75,
+    -0.195_090_322_016_128_28,
+    -0.242_980_179_903_263_9,
+    -0.290_284_677_254_462_4,
+    -0.336_889_853_392_220_05,
+    -0.382_683_432_365_089_8,
+    -0.427_555_093_430_282_1,
+    -0.471_396_736_825_997_64,
+    -0.514_102_744_193_221_8,
+    -0.555_570_233_019_602_2,
+    -0.595_699_304_492_433_4,
+    -0.634_393_284_163_645_5,
+    -0.671_558_954_847_018_4,
+    -core::f64::consts::FRAC_1_SQRT_2,
+    -0.740_951_125_354_959_1,
+    -0.773_010_453_362_737,
+    -0.803_207_531_480_644_9,
+    -0.831_469_612_302_545_2,
+    -0.857_728_610_000_272_1,
+    -0.881_921_264_348_355,
+    -0.903_989_293_123_443_3,
+    -0.923_879_532_511_286_7,
+    -0.941_544_065_183_020_8,
+    -0.956_940_335_732_208_8,
+    -0.970_031_253_194_544,
+    -0.980_785_280_403_230_4,
+    -0.989_176_509_964_781,
+    -0.995_184_726_672_196_9,
+    -0.998_795_456_205_172_4,
+    -1.0,
+    -0.998_795_456_205_172_4,
+    -0.995_184_726_672_196_9,
+    -0.989_176_509_964_781,
+    -0.980_785_280_403_230_4,
+    -0.970_031_253_194_544,
+    -0.956_940_335_732_208_8,
+    -0.941_544_065_183_020_8,
+    -0.923_879_532_511_286_7,
+    -0.903_989_293_123_443_3,
+    -0.881_921_264_348_355,
+    -0.857_728_610_000_272_1,
+    -0.831_469_612_302_545_2,
+    -0.803_207_531_480_644_9,
+    -0.773_010_453_362_737,
+    -0.740_951_125_354_959_1,
+    -core::f64::consts::FRAC_1_SQRT_2,
+    -0.671_558_954_847_018_4,
+    -0.634_393_284_163_645_5,
+    -0.595_699_304_492_433_4,
+    -0.555_570_233_019_602_2,
+    -0.514_102_744_193_221_8,
+    -0.471_396_736_825_997_64,
+    -0.427_555_093_430_282_1,
+    -0.382_683_432_365_089_8,
+    -0.336_889_853_392_220_05,
+    -0.290_284_677_254_462_4,
+    -0.242_980_179_903_263_9,
+    -0.195_090_322_016_128_28,
+    -0.146_730_474_455_361_75,
+    -0.098_017_140_329_560_6,
+    -0.049_067_674_327_418_015,
+];
 
 /// Argument reduction for trigonometric functions
 ///
-/// - `x`: finite radians with a positive sign bit
-///
-/// The prototype of this function resembles `__rem_pio2` in GCC, but this
-/// function is only for `f32`.  Pseudocode is as follows.
+/// - `x`: finite radians
 ///
-/// ```text
-/// quotient = nearest integer of x / (π/2)
-/// y = x - quotient * (π/2) // IEEE remainder of x / (π/2)
-/// (quotient, y)
-/// ```
+/// This function returns `(q, r)` such that `x = (q + r) * π/64`, where `q` is
+/// an integer and `|r| <= 0.5`.  The lowest 7 bits of the returned quotient
+/// are accurate.  Besides a relative error of rounding, the absolute error of
+/// `r` is below 2<sup>-60</sup>.
 ///
-/// The lowest 2 bits of the returned quotient are accurate.
+/// The argument must be below 2<sup>19</sup> in magnitude, so that its product
+/// with the leading part of the two-part 64/π is exact.  Larger arguments go
+/// through [`rem_pio64_huge`] instead.
 #[inline]
-pub fn rem_pio2(x: f32) -> (i64, f64) {
-    use core::f64::consts;
-    debug_assert!(x.is_sign_positive());
+pub fn rem_pio64(x: f32) -> (i64, f64) {
+    /// 64/π with the highest 29 bits
+    const FRAC_64_PI_HI: f64 = 20.371_832_728_385_925;
+
+    /// Bits of 64/π below [`FRAC_64_PI_HI`]
+    const FRAC_64_PI_LO: f64 = -1.262_332_231_455_162_9e-8;
 
-    /// π/2 with the highest [`f32::MANTISSA_DIGITS`] (24) bits
-    const PI_2_HI: f64 = 1.570_796_310_901_641_8;
+    /// Adding this number rounds a `f64` below 2<sup>51</sup> in magnitude
+    /// to an integer, which is then stored in the lowest bits.
+    const ROUND: f64 = 6_755_399_441_055_744.0;
 
-    /// Bits of π/2 below [`PI_2_HI`]
-    const PI_2_LO: f64 = 1.589_325_477_352_819_6e-8;
+    let x: f64 = x.into();
+    let q = crate::mul_add(x, FRAC_64_PI_HI, ROUND);
+    let r = crate::mul_add(x, FRAC_64_PI_HI, ROUND - q);
+    let r = crate::mul_add(x, FRAC_64_PI_LO, r);
+
+    #[allow(clippy::cast_possible_wrap)]
+    (q.to_bits() as i64, r)
+}
 
+/// [`rem_pio64`] for arguments not below 2<sup>19</sup> in magnitude
+///
+/// The product of `x` and 2/π is computed as wide integers in the style of
+/// Payne and Hanek.  Only the lowest 7 bits of the quotient are returned.
+#[inline(never)]
+pub fn rem_pio64_huge(x: f32) -> (i64, f64) {
     /// Little-endian 256 bits of 2/π
     const FRAC_2_PI: [u64; 4] = [
         0xFE51_63AB_DEBB_C561,
@@ -144,74 +521,535 @@ pub fn rem_pio2(x: f32) -> (i64, f64) {
         0xA2F9_836E_4E44_1529,
     ];
 
-    if x < core::f32::consts::PI * crate::exp2i(27) as f32 {
-        let x: f64 = x.into();
-        let q = (x * consts::FRAC_2_PI).round_ties_even();
-        let y = crate::mul_add(q, -PI_2_HI, x);
-        let y = crate::mul_add(q, -PI_2_LO, y);
-
-        // SAFETY: q < 2^28
-        return (unsafe { q.to_int_unchecked() }, y);
-    }
-
-    let magnitude = x.to_bits();
+    let magnitude = x.to_bits() & 0x7FFF_FFFF;
     let significand: u128 = ((magnitude & 0x007F_FFFF) | 0x0080_0000).into();
     let p0 = significand * u128::from(FRAC_2_PI[0]);
     let p1 = significand * u128::from(FRAC_2_PI[1]) + (p0 >> 64);
     let p2 = significand * u128::from(FRAC_2_PI[2]) + (p1 >> 64);
     let high = significand * u128::from(FRAC_2_PI[3]) + (p2 >> 64);
     let low = p2 << 64 | p1 << 64 >> 64;
-    let shift = (magnitude >> super::EXP_SHIFT) - 150;
+
+    // Fixed-point |x| * 64/π with 64 fractional bits
+    let shift = (magnitude >> super::EXP_SHIFT) - 145;
     let product = high << shift | low >> (128 - shift);
     let r = product as i64;
-    let q = (product >> 64) as i64;
+    let q = ((product >> 64) as i64).wrapping_sub(r >> 63) & 127;
+    let r = r as f64 * crate::exp2i(-64);
 
-    (
-        q.wrapping_sub(r >> 63),
-        consts::PI * crate::exp2i(-65) * r as f64,
+    if x.is_sign_negative() {
+        (-q, -r)
+    } else {
+        (q, r)
+    }
+}
+
+/// Exact argument reduction for π-scaled trigonometric functions
+///
+/// - `x`: finite half-turns
+///
+/// This function returns `(q, r)` such that `x = (q + r) / 64`, where `q` is
+/// an integer and `|r| <= 0.5`.  The reduction is exact because `64 * x` is
+/// an exact `f64`.  Arguments not below 2<sup>24</sup> in magnitude are even
+/// integers, for which `(0, 0.0)` is returned.
+#[inline]
+pub fn rem_frac_1_64(x: f32) -> (i64, f64) {
+    let x = 64.0 * f64::from(x);
+
+    if x.abs() >= crate::exp2i(30) {
+        return (0, 0.0);
+    }
+
+    // Adding this number rounds `x` to an integer stored in the lowest bits.
+    const ROUND: f64 = 6_755_399_441_055_744.0;
+
+    let q = x + ROUND;
+
+    #[allow(clippy::cast_possible_wrap)]
+    (q.to_bits() as i64, x - (q - ROUND))
+}
+
+/// Sine of `(q + r) * π/64` for `|r| <= 0.5`
+///
+/// The arguments are meant to come from [`rem_pio64`] or [`rem_frac_1_64`].
+/// The cosine is also computed by this function with `q + 32`.  The relative
+/// error is below 2<sup>-43</sup> if `r` is exact.  Otherwise, the bound holds
+/// as long as the absolute error of `r` is below 2<sup>-60</sup> and the
+/// result is not below 2<sup>-15</sup> in magnitude.
+#[inline]
+pub fn sin(q: i64, r: f64) -> f64 {
+    #[allow(clippy::cast_sign_loss)]
+    let (sin, cos) = (
+        SIN_PI_64[(q & 127) as usize],
+        SIN_PI_64[((q + 32) & 127) as usize],
+    );
+
+    let r2 = r * r;
+    let s = crate::poly(
+        r2,
+        &[
+            4.908_738_521_234_045_5e-2,
+            -1.971_325_980_679_792_8e-5,
+            2.374_974_222_986_180_6e-9,
+        ],
+    );
+    let c = crate::mul_add(r2, 2.419_132_349_856_822_4e-7, -1.204_785_693_340_431_6e-3);
+
+    // sin(a + b) = sin(a) + sin(a) * (cos(b) - 1) + cos(a) * sin(b)
+    crate::mul_add(cos * r, s, crate::mul_add(sin * r2, c, sin))
+}
+
+/// Round a result of [`sin`] or a quotient of them to `f32` if it is far
+/// enough from a tie
+///
+/// - `ulps`: the error bound in units of the last place of `f64`
+///
+/// Results beyond 2<sup>-15</sup>..2<sup>15</sup> in magnitude are rejected
+/// because the absolute error of [`rem_pio64`] may dominate.  If the rounding
+/// cannot be determined, return `None` for a more accurate path to take over.
+#[cfg(not(feature = "core-math"))]
+#[inline]
+pub fn round_trig(y: f64, ulps: u64) -> Option<f32> {
+    // Doubled bits of 2^-15 and 2^15
+    const MIN: u64 = 0x3F00_0000_0000_0000 << 1;
+    const MAX: u64 = 0x40E0_0000_0000_0000 << 1;
+
+    // Half an ulp of `f32` in the significand of `f64`
+    const TIE: u64 = 1 << 28;
+
+    // Bitwise AND rather than short-circuit saves a branch in the fast path.
+    let bits = y.to_bits();
+    let far = bits.wrapping_sub(TIE - ulps) & (2 * TIE - 1) > 2 * ulps;
+    let moderate = (bits << 1).wrapping_sub(MIN) < MAX - MIN;
+    (far & moderate).then_some(y as f32)
+}
+
+/// Round `y` to `f32` if it is far enough from a tie
+///
+/// - `error`: the bound of the absolute error of `y`
+///
+/// If the rounding of `y` cannot be determined, return `None` for a more
+/// accurate path to take over.
+#[inline]
+pub fn round(y: f64, error: f64) -> Option<f32> {
+    let lower = (y - error) as f32;
+    let upper = (y + error) as f32;
+    (lower == upper).then_some(lower)
+}
+
+/// A zero of a Bessel function with the Taylor expansion about it
+///
+/// The zero is `hi + lo`, where `hi` is the nearest `f64`.  The coefficients
+/// of the Taylor series start from the linear term.
+pub struct BesselZero {
+    hi: f64,
+    lo: f64,
+    taylor: [f64; 5],
+}
+
+/// Evaluate a Bessel function with the Taylor expansion about a nearby zero
+///
+/// If `x` is within 2<sup>-8</sup> of one of the `zeros`, this function
+/// returns the result with relative accuracy.  Otherwise, return `None` so
+/// that a general approximation can take over without catastrophic
+/// cancellation.
+#[inline]
+fn near_bessel_zero(x: f64, zeros: &[BesselZero]) -> Option<f64> {
+    zeros.iter().find_map(|zero| {
+        // `x - zero.hi` is exact by the Sterbenz lemma.
+        let d = (x - zero.hi) - zero.lo;
+        (d.abs() < crate::exp2i(-8)).then(|| d * crate::poly(d, &zero.taylor))
+    })
+}
+
+/// Zeros of J<sub>0</sub> below 8
+const J0_ZEROS: [BesselZero; 2] = [
+    BesselZero {
+        hi: 2.404_825_557_695_773,
+        lo: -1.176_691_651_530_894e-16,
+        taylor: [
+            -5.191_474_972_894_667e-1,
+            1.079_387_017_549_201e-1,
+            5.660_177_443_794_623e-2,
+            -8.657_669_593_304_914e-3,
+            -2.194_200_359_016_156e-3,
+        ],
+    },
+    BesselZero {
+        hi: 5.520_078_110_286_311,
+        lo: 8.088_597_146_146_722e-17,
+        taylor: [
+            3.402_648_065_583_681_6e-1,
+            -3.082_065_142_559_364_8e-2,
+            -5.298_855_286_760_463e-2,
+            4.631_042_145_907_645e-3,
+            2.257_440_229_032_9e-3,
+        ],
+    },
+];
+
+/// Positive zeros of J<sub>1</sub> below 8
+const J1_ZEROS: [BesselZero; 2] = [
+    BesselZero {
+        hi: 3.831_705_970_207_512_5,
+        lo: -1.526_918_409_008_806_7e-16,
+        taylor: [
+            -4.027_593_957_025_53e-1,
+            5.255_614_585_697_724e-2,
+            5.341_044_413_272_481e-2,
+            -5.179_719_245_638_57e-3,
+            -2.233_125_339_147_478e-3,
+        ],
+    },
+    BesselZero {
+        hi: 7.015_586_669_815_619,
+        lo: -9.414_165_653_410_389e-17,
+        taylor: [
+            3.001_157_525_261_325_4e-1,
+            -2.138_921_280_934_158e-2,
+            -4.697_047_894_974_149e-2,
+            3.130_291_726_048_091e-3,
+            2.105_587_143_248_276_4e-3,
+        ],
+    },
+];
+
+/// Zeros of Y<sub>0</sub> below 8
+const Y0_ZEROS: [BesselZero; 3] = [
+    BesselZero {
+        hi: 8.935_769_662_791_675e-1,
+        lo: 2.659_623_153_972_038_5e-17,
+        taylor: [
+            8.794_208_024_971_948e-1,
+            -4.920_789_342_629_775_5e-1,
+            2.205_528_284_816_764_3e-1,
+            -2.261_217_135_441_633_6e-1,
+            2.189_484_270_103_813e-1,
+        ],
+    },
+    BesselZero {
+        hi: 3.957_678_419_314_858,
+        lo: -1.076_434_069_756_270_6e-16,
+        taylor: [
+            -4.025_426_717_750_242_3e-1,
+            5.085_590_959_215_824e-2,
+            5.852_382_210_517_024_4e-2,
+            -6.852_566_677_111_002e-3,
+            -2.183_518_874_049_356_5e-3,
+        ],
+    },
+    BesselZero {
+        hi: 7.086_051_060_301_773,
+        lo: -8.835_285_723_085_408e-17,
+        taylor: [
+            3.000_976_149_104_752e-1,
+            -2.117_523_655_676_953e-2,
+            -4.802_407_007_625_987_5e-2,
+            3.318_348_268_895_631_5e-3,
+            2.175_984_016_443_104e-3,
+        ],
+    },
+];
+
+/// Zeros of Y<sub>1</sub> below 8
+const Y1_ZEROS: [BesselZero; 2] = [
+    BesselZero {
+        hi: 2.197_141_326_031_017,
+        lo: -4.825_983_587_645_496_6e-17,
+        taylor: [
+            5.207_864_124_022_675e-1,
+            -1.185_145_457_490_966e-1,
+            -3.285_739_740_528_641e-2,
+            -4.797_811_670_105_437e-3,
+            7.422_553_332_707_468_5e-3,
+        ],
+    },
+    BesselZero {
+        hi: 5.429_681_040_794_135,
+        lo: 4.162_514_026_670_377e-16,
+        taylor: [
+            -3.403_180_455_234_406e-1,
+            3.133_867_744_408_668_6e-2,
+            5.094_793_974_342_009_4e-2,
+            -4.160_115_934_390_690_5e-3,
+            -2.165_998_751_088_117e-3,
+        ],
+    },
+];
+
+/// Polynomial approximation of J<sub>0</sub> restricted to `0..8`
+#[inline]
+fn j0_small(x: f64) -> f64 {
+    crate::poly(
+        x * x,
+        &[
+            9.999_999_999_999_992e-1,
+            -2.499_999_999_999_944_8e-1,
+            1.562_499_999_999_356_6e-2,
+            -4.340_277_777_748_114_3e-4,
+            6.781_684_027_061_221e-6,
+            -6.781_684_017_348_278e-8,
+            4.709_502_697_928_388e-10,
+            -2.402_806_902_834_508_2e-12,
+            9.385_937_152_686_968e-15,
+            -2.896_804_394_028_807e-17,
+            7.239_884_758_966_251e-20,
+            -1.492_248_869_974_170_5e-22,
+            2.548_379_393_485_915e-25,
+            -3.440_117_259_269_303_6e-28,
+            2.861_668_363_417_903e-31,
+        ],
     )
 }
 
-/// Cosine restricted to `-π/4..=π/4`
+/// Polynomial approximation of J<sub>1</sub>(x) / x restricted to `0..8`
+///
+/// In geometry, this function returns the slope of the line between the origin
+/// and the point `(x, J₁(x))` on the graph of the Bessel function.
 #[inline]
-pub fn cos(x: f64) -> f32 {
+fn j1_slope(x: f64) -> f64 {
     crate::poly(
         x * x,
         &[
-            1.0,
-            -4.999_999_999_999_946_7e-1,
-            4.166_666_666_650_087e-2,
-            -1.388_888_887_158_942_7e-3,
-            2.480_157_897_844_104e-5,
-            -2.755_529_138_739_507_4e-7,
-            2.063_333_980_512_758_6e-9,
+            5.0e-1,
+            -6.249_999_999_999_982e-2,
+            2.604_166_666_666_459_7e-3,
+            -5.425_347_222_212_688_4e-5,
+            6.781_684_027_547_502e-7,
+            -5.651_403_353_130_409e-9,
+            3.363_930_537_487_64e-11,
+            -1.501_754_510_782_725_9e-13,
+            5.214_416_528_089_021e-16,
+            -1.448_419_936_476_904_3e-18,
+            3.291_174_755_814_770_3e-21,
+            -6.221_644_875_673_282e-24,
+            9.833_568_021_840_737e-27,
+            -1.244_085_002_948_803_6e-29,
+            9.872_972_394_640_96e-33,
         ],
-    ) as f32
+    )
 }
 
-/// Sine restricted to `-π/4..=π/4`
+/// Compute `m * sin(x + φ)` for `x >= 8`, where `φ` is in units of π/64
+///
+/// The phase `φ` is `offset + phase`, where `offset` is an integer and
+/// `phase` is a small correction.  The argument is reduced by [`rem_pio64`]
+/// so that results near zeros retain relative accuracy.
 #[inline]
-pub fn sin(x: f64) -> f32 {
-    let y = x * x;
-    let y = y * crate::poly(
-        y,
+fn shifted_sin(x: f32, m: f64, offset: i64, phase: f64) -> f64 {
+    let (q, r) = if x < 524_288.0 {
+        rem_pio64(x)
+    } else {
+        rem_pio64_huge(x)
+    };
+
+    let r = r + phase;
+    let k = r.round();
+    m * sin(q + offset + k as i64, r - k)
+}
+
+/// Modulus and phase of Bessel functions of order 0 for `x >= 8`
+///
+/// Given J<sub>0</sub>(x) = M cos θ and Y<sub>0</sub>(x) = M sin θ, this
+/// function returns `(M, ψ)`, where θ = x - π/4 + ψ and `ψ` is in units of
+/// π/64.  Both are approximated by polynomials in `1/x^2`.
+#[inline]
+fn modulus_phase_0(x: f64) -> (f64, f64) {
+    let recip = x.recip();
+    let u = recip * recip;
+
+    let m = crate::poly(
+        u,
+        &[
+            9.999_999_999_999_991e-1,
+            -6.249_999_998_844_815e-2,
+            1.035_156_000_498_358_6e-1,
+            -5.428_253_479_888_548e-1,
+            5.839_185_916_549_46,
+            -104.350_103_702_730_79,
+            2_528.083_144_414_704,
+            -63_210.789_325_580_52,
+            1_198_835.972_928_245_8,
+            -11_500_035.957_072_478,
+        ],
+    );
+
+    let psi = crate::poly(
+        u,
         &[
-            -1.666_666_666_666_663e-1,
-            8.333_333_333_321_917e-3,
-            -1.984_126_982_945_719_3e-4,
-            2.755_731_358_196_805e-6,
-            -2.505_074_230_488_205e-8,
-            1.589_594_452_434_234_8e-10,
+            -2.546_479_089_470_324_6,
+            1.326_291_192_416_128_3,
+            -4.269_331_288_722_35,
+            33.370_316_872_014_33,
+            -478.163_713_631_268_37,
+            10_879.890_191_154_35,
+            -353_216.830_310_118_8,
+            14_347_726.892_921_032,
+            -612_839_992.418_810_8,
+            23_081_192_697.123_535,
+            -656_805_341_079.029_7,
+            11_956_910_168_633.768,
+            -102_034_474_480_494.52,
         ],
     );
-    crate::mul_add(y, x, x) as f32
+
+    (
+        m * (core::f64::consts::FRAC_2_PI * recip).sqrt(),
+        psi * recip,
+    )
 }
 
+/// Modulus and phase of Bessel functions of order 1 for `x >= 8`
+///
+/// Given J<sub>1</sub>(x) = M cos θ and Y<sub>1</sub>(x) = M sin θ, this
+/// function returns `(M, ψ)`, where θ = x - 3π/4 + ψ and `ψ` is in units of
+/// π/64.  Both are approximated by polynomials in `1/x^2`.
 #[inline]
-pub fn apply_sign(x: f32, sign: bool) -> f32 {
-    if sign {
-        -x
-    } else {
-        x
+fn modulus_phase_1(x: f64) -> (f64, f64) {
+    let recip = x.recip();
+    let u = recip * recip;
+
+    let m = crate::poly(
+        u,
+        &[
+            1.000_000_000_000_001,
+            1.874_999_999_867_318e-1,
+            -1.933_593_463_505_636e-1,
+            8.052_733_697_424_369e-1,
+            -7.729_044_728_051_792,
+            129.856_986_466_085_54,
+            -3_040.501_306_204_402_7,
+            74_690.803_025_388_01,
+            -1_404_062.130_815_753,
+            13_406_467.588_749_47,
+        ],
+    );
+
+    let psi = crate::poly(
+        u,
+        &[
+            7.639_437_268_410_975,
+            -3.342_253_804_911_487,
+            7.555_880_856_333_916,
+            -48.268_878_705_204_47,
+            623.791_447_381_509_4,
+            -13_392.887_364_844_903,
+            419_629.514_818_295_37,
+            -16_680_542.273_287_093,
+            703_715_768.830_788,
+            -26_323_308_294.307_354,
+            746_185_769_186.076_3,
+            -13_553_005_615_488.207,
+            115_487_697_042_341.95,
+        ],
+    );
+
+    (
+        m * (core::f64::consts::FRAC_2_PI * recip).sqrt(),
+        psi * recip,
+    )
+}
+
+/// Bessel function of the first kind of order 0 for finite `x >= 0`
+#[inline]
+pub fn j0(x: f32) -> f64 {
+    let x64 = f64::from(x);
+
+    if x64 >= 8.0 {
+        // J₀(x) = M sin(θ + π/2)
+        let (m, psi) = modulus_phase_0(x64);
+        return shifted_sin(x, m, 16, psi);
+    }
+
+    near_bessel_zero(x64, &J0_ZEROS).unwrap_or_else(|| j0_small(x64))
+}
+
+/// Bessel function of the first kind of order 1 for finite `x >= 0`
+#[inline]
+pub fn j1(x: f32) -> f64 {
+    let x64 = f64::from(x);
+
+    if x64 >= 8.0 {
+        // J₁(x) = M sin(θ + π/2)
+        let (m, psi) = modulus_phase_1(x64);
+        return shifted_sin(x, m, -16, psi);
+    }
+
+    near_bessel_zero(x64, &J1_ZEROS).unwrap_or_else(|| x64 * j1_slope(x64))
+}
+
+/// Bessel function of the second kind of order 0 for finite `x > 0`
+#[inline]
+pub fn y0(x: f32) -> f64 {
+    use core::f64::consts::{FRAC_2_PI, LN_2};
+    let x64 = f64::from(x);
+
+    if x64 >= 8.0 {
+        let (m, psi) = modulus_phase_0(x64);
+        return shifted_sin(x, m, -16, psi);
     }
+
+    near_bessel_zero(x64, &Y0_ZEROS).unwrap_or_else(|| {
+        // Y₀(x) = 2/π ln(x) J₀(x) + R(x^2), where R is entire
+        let r = crate::poly(
+            x64 * x64,
+            &[
+                -7.380_429_510_868_552e-2,
+                1.776_060_168_690_552e-1,
+                -1.607_396_802_592_448_2e-2,
+                5.386_026_668_552_263e-4,
+                -9.495_005_203_668_556e-6,
+                1.035_847_601_102_298_8e-7,
+                -7.693_079_686_009_736e-10,
+                4.143_564_334_618_075_5e-12,
+                -1.693_265_048_873_228e-14,
+                5.430_845_983_149_640_3e-17,
+                -1.403_355_952_000_118e-19,
+                2.978_294_506_188_630_7e-22,
+                -5.216_562_621_757_524e-25,
+                7.188_505_995_164_877e-28,
+                -6.067_815_288_554_263e-31,
+            ],
+        );
+        let ln = LN_2 * log2(x64);
+        crate::mul_add(FRAC_2_PI * ln, j0_small(x64), r)
+    })
+}
+
+/// Bessel function of the second kind of order 1 for finite `x > 0`
+#[inline]
+pub fn y1(x: f32) -> f64 {
+    use core::f64::consts::{FRAC_2_PI, LN_2};
+    let x64 = f64::from(x);
+
+    if x64 >= 8.0 {
+        let (m, psi) = modulus_phase_1(x64);
+        return shifted_sin(x, m, -48, psi);
+    }
+
+    near_bessel_zero(x64, &Y1_ZEROS).unwrap_or_else(|| {
+        // Y₁(x) = 2/π (ln(x) J₁(x) - 1/x) + x S(x^2), where S is entire
+        let s = crate::poly(
+            x64 * x64,
+            &[
+                -1.960_570_906_462_388_9e-1,
+                5.434_868_816_050_985_5e-2,
+                -2.955_305_336_079_381e-3,
+                7.164_268_749_953_094e-5,
+                -9.926_740_618_920_772e-7,
+                8.931_879_613_884_633e-9,
+                -5.648_024_481_878_785e-11,
+                2.649_481_052_347_659_7e-13,
+                -9.591_465_615_083_484e-16,
+                2.761_566_426_464_564_4e-18,
+                -6.474_823_840_671_726e-21,
+                1.258_321_401_462_102_7e-23,
+                -2.037_470_050_249_508_2e-26,
+                2.629_444_633_130_607_6e-29,
+                -2.116_614_854_434_736e-32,
+            ],
+        );
+        let ln = LN_2 * log2(x64);
+        let j1 = x64 * j1_slope(x64);
+        crate::mul_add(FRAC_2_PI, crate::mul_add(ln, j1, -x64.recip()), x64 * s)
+    })
 }
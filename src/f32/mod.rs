@@ -1,6 +1,9 @@
 #![allow(clippy::pedantic)]
 #![warn(clippy::unreadable_literal)]
 
+mod accurate;
+#[cfg(feature = "half")]
+pub(crate) use accurate::erf_wide;
 mod kernel;
 use core::cmp::Ordering;
 use core::f32;
@@ -128,6 +131,60 @@ pub fn cbrt(x: f32) -> f32 {
     y as f32
 }
 
+/// Reciprocal of the square root
+///
+/// The result is correctly rounded, unlike `1.0 / x.sqrt()`, which rounds
+/// twice.
+#[must_use]
+#[inline]
+pub fn rsqrt(x: f32) -> f32 {
+    /// Taylor coefficients of `(1 - e)^(-1/2)` from the linear term
+    const C: [f64; 4] = [0.5, 0.375, 0.3125, 0.273_437_5];
+
+    let (false, Magnitude::Normalized(magnitude)) = normalize(x) else {
+        return if x < 0.0 { f32::NAN } else { 1.0 / x };
+    };
+
+    let magnitude = (0x5F37_642E - magnitude / 2) as u32;
+    let x: f64 = x.into();
+    let y: f64 = f32::from_bits(magnitude).into();
+
+    // The residual `e` is `1 - x * y^2`, which quintuples the correct bits.
+    let e = crate::mul_add(-x * y, y, 1.0);
+    let y = crate::mul_add(y * e, crate::poly(e, &C), y);
+    let e = crate::mul_add(-x * y, y, 1.0);
+    let y = crate::mul_add(y * e, crate::poly(e, &C), y);
+
+    kernel::round(y, y * crate::exp2i(-48)).unwrap_or_else(|| accurate::rsqrt_f32(x as f32))
+}
+
+/// Reciprocal of the cube root
+///
+/// The result is correctly rounded, unlike `1.0 / x.cbrt()`, which rounds
+/// twice.
+#[must_use]
+#[inline]
+pub fn rcbrt(x: f32) -> f32 {
+    /// Taylor coefficients of `(1 - e)^(-1/3)` from the linear term
+    const C: [f64; 4] = [1.0 / 3.0, 2.0 / 9.0, 14.0 / 81.0, 35.0 / 243.0];
+
+    let (sign, Magnitude::Normalized(magnitude)) = normalize(x) else {
+        return 1.0 / x;
+    };
+
+    let magnitude = (0x54A2_32A3 - magnitude / 3) as u32;
+    let x: f64 = x.into();
+    let y: f64 = f32::from_bits(u32::from(sign) << 31 | magnitude).into();
+
+    // The residual `e` is `1 - x * y^3`, which quintuples the correct bits.
+    let e = crate::mul_add(-x * y, y * y, 1.0);
+    let y = crate::mul_add(y * e, crate::poly(e, &C), y);
+    let e = crate::mul_add(-x * y, y * y, 1.0);
+    let y = crate::mul_add(y * e, crate::poly(e, &C), y);
+
+    kernel::round(y, y.abs() * crate::exp2i(-48)).unwrap_or_else(|| accurate::rcbrt_f32(x as f32))
+}
+
 /// Hypotenuse of a right-angled triangle with sides `x` and `y`
 #[must_use]
 #[inline]
@@ -239,13 +296,25 @@ pub fn exp2(x: f32) -> f32 {
     kernel::fast_ldexp(x, n as i64) as f32
 }
 
+/// Split `x` into `(n, r)` such that `10^x = 2^n * 10^r`
+///
+/// The integer `n` is rounded from `x * log2(10)`, so `r` is within about
+/// `-0.5 * log10(2)..=0.5 * log10(2)`.
+#[inline]
+fn split_exp10(x: f64) -> (f64, f64) {
+    const LOG10_2_HI: f64 = 0.301_029_995_664_066_5;
+    const LOG10_2_LO: f64 = -8.532_344_317_057_107e-14;
+
+    let n = (x * core::f64::consts::LOG2_10).round_ties_even();
+    let r = crate::mul_add(n, -LOG10_2_HI, x);
+    (n, crate::mul_add(n, -LOG10_2_LO, r))
+}
+
 /// Raise 10 to the power of `x`
 #[must_use]
 #[inline]
 pub fn exp10(x: f32) -> f32 {
     use core::f32::consts::LOG10_2;
-    const LOG10_2_HI: f64 = 0.301_029_995_664_066_5;
-    const LOG10_2_LO: f64 = -8.532_344_317_057_107e-14;
 
     if x < (f32::MIN_EXP - f32::MANTISSA_DIGITS as i32 - 1) as f32 * LOG10_2 {
         return 0.0;
@@ -255,10 +324,7 @@ pub fn exp10(x: f32) -> f32 {
         return f32::INFINITY;
     }
 
-    let x: f64 = x.into();
-    let n = (x * core::f64::consts::LOG2_10).round_ties_even();
-    let x = crate::mul_add(n, -LOG10_2_HI, x);
-    let x = crate::mul_add(n, -LOG10_2_LO, x);
+    let (n, x) = split_exp10(x.into());
     let x = crate::poly(
         x,
         &[
@@ -307,6 +373,73 @@ pub fn exp_m1(x: f32) -> f32 {
     (kernel::fast_ldexp(crate::mul_add(x, y, 1.0), n as i64) - 1.0) as f32
 }
 
+/// Compute `2^x - 1` accurately especially for small `x`
+#[must_use]
+#[inline]
+pub fn exp2m1(x: f32) -> f32 {
+    use core::f64::consts::LN_2;
+
+    if x < -((f32::MANTISSA_DIGITS + 2) as f32) {
+        return -1.0;
+    }
+
+    if x > f32::MAX_EXP as f32 {
+        return f32::INFINITY;
+    }
+
+    if x.is_nan() {
+        return x;
+    }
+
+    let x64: f64 = x.into();
+    let n = x64.round_ties_even();
+
+    let y = if n == 0.0 {
+        let r = x64 * LN_2;
+        r * kernel::exp_slope(r)
+    } else {
+        let r = (x64 - n) * LN_2;
+        let scale = crate::exp2i(n as i64);
+        crate::mul_add(scale, r * kernel::exp_slope(r), scale - 1.0)
+    };
+
+    kernel::round(y, y.abs() * crate::exp2i(-48)).unwrap_or_else(|| accurate::exp2m1_f32(x))
+}
+
+/// Compute `10^x - 1` accurately especially for small `x`
+#[must_use]
+#[inline]
+pub fn exp10m1(x: f32) -> f32 {
+    use core::f32::consts::LOG10_2;
+    use core::f64::consts::LN_10;
+
+    if x < -((f32::MANTISSA_DIGITS + 2) as f32) * LOG10_2 {
+        return -1.0;
+    }
+
+    if x > f32::MAX_EXP as f32 * LOG10_2 {
+        return f32::INFINITY;
+    }
+
+    if x.is_nan() {
+        return x;
+    }
+
+    let x64: f64 = x.into();
+    let (n, r) = split_exp10(x64);
+
+    let y = if n == 0.0 {
+        let r = x64 * LN_10;
+        r * kernel::exp_slope(r)
+    } else {
+        let r = r * LN_10;
+        let scale = crate::exp2i(n as i64);
+        crate::mul_add(scale, r * kernel::exp_slope(r), scale - 1.0)
+    };
+
+    kernel::round(y, y.abs() * crate::exp2i(-48)).unwrap_or_else(|| accurate::exp10m1_f32(x))
+}
+
 /// Multiply `x` by 2 raised to the power of `n`
 #[must_use]
 #[inline]
@@ -344,6 +477,222 @@ pub fn frexp(x: f32) -> (f32, i32) {
     )
 }
 
+/// Return value of [`ilogb`] for zero
+pub const FP_ILOGB0: i32 = i32::MIN;
+
+/// Return value of [`ilogb`] for NaN
+pub const FP_ILOGBNAN: i32 = i32::MIN;
+
+/// Unbiased exponent of `x` as an integer
+///
+/// Subnormal numbers are normalized, so the result is `⌊log2 |x|⌋` for
+/// nonzero finite `x`.  Otherwise, the result is [`FP_ILOGB0`] for zero,
+/// [`FP_ILOGBNAN`] for NaN, and [`i32::MAX`] for infinity.
+#[must_use]
+#[inline]
+pub fn ilogb(x: f32) -> i32 {
+    match normalize(x).1 {
+        Magnitude::Nan => FP_ILOGBNAN,
+        Magnitude::Infinite => i32::MAX,
+        Magnitude::Zero => FP_ILOGB0,
+        Magnitude::Normalized(magnitude) => (magnitude >> EXP_SHIFT) - (f32::MAX_EXP - 1),
+    }
+}
+
+/// Unbiased exponent of `x` as a floating-point number
+///
+/// This function is exact, and it signals a pole at zero like [`ln`].
+#[must_use]
+#[inline]
+pub fn logb(x: f32) -> f32 {
+    match normalize(x).1 {
+        Magnitude::Nan => x,
+        Magnitude::Infinite => f32::INFINITY,
+        Magnitude::Zero => f32::NEG_INFINITY,
+        Magnitude::Normalized(magnitude) => ((magnitude >> EXP_SHIFT) - (f32::MAX_EXP - 1)) as f32,
+    }
+}
+
+/// Multiply `x` by 2 raised to the power of `n`
+///
+/// This is an alias of [`ldexp`] because `f32` is binary.
+#[must_use]
+#[inline]
+pub fn scalbn(x: f32, n: i32) -> f32 {
+    ldexp(x, n)
+}
+
+/// [`scalbn`] with a wider exponent
+#[must_use]
+#[inline]
+pub fn scalbln(x: f32, n: i64) -> f32 {
+    // Saturation is harmless because `i32` already overflows any `f32`
+    ldexp(x, n.clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+}
+
+/// Split `x` into its fractional and integral parts
+///
+/// Both parts have the same sign as `x`.  The fractional part of an
+/// infinity is zero.
+#[must_use]
+#[inline]
+pub fn modf(x: f32) -> (f32, f32) {
+    let integral = x.trunc();
+
+    let fractional = match normalize(x).1 {
+        Magnitude::Infinite => 0.0,
+        _ => x - integral,
+    };
+
+    (fractional.copysign(x), integral)
+}
+
+/// The next representable number after `x` in the direction of `y`
+///
+/// If `x == y`, the result is `y`, so the sign of zero follows `y`.
+#[must_use]
+#[inline]
+pub fn nextafter(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    match x.partial_cmp(&y) {
+        Some(Ordering::Less) => next_up(x),
+        Some(Ordering::Greater) => next_down(x),
+        _ => y,
+    }
+}
+
+/// [`nextafter`] with the direction given in `f64`
+///
+/// This is the closest analogue of C's `nexttowardf` since Rust has no
+/// `long double`.
+#[must_use]
+#[inline]
+pub fn nexttoward(x: f32, y: f64) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return (f64::from(x) + y) as f32;
+    }
+
+    match f64::from(x).partial_cmp(&y) {
+        Some(Ordering::Less) => next_up(x),
+        Some(Ordering::Greater) => next_down(x),
+        _ => y as f32,
+    }
+}
+
+/// Remainder and low bits of the quotient of `|x| / |y|` truncated toward 0
+///
+/// - `x`: a finite `f32`
+/// - `y`: a nonzero finite `f32`
+///
+/// The integer significands are divided in long division, 40 bits at a
+/// time, so that even the largest exponent difference takes only 7 steps.
+/// The quotient of the last step holds at least the lowest 40 bits of the
+/// whole quotient.  The returned remainder is exact and nonnegative.
+#[inline]
+fn rem_quo(x: f32, y: f32) -> (f32, u64) {
+    const STEP: u32 = 40;
+
+    // Split `x` into `m * 2^(e - 150)`, where subnormal numbers share the
+    // exponent with `f32::MIN_POSITIVE` and keep their significands as is.
+    #[inline]
+    fn split(x: f32) -> (u64, u32) {
+        let bits = x.abs().to_bits();
+        let mask = f32::MIN_POSITIVE.to_bits() - 1;
+
+        match bits >> EXP_SHIFT {
+            0 => (bits.into(), 1),
+            e => ((bits & mask | 1 << EXP_SHIFT).into(), e),
+        }
+    }
+
+    let (mx, ex) = split(x);
+    let (my, ey) = split(y);
+
+    if ex < ey {
+        return (x.abs(), 0);
+    }
+
+    let difference = ex - ey;
+    let r = mx << (difference % STEP);
+    let (mut q, mut r) = (r / my, r % my);
+
+    for _ in 0..difference / STEP {
+        r <<= STEP;
+        (q, r) = (r / my, r % my);
+    }
+
+    // Exact because the remainder is a multiple of ulp(y) below |y|
+    let r = r as f64 * crate::exp2i(i64::from(ey) - 150);
+    (r as f32, q)
+}
+
+/// Remainder of `x / y` with the quotient truncated toward zero
+///
+/// The result is exact and has the same sign as `x`.
+#[must_use]
+#[inline]
+pub fn fmod(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() || x.is_infinite() || y == 0.0 {
+        return f32::NAN;
+    }
+
+    if y.is_infinite() {
+        return x;
+    }
+
+    rem_quo(x, y).0.copysign(x)
+}
+
+/// IEEE 754 remainder of `x / y` with the quotient rounded to nearest
+///
+/// The quotient is rounded half to even, so the result is within
+/// `-|y|/2..=|y|/2` and exact.
+#[must_use]
+#[inline]
+pub fn remainder(x: f32, y: f32) -> f32 {
+    remquo(x, y).0
+}
+
+/// [`remainder`] with the lowest 31 bits of the quotient
+///
+/// The quotient has the sign of `x / y`.  Its lowest bits are useful for
+/// argument reduction, e.g. to find the octant of an angle.
+#[must_use]
+#[inline]
+pub fn remquo(x: f32, y: f32) -> (f32, i32) {
+    if x.is_nan() || y.is_nan() || x.is_infinite() || y == 0.0 {
+        return (f32::NAN, 0);
+    }
+
+    if y.is_infinite() {
+        return (x, 0);
+    }
+
+    let (r, q) = rem_quo(x, y);
+    let s = y.abs();
+    let twice = 2.0 * f64::from(r);
+
+    // `r - s` is exact by the Sterbenz lemma.
+    let (r, q) = if twice > s.into() || (twice == s.into() && q & 1 == 1) {
+        (r - s, q + 1)
+    } else {
+        (r, q)
+    };
+
+    let q = (q & 0x7FFF_FFFF) as i32;
+    let r = if x.is_sign_negative() { -r } else { r };
+    let q = if x.is_sign_negative() == y.is_sign_negative() {
+        q
+    } else {
+        -q
+    };
+
+    (r, q)
+}
+
 /// Natural logarithm
 #[must_use]
 #[inline]
@@ -394,19 +743,37 @@ pub fn ln_1p(x: f32) -> f32 {
         1.278_378_4e23 => 53.20505,
         5.498_306e28 => 66.17683,
         x if x < -1.0 || x.is_nan() => f32::NAN,
+        _ => kernel::ln_1p(x.into()) as f32,
+    }
+}
+
+/// Compute `log2(1 + x)` accurately especially for small `x`
+#[must_use]
+#[inline]
+pub fn log2p1(x: f32) -> f32 {
+    match x {
+        f32::INFINITY => f32::INFINITY,
+        -1.0 => f32::NEG_INFINITY,
+        x if x < -1.0 || x.is_nan() => f32::NAN,
         _ => {
-            use core::f64::consts::FRAC_1_SQRT_2;
-            let x: f64 = x.into();
-            let i = (1.0 + x).to_bits() as i64;
-            let exponent = (i - FRAC_1_SQRT_2.to_bits() as i64) >> crate::f64::EXP_SHIFT;
-            let y = f64::from_bits((i - (exponent << crate::f64::EXP_SHIFT)) as u64);
-            let z = if exponent == 0 { x } else { y - 1.0 };
+            let y = core::f64::consts::LOG2_E * kernel::ln_1p(x.into());
+            kernel::round(y, y.abs() * crate::exp2i(-48)).unwrap_or_else(|| accurate::log2p1_f32(x))
+        }
+    }
+}
 
-            crate::mul_add(
-                -core::f64::consts::LN_2,
-                -exponent as f64,
-                2.0 * kernel::atanh(z / (z + 2.0)),
-            ) as f32
+/// Compute `log10(1 + x)` accurately especially for small `x`
+#[must_use]
+#[inline]
+pub fn log10p1(x: f32) -> f32 {
+    match x {
+        f32::INFINITY => f32::INFINITY,
+        -1.0 => f32::NEG_INFINITY,
+        x if x < -1.0 || x.is_nan() => f32::NAN,
+        _ => {
+            let y = core::f64::consts::LOG10_E * kernel::ln_1p(x.into());
+            kernel::round(y, y.abs() * crate::exp2i(-48))
+                .unwrap_or_else(|| accurate::log10p1_f32(x))
         }
     }
 }
@@ -482,10 +849,58 @@ pub fn log(x: f32, base: f32) -> f32 {
     (log2(x) / log2(base)) as f32
 }
 
+/// Round `2^t` to `f32` if it is far enough from a tie
+///
+/// - `t`: an approximation whose absolute error is below 2<sup>-42</sup>
+///
+/// The result is first approximated in `f64` with a relative error below
+/// 2<sup>-40</sup>, which suffices unless the result is close to a tie.  In
+/// that case, return `None` for a more accurate path to take over.
+#[inline]
+fn round_exp2(t: f64) -> Option<f32> {
+    if t > 128.0 {
+        return Some(f32::INFINITY);
+    }
+
+    if t < -151.0 {
+        return Some(0.0);
+    }
+
+    let n = t.round_ties_even();
+    let r = (t - n) * core::f64::consts::LN_2;
+    let z = crate::mul_add(r, kernel::exp_slope(r), 1.0);
+    let z = kernel::fast_ldexp(z, n as i64);
+
+    // Error bound of `z` relative to itself
+    let error = z * crate::exp2i(-40);
+    kernel::round(z, error)
+}
+
+/// Correctly rounded `x^y` for a positive finite `x` and a finite `y`
+///
+/// The product `y * log2(x)` must not exceed 2<sup>7</sup> much unless the
+/// result overflows or underflows anyway.  Hard cases fall back to the slower
+/// but more accurate machinery behind [`crate::f64::pow`].
+#[inline]
+fn finite_pow(x: f32, y: f64) -> f32 {
+    round_exp2(y * kernel::log2(x.into())).unwrap_or_else(|| accurate::pow_f32(x, y))
+}
+
+/// Check if `x` is a signaling NaN
+#[inline]
+fn is_signaling(x: f32) -> bool {
+    x.is_nan() && x.to_bits() & 1 << (EXP_SHIFT - 1) == 0
+}
+
 #[cfg(feature = "core-math")]
 pub use core_math::powf;
 
 /// Raise to a floating-point power
+///
+/// The result is correctly rounded.  It is first approximated in `f64` with
+/// a relative error below 2<sup>-40</sup>, which suffices unless the result
+/// is close to a tie.  Such hard cases fall back to the slower but more
+/// accurate machinery behind [`crate::f64::pow`].
 #[cfg(not(feature = "core-math"))]
 #[must_use]
 #[inline]
@@ -509,7 +924,15 @@ pub fn powf(x: f32, y: f32) -> f32 {
             _ => match x {
                 1.0 => 1.0,
                 x if x.is_sign_negative() => f32::NAN,
-                _ => kernel::exp2(f64::from(y) * kernel::log2(x.into())) as f32,
+                _ if y.is_nan() => f32::NAN,
+                x if y.is_infinite() => {
+                    if (x < 1.0) == (y < 0.0) {
+                        f32::INFINITY
+                    } else {
+                        0.0
+                    }
+                }
+                _ => finite_pow(x, y.into()),
             },
         }
     }
@@ -519,6 +942,11 @@ pub fn powf(x: f32, y: f32) -> f32 {
         x.trunc().eq(&x)
     }
 
+    // Unlike quiet NaNs, signaling NaNs propagate through `1^y` and `x^0`.
+    if is_signaling(x) || is_signaling(y) {
+        return x + y;
+    }
+
     if y == 0.0 {
         return 1.0;
     }
@@ -531,47 +959,219 @@ pub fn powf(x: f32, y: f32) -> f32 {
     magnitude(x, y)
 }
 
-/// Inverse hyperbolic tangent
+/// Raise to an integer power
+///
+/// Unlike [`powf`], this function takes any `i64` exponent, whose parity
+/// determines the sign of the result for negative `x`.  The result is
+/// correctly rounded.
 #[must_use]
 #[inline]
-pub fn atanh(x: f32) -> f32 {
-    match x.abs().partial_cmp(&1.0) {
-        Some(core::cmp::Ordering::Less) => {
-            use crate::f64::EXP_SHIFT;
-            use core::f64::consts;
-
-            let x: f64 = x.into();
-            let i = ((1.0 + x) / (1.0 - x)).to_bits() as i64;
-            let exponent = (i - consts::FRAC_1_SQRT_2.to_bits() as i64) >> EXP_SHIFT;
+pub fn pown(x: f32, n: i64) -> f32 {
+    if n == 0 && !is_signaling(x) {
+        return 1.0;
+    }
 
-            if exponent == 0 {
-                return kernel::atanh(x) as f32;
+    let magnitude = match x.abs() {
+        0.0 => {
+            if n > 0 {
+                0.0
+            } else {
+                f32::INFINITY
             }
-
-            let x = f64::from_bits((i - (exponent << EXP_SHIFT)) as u64);
-
-            crate::mul_add(
-                0.5 * consts::LN_2,
-                exponent as f64,
-                kernel::atanh((x - 1.0) / (x + 1.0)),
-            ) as f32
         }
-        Some(core::cmp::Ordering::Equal) => f32::INFINITY.copysign(x),
-        _ => f32::NAN,
+        f32::INFINITY => {
+            if n > 0 {
+                f32::INFINITY
+            } else {
+                0.0
+            }
+        }
+        s if s.is_nan() => return x + x,
+        s => finite_pow(s, n as f64),
+    };
+
+    if x.is_sign_negative() && n & 1 != 0 {
+        -magnitude
+    } else {
+        magnitude
     }
 }
 
-/// Inverse hyperbolic sine
+/// Compute the `n`th root of `x`
+///
+/// Odd roots of negative numbers are negative, while even roots of them are
+/// NaN.  The 0th root is always NaN.  The result is correctly rounded.
 #[must_use]
 #[inline]
-pub fn asinh(x: f32) -> f32 {
-    use crate::f64::EXP_SHIFT;
-    use core::f64::consts;
-    let s = x.abs();
+pub fn rootn(x: f32, n: i64) -> f32 {
+    let odd = n & 1 != 0;
 
-    let magnitude = match s {
-        f32::INFINITY => f32::INFINITY,
-        2.901_895_4e7 => 17.876_608,
+    if x.is_nan() {
+        return x + x;
+    }
+
+    if n == 0 || (x < 0.0 && !odd) {
+        return f32::NAN;
+    }
+
+    let magnitude = match x.abs() {
+        0.0 => {
+            if n > 0 {
+                0.0
+            } else {
+                f32::INFINITY
+            }
+        }
+        f32::INFINITY => {
+            if n > 0 {
+                f32::INFINITY
+            } else {
+                0.0
+            }
+        }
+        s => round_exp2(kernel::log2(s.into()) / n as f64)
+            .unwrap_or_else(|| accurate::rootn_f32(s, n)),
+    };
+
+    if x.is_sign_negative() && odd {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Raise to a floating-point power defined as `exp(y * ln(x))`
+///
+/// Special cases follow `powr` in IEEE 754, which differs from [`powf`] in
+/// that `x` is never treated as an integer power.  Negative `x` results in
+/// NaN, and so do `0^0`, `inf^0` and `1^inf`.  The result is correctly
+/// rounded.
+#[must_use]
+#[inline]
+pub fn powr(x: f32, y: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    if x < 0.0 {
+        return f32::NAN;
+    }
+
+    match (x, y) {
+        (0.0 | f32::INFINITY, 0.0) | (1.0, f32::INFINITY | f32::NEG_INFINITY) => f32::NAN,
+        (0.0, _) => {
+            if y > 0.0 {
+                0.0
+            } else {
+                f32::INFINITY
+            }
+        }
+        (f32::INFINITY, _) => {
+            if y > 0.0 {
+                f32::INFINITY
+            } else {
+                0.0
+            }
+        }
+        (_, 0.0) | (1.0, _) => 1.0,
+        _ if y.is_infinite() => {
+            if (x < 1.0) == (y < 0.0) {
+                f32::INFINITY
+            } else {
+                0.0
+            }
+        }
+        _ => finite_pow(x, y.into()),
+    }
+}
+
+/// Compute the compound interest `(1 + x)^n`
+///
+/// This function is accurate even if `x` is tiny and `n` is huge, where
+/// rounding `1 + x` would lose most significant bits of the result.  The
+/// result is correctly rounded.
+#[must_use]
+#[inline]
+pub fn compound(x: f32, n: i64) -> f32 {
+    if x.is_nan() {
+        return if n == 0 && !is_signaling(x) {
+            1.0
+        } else {
+            x + x
+        };
+    }
+
+    if x < -1.0 {
+        return f32::NAN;
+    }
+
+    if n == 0 || x == 0.0 {
+        return 1.0;
+    }
+
+    match x {
+        -1.0 => {
+            if n > 0 {
+                0.0
+            } else {
+                f32::INFINITY
+            }
+        }
+        f32::INFINITY => {
+            if n > 0 {
+                f32::INFINITY
+            } else {
+                0.0
+            }
+        }
+        _ => {
+            let t = n as f64 * (core::f64::consts::LOG2_E * kernel::ln_1p(x.into()));
+            round_exp2(t).unwrap_or_else(|| accurate::compound_f32(x, n))
+        }
+    }
+}
+
+/// Inverse hyperbolic tangent
+#[must_use]
+#[inline]
+pub fn atanh(x: f32) -> f32 {
+    match x.abs().partial_cmp(&1.0) {
+        Some(core::cmp::Ordering::Less) => {
+            use crate::f64::EXP_SHIFT;
+            use core::f64::consts;
+
+            let x: f64 = x.into();
+            let i = ((1.0 + x) / (1.0 - x)).to_bits() as i64;
+            let exponent = (i - consts::FRAC_1_SQRT_2.to_bits() as i64) >> EXP_SHIFT;
+
+            if exponent == 0 {
+                return kernel::atanh(x) as f32;
+            }
+
+            let x = f64::from_bits((i - (exponent << EXP_SHIFT)) as u64);
+
+            crate::mul_add(
+                0.5 * consts::LN_2,
+                exponent as f64,
+                kernel::atanh((x - 1.0) / (x + 1.0)),
+            ) as f32
+        }
+        Some(core::cmp::Ordering::Equal) => f32::INFINITY.copysign(x),
+        _ => f32::NAN,
+    }
+}
+
+/// Inverse hyperbolic sine
+#[must_use]
+#[inline]
+pub fn asinh(x: f32) -> f32 {
+    use crate::f64::EXP_SHIFT;
+    use core::f64::consts;
+    let s = x.abs();
+
+    let magnitude = match s {
+        f32::INFINITY => f32::INFINITY,
+        2.901_895_4e7 => 17.876_608,
         6.391_892e22 => 53.20505,
         2.749_153e28 => 66.17683,
         s if s.is_nan() => f32::NAN,
@@ -712,37 +1312,10 @@ pub fn tanh(x: f32) -> f32 {
 #[must_use]
 #[inline]
 pub fn acos(x: f32) -> f32 {
-    let y = {
-        let x: f64 = x.abs().into();
-        crate::poly(
-            x,
-            &[
-                1.570_796_326_794_895_7,
-                -2.146_018_366_019_891_2e-1,
-                8.904_862_249_163_578e-2,
-                -5.079_281_229_679_732e-2,
-                3.368_124_006_642_692e-2,
-                -2.437_373_657_488_884e-2,
-                1.866_733_156_855_094_8e-2,
-                -1.485_707_820_782_702e-2,
-                1.209_263_142_298_289e-2,
-                -9.833_619_072_388_472e-3,
-                7.685_589_578_218_092e-3,
-                -5.463_397_714_481_38e-3,
-                3.307_640_446_073_071_4e-3,
-                -1.585_712_772_872_508_6e-3,
-                5.515_942_277_755_394e-4,
-                -1.219_755_299_410_277_6e-4,
-                1.275_454_772_275_258_2e-5,
-            ],
-        ) * (1.0 - x).sqrt()
-    };
-
     match x {
         1.589_325_5e-8 => 1.570_796_4,
         2.486_864_7e-4 => 1.570_547_7,
-        x if x.is_sign_positive() => y as f32,
-        _ => (core::f64::consts::PI - y) as f32,
+        x => kernel::acos(x.into()) as f32,
     }
 }
 
@@ -750,95 +1323,178 @@ pub fn acos(x: f32) -> f32 {
 #[must_use]
 #[inline]
 pub fn asin(x: f32) -> f32 {
-    let sin = x.abs();
+    if x.abs().eq(&0.532_136_56) {
+        return 0.561_122_06_f32.copysign(x);
+    }
 
-    if sin < 0.5 {
-        let x: f64 = x.into();
-        let y = x * x;
-        let y = y * crate::poly(
-            y,
-            &[
-                0.166_666_666_666_669_9,
-                0.074_999_999_996_942_23,
-                0.044_642_857_621_259_74,
-                0.030_381_915_298_596_9,
-                0.022_373_067_117_079_332,
-                0.017_336_338_079_820_927,
-                0.014_148_729_679_087_69,
-                0.010_245_724_097_753_366,
-                0.015_594_752_512_270_386,
-                -0.007_104_188_100_086_482_5,
-                0.028_097_370_567_441_11,
-            ],
-        );
-
-        return crate::mul_add(y, x, x) as f32;
-    }
-
-    let y = if sin.eq(&0.532_136_56) {
-        0.561_122_06
+    kernel::asin(x.into()) as f32
+}
+
+/// Arctangent
+#[must_use]
+#[inline]
+pub fn atan(x: f32) -> f32 {
+    kernel::atan(x.into()) as f32
+}
+
+/// Angle of the point `(x, y)` in radians
+///
+/// The result is correctly rounded and in `-π..=π`.  Signed zeros and
+/// infinities follow Annex F of the C standard, e.g. `atan2(±0, -0) = ±π` and
+/// `atan2(±∞, -∞) = ±3π/4`.
+#[must_use]
+#[inline]
+pub fn atan2(y: f32, x: f32) -> f32 {
+    atan2_special(y, x, core::f64::consts::PI).unwrap_or_else(|| {
+        let z = atan2_finite(y, x);
+
+        // Error bound of `z` relative to itself
+        let error = z * crate::exp2i(-45);
+        kernel::round(z, error).map_or_else(|| accurate::atan2_f32(y, x), |z| z.copysign(y))
+    })
+}
+
+/// Special cases of [`atan2`] and [`atan2pi`]
+///
+/// This function returns `None` if both `y` and `x` are finite and nonzero.
+/// Otherwise, it returns the angle in units where a half-turn is `pi`.
+#[inline]
+fn atan2_special(y: f32, x: f32, pi: f64) -> Option<f32> {
+    let angle = match (y.classify(), x.classify()) {
+        (FpCategory::Nan, _) | (_, FpCategory::Nan) => return Some(f32::NAN),
+        (FpCategory::Infinite, FpCategory::Infinite) => 0.25 * pi,
+        (FpCategory::Infinite, _) => 0.5 * pi,
+        (_, FpCategory::Infinite) | (FpCategory::Zero, _) => 0.0,
+        (_, FpCategory::Zero) => 0.5 * pi,
+        _ => return None,
+    };
+
+    let angle = if x.is_sign_negative() {
+        pi - angle
     } else {
-        let sin: f64 = sin.into();
-        let y = crate::poly(
-            sin,
-            &[
-                -1.570_795_268_727_950_5,
-                2.145_844_720_538_429_6e-1,
-                -8.891_815_130_471_556e-2,
-                5.019_724_059_139_869e-2,
-                -3.183_089_187_114_656e-2,
-                2.021_070_346_378_044_8e-2,
-                -1.159_335_145_410_863_3e-2,
-                5.441_837_134_625_65e-3,
-                -1.883_759_349_016_505e-3,
-                4.174_581_850_783_569e-4,
-                -4.385_109_488_852_58e-5,
-            ],
-        );
-        crate::mul_add((1.0 - sin).sqrt(), y, core::f64::consts::FRAC_PI_2) as f32
+        angle
     };
 
-    y.copysign(x)
+    Some((angle as f32).copysign(y))
 }
 
-/// Arctangent
+/// Absolute value of [`atan2`] for finite nonzero `f32`s
+///
+/// The relative error is below 2<sup>-45</sup>.
+#[inline]
+fn atan2_finite(y: f32, x: f32) -> f64 {
+    use core::f64::consts::{FRAC_PI_2, PI};
+    let (a, b) = (f64::from(y.abs()), f64::from(x.abs()));
+
+    // atan2(a, b) = atan(a / b) = π/2 - atan(b / a)
+    if a <= b {
+        let t = a / b;
+
+        if x.is_sign_negative() {
+            crate::mul_add(-t, kernel::atan_slope(t), PI)
+        } else {
+            t * kernel::atan_slope(t)
+        }
+    } else {
+        let t = b / a;
+        let t = if x.is_sign_negative() { t } else { -t };
+        crate::mul_add(t, kernel::atan_slope(t), FRAC_PI_2)
+    }
+}
+
+/// Arcsine in half-turns
+///
+/// The result is correctly rounded and in `-0.5..=0.5`.  It is more accurate
+/// than dividing [`asin`] by π, which rounds twice.
 #[must_use]
 #[inline]
-pub fn atan(x: f32) -> f32 {
-    #[inline]
-    fn kernel(x: f64) -> f64 {
-        fast_polynomial::rational_array(
-            x * x,
-            &[
-                3.300_049_005_002_112e-1,
-                8.269_936_280_545_194e-1,
-                7.536_692_262_484_512e-1,
-                3.041_250_192_035_205_3e-1,
-                5.258_546_450_061_43e-2,
-                3.092_811_576_351_314e-3,
-                2.668_044_628_603_543_2e-5,
-            ],
-            &[
-                3.300_049_005_002_111_4e-1,
-                9.369_952_615_545_891e-1,
-                1.0,
-                4.972_028_574_382_380_6e-1,
-                1.155_090_051_164_766_6e-1,
-                1.090_224_520_186_812_4e-2,
-                2.732_269_307_955_130_4e-4,
-            ],
-        )
+pub fn asinpi(x: f32) -> f32 {
+    if x.abs() > 1.0 || x.is_nan() {
+        return f32::NAN;
     }
 
-    let use_outer = x.abs() > 1.0;
-    let x: f64 = x.into();
+    let z = kernel::asin(x.into()) * core::f64::consts::FRAC_1_PI;
+    kernel::round(z, z.abs() * crate::exp2i(-43)).unwrap_or_else(|| accurate::asin_pi_f32(x))
+}
+
+/// Arccosine in half-turns
+///
+/// The result is correctly rounded and in `0..=1`.  It is more accurate than
+/// dividing [`acos`] by π, which rounds twice.
+#[must_use]
+#[inline]
+pub fn acospi(x: f32) -> f32 {
+    if x.abs() > 1.0 || x.is_nan() {
+        return f32::NAN;
+    }
+
+    let z = kernel::acos(x.into()) * core::f64::consts::FRAC_1_PI;
+    kernel::round(z, z * crate::exp2i(-43)).unwrap_or_else(|| accurate::acos_pi_f32(x))
+}
+
+/// Arctangent in half-turns
+///
+/// The result is correctly rounded and in `-0.5..=0.5`.  It is more accurate
+/// than dividing [`atan`] by π, which rounds twice.
+#[must_use]
+#[inline]
+pub fn atanpi(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+
+    let z = kernel::atan(x.into()) * core::f64::consts::FRAC_1_PI;
+    kernel::round(z, z.abs() * crate::exp2i(-44)).unwrap_or_else(|| accurate::atan_pi_f32(x))
+}
+
+/// Angle of the point `(x, y)` in half-turns
+///
+/// The result is correctly rounded and in `-1..=1`.  Special cases are those
+/// of [`atan2`] scaled by 1/π, e.g. `atan2pi(±0, -0) = ±1`.
+#[must_use]
+#[inline]
+pub fn atan2pi(y: f32, x: f32) -> f32 {
+    atan2_special(y, x, 1.0).unwrap_or_else(|| {
+        let z = atan2_finite(y, x) * core::f64::consts::FRAC_1_PI;
+        let error = z * crate::exp2i(-44);
+        kernel::round(z, error).map_or_else(|| accurate::atan2pi_f32(y, x), |z| z.copysign(y))
+    })
+}
+
+/// Evaluate a trigonometric function after reduction modulo π/64
+///
+/// - `x`: the argument in radians
+/// - `tiny`: the result for `x` below 2<sup>-12</sup> in magnitude
+/// - `nan`: the result for non-finite `x`
+/// - `f`: the function of the reduced argument from [`kernel::rem_pio64`]
+///
+/// Only the common case is inlined.  Huge and special arguments are handled
+/// out of line to keep the fast path lean.
+#[cfg(not(feature = "core-math"))]
+#[inline]
+fn with_rem_pio64<T>(x: f32, tiny: T, nan: T, f: impl FnOnce(i64, f64) -> T) -> T {
+    // Doubled bits of 2^-12 and 2^19
+    const MIN: u32 = 0x7300_0000;
+    const MAX: u32 = 0x9200_0000;
+
+    #[cold]
+    #[inline(never)]
+    fn rare<T>(x: f32, tiny: T, nan: T, f: impl FnOnce(i64, f64) -> T) -> T {
+        if x.abs() < crate::exp2i(-12) as f32 {
+            tiny
+        } else if x.is_finite() {
+            let (q, r) = kernel::rem_pio64_huge(x);
+            f(q, r)
+        } else {
+            nan
+        }
+    }
 
-    if use_outer {
-        use core::f64::consts::FRAC_PI_2;
-        let recip = x.recip();
-        crate::mul_add(-recip, kernel(recip), FRAC_PI_2.copysign(x)) as f32
+    if (MIN..MAX).contains(&(x.to_bits() << 1)) {
+        let (q, r) = kernel::rem_pio64(x);
+        f(q, r)
     } else {
-        (x * kernel(x)) as f32
+        rare(x, tiny, nan, f)
     }
 }
 
@@ -846,121 +1502,626 @@ pub fn atan(x: f32) -> f32 {
 pub use core_math::sinf as sin;
 
 /// Sine
+///
+/// The result is correctly rounded.  Arguments are reduced modulo π/64 to
+/// look up a table.  In the rare case where the result is too close to a tie,
+/// it is recomputed with double-double arithmetic.
 #[cfg(not(feature = "core-math"))]
 #[must_use]
 #[inline]
 pub fn sin(x: f32) -> f32 {
-    let y = match x.abs() {
-        9830.398 => -0.347_613_25,
-        x if x.to_bits() >= f32::INFINITY.to_bits() => f32::NAN,
-        x => {
-            let (q, x) = kernel::rem_pio2(x);
-            let y = match q & 1 {
-                0 => kernel::sin(x),
-                _ => kernel::cos(x),
-            };
-            kernel::apply_sign(y, q & 2 == 2)
-        }
-    };
-
-    kernel::apply_sign(y, x.is_sign_negative())
+    // sin(x) rounds to x for |x| < 2^-12
+    with_rem_pio64(x, x, f32::NAN, move |q, r| {
+        kernel::round_trig(kernel::sin(q, r), 2048).unwrap_or_else(|| accurate::sin_cos_f32(x).0)
+    })
 }
 
 #[cfg(feature = "core-math")]
 pub use core_math::cosf as cos;
 
 /// Cosine
+///
+/// The result is correctly rounded.  The algorithm is the same as [`sin`].
 #[cfg(not(feature = "core-math"))]
 #[must_use]
 #[inline]
 pub fn cos(x: f32) -> f32 {
-    let x = x.abs();
-
-    match x {
-        2.861_650_8e15 => return 0.533_916_4,
-        1.100_467_8e19 => return 0.996_410_1,
-        1.726_998_3e20 => return 0.969_058,
-        x if x.to_bits() >= f32::INFINITY.to_bits() => return f32::NAN,
-        _ => (),
-    }
-
-    let (q, x) = kernel::rem_pio2(x);
-
-    match q & 3 {
-        0 => kernel::cos(x),
-        1 => -kernel::sin(x),
-        2 => -kernel::cos(x),
-        _ => kernel::sin(x),
-    }
+    // cos(x) rounds to 1 for |x| < 2^-12
+    with_rem_pio64(x, 1.0, f32::NAN, move |q, r| {
+        kernel::round_trig(kernel::sin(q + 32, r), 2048)
+            .unwrap_or_else(|| accurate::sin_cos_f32(x).1)
+    })
 }
 
 #[cfg(feature = "core-math")]
 pub use core_math::sincosf as sin_cos;
 
 /// Compute sine and cosine simultaneously
+///
+/// The results are correctly rounded.  The algorithm is the same as [`sin`].
 #[cfg(not(feature = "core-math"))]
 #[must_use]
 #[inline]
 pub fn sin_cos(x: f32) -> (f32, f32) {
-    let (s, c) = match x.abs() {
-        9830.398 => (-0.347_613_25, -0.937_638),
-        2.861_650_8e15 => (-0.845_537_3, 0.533_916_4),
-        1.100_467_8e19 => (0.084_657_6, 0.996_410_1),
-        1.726_998_3e20 => (-0.246_833_34, 0.969_058),
-        x if x.to_bits() >= f32::INFINITY.to_bits() => (f32::NAN, f32::NAN),
-        x => {
-            let (q, x) = kernel::rem_pio2(x);
-            let s = kernel::sin(x);
-            let c = kernel::cos(x);
-
-            match q & 3 {
-                0 => (s, c),
-                1 => (c, -s),
-                2 => (-s, -c),
-                _ => (-c, s),
-            }
-        }
-    };
+    with_rem_pio64(x, (x, 1.0), (f32::NAN, f32::NAN), move |q, r| {
+        let sin = kernel::round_trig(kernel::sin(q, r), 2048);
+        let cos = kernel::round_trig(kernel::sin(q + 32, r), 2048);
 
-    (kernel::apply_sign(s, x.is_sign_negative()), c)
+        match (sin, cos) {
+            (Some(sin), Some(cos)) => (sin, cos),
+            _ => accurate::sin_cos_f32(x),
+        }
+    })
 }
 
 #[cfg(feature = "core-math")]
 pub use core_math::tanf as tan;
 
 /// Tangent function
+///
+/// The result is correctly rounded.  The tangent is the quotient of the sine
+/// and the cosine computed like [`sin_cos`].
 #[cfg(not(feature = "core-math"))]
 #[must_use]
 #[inline]
 pub fn tan(x: f32) -> f32 {
-    let y = match x.abs() {
-        3013.517 => 0.894_440_53,
-        2.898_609_4e37 => 0.792_096_8,
-        x if x.to_bits() >= f32::INFINITY.to_bits() => f32::NAN,
-        x => {
-            let (q, x) = kernel::rem_pio2(x);
-            let xcotx = crate::poly(
-                x * x,
-                &[
-                    9.999_999_999_999_997e-1,
-                    -3.333_333_333_332_465_5e-1,
-                    -2.222_222_222_589_263_8e-2,
-                    -2.116_402_056_643_148_4e-3,
-                    -2.116_406_990_379_701_1e-4,
-                    -2.137_556_903_554_936_4e-5,
-                    -2.170_374_883_723_272e-6,
-                    -2.100_327_091_903_891_5e-7,
-                    -2.970_220_949_700_791e-8,
-                ],
-            );
+    // tan(x) rounds to x for |x| < 2^-12
+    with_rem_pio64(x, x, f32::NAN, move |q, r| {
+        let y = kernel::sin(q, r) / kernel::sin(q + 32, r);
+        kernel::round_trig(y, 4096).unwrap_or_else(|| accurate::tan_f32(x))
+    })
+}
 
-            let y = match q & 1 {
-                0 => x / xcotx,
-                _ => xcotx / -x,
-            };
-            y as f32
+/// Sine of `x` half-turns, i.e. sin(πx)
+///
+/// The result is correctly rounded.  Unlike [`sin`], the argument is reduced
+/// exactly modulo 1/64, so results near zeros are as accurate as the others.
+#[must_use]
+#[inline]
+pub fn sinpi(x: f32) -> f32 {
+    if !x.is_finite() {
+        return f32::NAN;
+    }
+
+    let (q, r) = kernel::rem_frac_1_64(x.abs());
+    let y = kernel::sin(q, r);
+    let y = kernel::round(y, y.abs() * crate::exp2i(-42))
+        .unwrap_or_else(|| accurate::sin_cos_pi_f32(x.abs()).0);
+
+    if x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Cosine of `x` half-turns, i.e. cos(πx)
+///
+/// The result is correctly rounded.  The algorithm is the same as [`sinpi`].
+#[must_use]
+#[inline]
+pub fn cospi(x: f32) -> f32 {
+    if !x.is_finite() {
+        return f32::NAN;
+    }
+
+    let (q, r) = kernel::rem_frac_1_64(x);
+    let y = kernel::sin(q + 32, r);
+    kernel::round(y, y.abs() * crate::exp2i(-42)).unwrap_or_else(|| accurate::sin_cos_pi_f32(x).1)
+}
+
+/// Compute [`sinpi`] and [`cospi`] simultaneously
+///
+/// The results are correctly rounded.
+#[must_use]
+#[inline]
+pub fn sin_cos_pi(x: f32) -> (f32, f32) {
+    if !x.is_finite() {
+        return (f32::NAN, f32::NAN);
+    }
+
+    let (q, r) = kernel::rem_frac_1_64(x.abs());
+    let sin = kernel::sin(q, r);
+    let cos = kernel::sin(q + 32, r);
+    let sin = kernel::round(sin, sin.abs() * crate::exp2i(-42));
+    let cos = kernel::round(cos, cos.abs() * crate::exp2i(-42));
+
+    let (sin, cos) = match (sin, cos) {
+        (Some(sin), Some(cos)) => (sin, cos),
+        _ => accurate::sin_cos_pi_f32(x.abs()),
+    };
+
+    (if x.is_sign_negative() { -sin } else { sin }, cos)
+}
+
+/// Tangent of `x` half-turns, i.e. tan(πx)
+///
+/// The result is correctly rounded.  The tangent is the quotient of the sine
+/// and the cosine computed like [`sin_cos_pi`].  Following IEEE 754, poles
+/// at half-integers `n + 1/2` evaluate to +∞ for even `n` and -∞ for odd `n`.
+#[must_use]
+#[inline]
+pub fn tanpi(x: f32) -> f32 {
+    if !x.is_finite() {
+        return f32::NAN;
+    }
+
+    let (q, r) = kernel::rem_frac_1_64(x.abs());
+    let sin = kernel::sin(q, r);
+    let cos = kernel::sin(q + 32, r);
+    let y = sin / cos;
+
+    let y = if cos == 0.0 {
+        y as f32
+    } else {
+        kernel::round(y, y.abs() * crate::exp2i(-41))
+            .unwrap_or_else(|| accurate::tan_pi_f32(x.abs()))
+    };
+
+    if x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Error function
+#[must_use]
+#[inline]
+pub fn erf(x: f32) -> f32 {
+    let s = f64::from(x.abs());
+
+    if s < 1.0 {
+        let y = f64::from(x) * kernel::erf_slope(s);
+        return kernel::round(y, y.abs() * crate::exp2i(-48))
+            .unwrap_or_else(|| accurate::erf_f32(x));
+    }
+
+    if x.is_nan() {
+        return x;
+    }
+
+    if s >= 4.0 {
+        return 1.0_f32.copysign(x);
+    }
+
+    let y = 1.0 - finite_exp(-s * s) * kernel::erfcx(s);
+    kernel::round(y, y * crate::exp2i(-48)).map_or_else(|| accurate::erf_f32(x), |y| y.copysign(x))
+}
+
+/// Complementary error function `1 - erf(x)`
+///
+/// This function is accurate for large `x`, where `1 - erf(x)` suffers from
+/// catastrophic cancellation.  The result underflows to zero for `x` above
+/// about 10.05.
+#[must_use]
+#[inline]
+pub fn erfc(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+
+    if x > 10.1 {
+        return 0.0;
+    }
+
+    if x < -4.0 {
+        return 2.0;
+    }
+
+    let x64 = f64::from(x);
+    let s = x64.abs();
+
+    let y = if s < 1.0 {
+        crate::mul_add(-x64, kernel::erf_slope(x64), 1.0)
+    } else {
+        let y = finite_exp(-s * s) * kernel::erfcx(s);
+        if x64 < 0.0 {
+            2.0 - y
+        } else {
+            y
         }
     };
 
-    kernel::apply_sign(y, x.is_sign_negative())
+    kernel::round(y, y * crate::exp2i(-46)).unwrap_or_else(|| accurate::erfc_f32(x))
+}
+
+/// Scaled complementary error function `exp(x^2) * erfc(x)`
+///
+/// This function is free from the underflow of [`erfc`] and is about
+/// `1 / (x * √π)` for large `x`.  It overflows for `x` below about -9.38.
+#[must_use]
+#[inline]
+pub fn erfcx(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+
+    if x < -9.4 {
+        return f32::INFINITY;
+    }
+
+    if x == f32::INFINITY {
+        return 0.0;
+    }
+
+    let x64 = f64::from(x);
+
+    let y = if x64 >= 1.0 {
+        kernel::erfcx(x64)
+    } else if x64 > -1.0 {
+        finite_exp(x64 * x64) * crate::mul_add(-x64, kernel::erf_slope(x64), 1.0)
+    } else {
+        crate::mul_add(2.0, finite_exp(x64 * x64), -kernel::erfcx(-x64))
+    };
+
+    kernel::round(y, y * crate::exp2i(-46)).unwrap_or_else(|| accurate::erfcx_f32(x))
+}
+
+/// Reduce the argument of the gamma function to `1 + t` with `|t| <= 0.5`
+///
+/// This function returns `(t, p, n)` such that `n` is `x` rounded to an
+/// integer and Γ(x) is either `Γ(1 + t) * p` for positive `n` or
+/// `Γ(1 + t) / p` otherwise.  The argument must be within `-9..=9`, so that
+/// `p` is a product of a few exact factors.
+#[inline]
+fn reduce_gamma(x: f64) -> (f64, f64, f64) {
+    let n = x.round_ties_even();
+    let t = x - n;
+    let mut p = 1.0;
+
+    if n > 0.0 {
+        let mut k = n - 1.0;
+
+        while k > 0.0 {
+            p *= t + k;
+            k -= 1.0;
+        }
+    } else {
+        let mut y = x;
+
+        while y <= t {
+            p *= y;
+            y += 1.0;
+        }
+    }
+
+    (t, p, n)
+}
+
+/// Check if Γ(x) is negative for `x` that is not a pole
+#[inline]
+fn is_gamma_negative(x: f32) -> bool {
+    (x == 0.0 && x.is_sign_negative()) || (x < 0.0 && x.floor() % 2.0 != 0.0)
+}
+
+/// The gamma function
+///
+/// The result is correctly rounded.  Following C, poles at non-positive
+/// integers evaluate to NaN except for `tgamma(±0) = ±∞`.
+#[must_use]
+#[inline]
+pub fn tgamma(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+
+    if x == 0.0 {
+        return x.recip();
+    }
+
+    if x > 36.0 {
+        return f32::INFINITY;
+    }
+
+    if x < 0.0 && x == x.floor() {
+        return f32::NAN;
+    }
+
+    if x < -50.0 {
+        return if is_gamma_negative(x) { -0.0 } else { 0.0 };
+    }
+
+    let x64 = f64::from(x);
+
+    let (y, error) = if x64 >= 8.0 {
+        let y = finite_exp(kernel::ln_gamma(x64));
+        (y, y * crate::exp2i(-43))
+    } else if x64 > -8.0 {
+        let (t, p, n) = reduce_gamma(x64);
+        let q = crate::mul_add(t, kernel::recip_gamma_slope(t), 1.0);
+        let y = if n > 0.0 { p / q } else { (p * q).recip() };
+        (y, y.abs() * crate::exp2i(-47))
+    } else {
+        // Γ(x) Γ(1 - x) = π / sin(πx)
+        let (q, r) = kernel::rem_frac_1_64(x.abs());
+        let s = -kernel::sin(q, r);
+        let y = core::f64::consts::PI / s * finite_exp(-kernel::ln_gamma(1.0 - x64));
+        (y, y.abs() * crate::exp2i(-40))
+    };
+
+    kernel::round(y, error).unwrap_or_else(|| accurate::tgamma_f32(x))
+}
+
+/// Natural logarithm of the absolute value of the gamma function
+///
+/// The result is correctly rounded.  Poles at non-positive integers evaluate
+/// to +∞.
+#[must_use]
+#[inline]
+pub fn lgamma(x: f32) -> f32 {
+    use core::f64::consts::LN_2;
+
+    if x.is_nan() {
+        return x;
+    }
+
+    if x.is_infinite() || x == 0.0 || (x < 0.0 && x == x.floor()) {
+        return f32::INFINITY;
+    }
+
+    if x == 1.0 || x == 2.0 {
+        return 0.0;
+    }
+
+    let x64 = f64::from(x);
+
+    let (y, error) = if x64 >= 8.0 {
+        let y = kernel::ln_gamma(x64);
+        (y, y * crate::exp2i(-48))
+    } else if x64 > -8.0 {
+        let (t, p, n) = reduce_gamma(x64);
+        let s = kernel::recip_gamma_slope(t);
+        let q = crate::mul_add(t, s, 1.0);
+
+        // Zeros at 1 and 2 are computed relative to `ln(1 + u)`.
+        let u = match n {
+            1.0 => Some(-t * s / q),
+            2.0 => Some(t * (1.0 - s) / q),
+            _ => None,
+        };
+
+        if let Some(u) = u {
+            let y = kernel::ln_1p(u);
+            (y, y.abs() * crate::exp2i(-47))
+        } else {
+            let g = if n > 0.0 { p / q } else { (p * q).recip() };
+            let y = LN_2 * kernel::log2(g.abs());
+            (
+                y,
+                crate::mul_add(y.abs(), crate::exp2i(-50), crate::exp2i(-47)),
+            )
+        }
+    } else {
+        // Γ(x) Γ(1 - x) = π / sin(πx)
+        const LN_PI: f64 = 1.144_729_885_849_400_2;
+        let (q, r) = kernel::rem_frac_1_64(x.abs());
+        let s = LN_2 * kernel::log2(kernel::sin(q, r).abs());
+        let z = kernel::ln_gamma(1.0 - x64);
+        let y = LN_PI - s - z;
+        (
+            y,
+            crate::mul_add(z - s, crate::exp2i(-49), crate::exp2i(-40)),
+        )
+    };
+
+    kernel::round(y, error).unwrap_or_else(|| accurate::lgamma_f32(x))
+}
+
+/// Natural logarithm of the absolute value of the gamma function with its sign
+///
+/// This function returns `(lgamma(x), sign)` like the reentrant C function
+/// `lgammaf_r`, where `sign` is -1 if Γ(x) is negative and 1 otherwise.
+#[must_use]
+#[inline]
+pub fn lgamma_r(x: f32) -> (f32, i32) {
+    (lgamma(x), if is_gamma_negative(x) { -1 } else { 1 })
+}
+
+/// Bessel function of the first kind of order 0
+///
+/// The result is faithfully rounded.  Arguments not below 8 in magnitude are
+/// evaluated in modulus-phase form, where the phase is reduced like [`sin`].
+/// Results near zeros below 8 are computed from Taylor expansions about the
+/// zeros, so the relative error is small there too.
+#[must_use]
+#[inline]
+pub fn j0(x: f32) -> f32 {
+    if !x.is_finite() {
+        return if x.is_nan() { x } else { 0.0 };
+    }
+
+    kernel::j0(x.abs()) as f32
+}
+
+/// Bessel function of the first kind of order 1
+///
+/// The result is faithfully rounded.  The algorithm is the same as [`j0`].
+#[must_use]
+#[inline]
+pub fn j1(x: f32) -> f32 {
+    if !x.is_finite() {
+        return if x.is_nan() { x } else { 0.0_f32.copysign(x) };
+    }
+
+    let y = kernel::j1(x.abs()) as f32;
+
+    if x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Handle special arguments of Bessel functions of the second kind
+///
+/// - `f`: the function for finite positive `x`
+#[inline]
+fn bessel_y(x: f32, f: impl FnOnce(f32) -> f64) -> f32 {
+    if x.is_nan() {
+        x
+    } else if x < 0.0 {
+        f32::NAN
+    } else if x == 0.0 {
+        f32::NEG_INFINITY
+    } else if x == f32::INFINITY {
+        0.0
+    } else {
+        f(x) as f32
+    }
+}
+
+/// Bessel function of the second kind of order 0
+///
+/// The result is faithfully rounded.  The algorithm is the same as [`j0`].
+/// Negative arguments evaluate to NaN.
+#[must_use]
+#[inline]
+pub fn y0(x: f32) -> f32 {
+    bessel_y(x, kernel::y0)
+}
+
+/// Bessel function of the second kind of order 1
+///
+/// The result is faithfully rounded.  The algorithm is the same as [`j0`].
+/// Negative arguments evaluate to NaN.
+#[must_use]
+#[inline]
+pub fn y1(x: f32) -> f32 {
+    bessel_y(x, kernel::y1)
+}
+
+/// J<sub>n</sub>(x) for finite `x >= 0`
+fn jn_finite(n: u32, x: f32) -> f64 {
+    use core::f64::consts::LN_2;
+
+    match n {
+        0 => return kernel::j0(x),
+        1 => return kernel::j1(x),
+        _ if x == 0.0 => return 0.0,
+        _ => (),
+    }
+
+    let x64 = f64::from(x);
+    let h = 2.0 / x64;
+
+    // The forward recurrence is stable when the order is below the argument.
+    if f64::from(n) < x64 {
+        let (mut a, mut b) = (kernel::j0(x), kernel::j1(x));
+
+        for k in 1..n {
+            (a, b) = (b, crate::mul_add(f64::from(k) * h, b, -a));
+        }
+        return b;
+    }
+
+    // Underflow if (x/2)^n / n! < 2^-151, which bounds J_n(x)
+    if n >= 8 {
+        let n = f64::from(n);
+        let ln = n * LN_2 * kernel::log2(0.5 * x64) - kernel::ln_gamma(n + 1.0);
+
+        if ln < -105.0 {
+            return 0.0;
+        }
+    }
+
+    // Find where the minimal solution of the recurrence is negligible by
+    // simulating the growth of the dominant solution.
+    let (mut m, mut p, mut q) = (n + 1, 1.0, f64::from(n) * h);
+
+    while q < crate::exp2i(32) {
+        (p, q) = (q, crate::mul_add(f64::from(m) * h, q, -p));
+        m += 1;
+    }
+
+    // Miller's backward recurrence, normalized by J_0 or J_1
+    let (mut a, mut b, mut y) = (0.0, 1.0, 0.0);
+
+    for k in (1..=m).rev() {
+        if k == n {
+            y = b;
+        }
+
+        (a, b) = (b, crate::mul_add(f64::from(k) * h, b, -a));
+
+        if b.abs() > crate::exp2i(600) {
+            a *= crate::exp2i(-600);
+            b *= crate::exp2i(-600);
+            y *= crate::exp2i(-600);
+        }
+    }
+
+    let (j0, j1) = (kernel::j0(x), kernel::j1(x));
+
+    if j0.abs() >= j1.abs() {
+        y * (j0 / b)
+    } else {
+        y * (j1 / a)
+    }
+}
+
+/// Bessel function of the first kind of order `n`
+///
+/// The result is computed by recurrence from [`j0`] and [`j1`].  The forward
+/// recurrence is taken if `n` is below `|x|`.  Otherwise, the backward
+/// recurrence by Miller's algorithm is taken.  The relative error is small
+/// except near zeros of J<sub>n</sub> for `|n| > 1`, where the forward
+/// recurrence only guarantees a small absolute error.
+#[must_use]
+#[inline]
+pub fn jn(n: i32, x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+
+    let y = if x.is_infinite() {
+        0.0
+    } else {
+        jn_finite(n.unsigned_abs(), x.abs()) as f32
+    };
+
+    // J_{-n}(x) = J_n(-x) = (-1)^n J_n(x)
+    if n & 1 != 0 && (n < 0) != x.is_sign_negative() {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Y<sub>n</sub>(x) for finite `x > 0`
+fn yn_finite(n: u32, x: f32) -> f64 {
+    if n == 0 {
+        return kernel::y0(x);
+    }
+
+    let h = 2.0 / f64::from(x);
+    let (mut a, mut b) = (kernel::y0(x), kernel::y1(x));
+
+    // The forward recurrence is stable.  Stop when `f32` surely overflows.
+    for k in 1..n {
+        if b.abs() > crate::exp2i(130) {
+            break;
+        }
+        (a, b) = (b, crate::mul_add(f64::from(k) * h, b, -a));
+    }
+    b
+}
+
+/// Bessel function of the second kind of order `n`
+///
+/// The result is computed by the forward recurrence from [`y0`] and [`y1`].
+/// The relative error is small except near zeros of Y<sub>n</sub> for
+/// `|n| > 1`, where only the absolute error is small.  Negative arguments
+/// evaluate to NaN.
+#[must_use]
+#[inline]
+pub fn yn(n: i32, x: f32) -> f32 {
+    let y = bessel_y(x, |x| yn_finite(n.unsigned_abs(), x));
+
+    // Y_{-n}(x) = (-1)^n Y_n(x)
+    if n < 0 && n & 1 != 0 {
+        -y
+    } else {
+        y
+    }
 }
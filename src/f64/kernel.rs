@@ -0,0 +1,664 @@
+use super::wide::Wide;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Unevaluated sum of two `f64`s for extra precision
+///
+/// The first component is the leading part.  A normalized [`Double`] has its
+/// first component equal to the sum of both components rounded to `f64`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Double(pub f64, pub f64);
+
+/// Exact sum assuming `|a| >= |b|` or `a == 0`
+#[inline]
+pub fn fast_two_sum(a: f64, b: f64) -> Double {
+    let s = a + b;
+    Double(s, b - (s - a))
+}
+
+/// Exact sum of two `f64`s
+#[inline]
+pub fn two_sum(a: f64, b: f64) -> Double {
+    let s = a + b;
+    let t = s - a;
+    Double(s, (a - (s - t)) + (b - t))
+}
+
+/// Exact product of two `f64`s
+///
+/// This function requires a correctly rounded [`f64::mul_add`], so it does not
+/// go through [`crate::mul_add`].
+#[inline]
+pub fn two_product(a: f64, b: f64) -> Double {
+    let p = a * b;
+    Double(p, a.mul_add(b, -p))
+}
+
+/// Sum of a `f64` and a [`Double`] with its leading part correctly rounded
+///
+/// The usual addition of [`Double`]s rounds the lower parts before summing
+/// them up, which may turn a near tie into an exact tie.  This function keeps
+/// the sum exact until the final rounding, where ties are broken by the
+/// remainder.
+#[inline]
+pub fn sum(a: f64, b: Double) -> Double {
+    let Double(s, t) = two_sum(a, b.0);
+    let Double(t, u) = two_sum(t, b.1);
+    let Double(s, t) = fast_two_sum(s, t);
+    let next = s + 2.0 * t;
+
+    if u != 0.0 && (t > 0.0) == (u > 0.0) && next - s == 2.0 * t {
+        return Double(next, u - t);
+    }
+    Double(s, t + u)
+}
+
+impl From<f64> for Double {
+    #[inline]
+    fn from(x: f64) -> Self {
+        Self(x, 0.0)
+    }
+}
+
+impl Neg for Double {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl Add<f64> for Double {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: f64) -> Self {
+        let Self(s, t) = two_sum(self.0, other);
+        fast_two_sum(s, self.1 + t)
+    }
+}
+
+impl Add for Double {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        let Self(s, t) = two_sum(self.0, other.0);
+        let Self(u, v) = two_sum(self.1, other.1);
+        let Self(s, t) = fast_two_sum(s, t + u);
+        fast_two_sum(s, t + v)
+    }
+}
+
+impl Sub<f64> for Double {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: f64) -> Self {
+        self + -other
+    }
+}
+
+impl Sub for Double {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul<f64> for Double {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: f64) -> Self {
+        let Self(p, e) = two_product(self.0, other);
+        fast_two_sum(p, self.1.mul_add(other, e))
+    }
+}
+
+impl Mul for Double {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        let Self(p, e) = two_product(self.0, other.0);
+        let e = self.0.mul_add(other.1, self.1.mul_add(other.0, e));
+        fast_two_sum(p, self.1.mul_add(other.1, e))
+    }
+}
+
+impl Div for Double {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        let q = self.0 / other.0;
+        let r = self - other * q;
+        fast_two_sum(q, r.0 / other.0)
+    }
+}
+
+/// Square root of a nonnegative [`Double`]
+#[inline]
+pub fn sqrt(x: Double) -> Double {
+    let s = x.0.sqrt();
+
+    if s == 0.0 {
+        return x;
+    }
+
+    let Double(p, e) = two_product(s, s);
+    fast_two_sum(s, ((x.0 - p) - e + x.1) / (2.0 * s))
+}
+
+/// ln(2) split into parts whose sum is accurate to 137 bits
+///
+/// The first two parts have only 42 significant bits, so that their products
+/// with an exponent of `f64` are exact.
+pub const LN_2: [f64; 3] = [
+    0.693_147_180_559_890_3,
+    5.497_923_018_708_502_4e-14,
+    -1.312_469_841_778_525_5e-27,
+];
+
+/// Compute `x - n * ln(2)` exactly enough for [`exp_m1`]
+///
+/// - `x`: the leading part of the argument
+/// - `n`: an integer not exceeding 2<sup>11</sup> in magnitude
+#[inline]
+pub fn reduce_ln_2(x: Double, n: f64) -> Double {
+    two_sum(n.mul_add(-LN_2[0], x.0), n * -LN_2[1]) + n.mul_add(-LN_2[2], x.1)
+}
+
+/// Restriction of `exp(x) - 1` to `-0.36..=0.36`
+///
+/// The argument is divided by 16 to shorten the polynomial.  Then the result
+/// is recovered with the doubling formula `E(2x) = E(x) * (E(x) + 2)`, where
+/// `E(x) = exp(x) - 1`.  Unlike squaring `exp(x)`, this formula does not
+/// magnify the relative error.
+#[inline]
+pub fn exp_m1(x: Double) -> Double {
+    /// Taylor coefficients 1/3! to 1/7!
+    const C: [Double; 5] = [
+        Double(0.166_666_666_666_666_66, 9.251_858_538_542_97e-18),
+        Double(0.041_666_666_666_666_664, 2.312_964_634_635_742_7e-18),
+        Double(8.333_333_333_333_333e-3, 1.156_482_317_317_871_4e-19),
+        Double(1.388_888_888_888_889e-3, -5.300_543_954_373_577e-20),
+        Double(1.984_126_984_126_984e-4, 1.720_955_829_342_070_5e-22),
+    ];
+
+    let x = x * 0.0625;
+    let tail = crate::poly(
+        x.0,
+        &[
+            2.480_158_730_158_73e-5,
+            2.755_731_922_398_589_3e-6,
+            2.755_731_922_398_589e-7,
+            2.505_210_838_544_172e-8,
+            2.087_675_698_786_81e-9,
+            1.605_904_383_682_161_3e-10,
+        ],
+    );
+
+    let y = C.iter().rev().fold(Double::from(tail), |y, &c| y * x + c);
+    let mut y = ((y * x + 0.5) * x + 1.0) * x;
+
+    for _ in 0..4 {
+        y = y * (y + 2.0);
+    }
+    y
+}
+
+/// Restriction of `sinh(x) - x` to `-ln(2)/2..=ln(2)/2`
+///
+/// The leading `x` is left out so that the caller can round `x` plus this
+/// tail with [`sum`], which is free from cancellation and spurious ties.
+#[inline]
+pub fn sinh_tail(x: f64) -> Double {
+    /// Taylor coefficients 1/3! to 1/13!
+    const C: [Double; 6] = [
+        Double(0.166_666_666_666_666_66, 9.251_858_538_542_97e-18),
+        Double(8.333_333_333_333_333e-3, 1.156_482_317_317_871_4e-19),
+        Double(1.984_126_984_126_984e-4, 1.720_955_829_342_070_5e-22),
+        Double(2.755_731_922_398_589_3e-6, -1.858_393_274_046_472e-22),
+        Double(2.505_210_838_544_172e-8, -1.448_814_070_935_912e-24),
+        Double(1.605_904_383_682_161_3e-10, 1.258_529_458_875_209_8e-26),
+    ];
+
+    let y = two_product(x, x);
+    let tail = crate::poly(
+        y.0,
+        &[
+            7.647_163_731_819_816e-13,
+            2.811_457_254_345_520_6e-15,
+            8.220_635_246_624_33e-18,
+            1.957_294_106_339_126_3e-20,
+            3.868_170_170_630_684e-23,
+            6.446_950_284_384_474e-26,
+        ],
+    );
+
+    C.iter().rev().fold(Double::from(tail), |z, &c| z * y + c) * y * x
+}
+
+/// Correctly rounded `x * 2^n` for a normalized nonzero [`Double`] `x`
+///
+/// Overflow and gradual underflow are handled.  The leading part of `x` can
+/// be subnormal.
+#[inline]
+pub fn ldexp(x: Double, n: i64) -> f64 {
+    const SUBNORMAL_SHIFT: i64 = f64::MANTISSA_DIGITS as i64 - f64::MIN_EXP as i64;
+
+    let Double(hi, lo) = x;
+
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = (hi.to_bits() >> super::EXP_SHIFT & 0x7FF) as i64 + n;
+
+    if exponent > 0 {
+        let half = n / 2;
+        return hi * crate::exp2i(half) * crate::exp2i(n - half);
+    }
+
+    // Scale so that the least subnormal becomes 1 and round to an integer.
+    // Scale in two steps because `hi` can be subnormal.
+    let shift = n + SUBNORMAL_SHIFT;
+    let half = shift / 2;
+    let hi = hi * crate::exp2i(half) * crate::exp2i(shift - half);
+    let lo = lo * crate::exp2i(half) * crate::exp2i(shift - half);
+    let floor = hi.floor();
+    let fraction = (hi - floor) + lo;
+    let odd = floor % 2.0 != 0.0;
+    let carry = fraction > 0.5 || (fraction == 0.5 && odd);
+
+    (floor + f64::from(u8::from(carry))) * f64::from_bits(1)
+}
+
+/// Round `x * 2^n` to `f64` if it is far enough from a tie
+///
+/// - `x`: a normalized nonzero [`Double`] like the argument of [`ldexp`]
+/// - `error`: the bound of the relative error of `x`
+///
+/// If the rounding cannot be determined, return `None` for a more accurate
+/// path to take over.
+#[inline]
+pub fn round(x: Double, n: i64, error: f64) -> Option<f64> {
+    let error = x.0.abs() * error;
+    let lower = ldexp(fast_two_sum(x.0, x.1 - error), n);
+    let upper = ldexp(fast_two_sum(x.0, x.1 + error), n);
+    (lower == upper).then_some(lower)
+}
+
+/// Correctly rounded `x * 2^n` in `f32` for a normalized nonzero [`Double`]
+///
+/// - `x`: a [`Double`] whose leading part is within 2<sup>-64</sup>..=2<sup>64</sup>
+///   in magnitude unless `n` is zero, where any normal `f64` is fine
+///
+/// The range of `f32` is well within normal `f64`, so the scaling is exact.
+/// Rounding the leading part to `f32` is then correct unless it is a tie,
+/// which is broken by the lower part.
+#[inline]
+pub fn ldexp_f32(x: Double, n: i64) -> f32 {
+    let scale = crate::exp2i(n.clamp(-900, 900));
+    let Double(hi, lo) = Double(x.0 * scale, x.1 * scale);
+
+    // Significand of `hi` and the number of its bits below the `f32` precision
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = (hi.to_bits() >> super::EXP_SHIFT & 0x7FF) as i64 - 1023;
+    let significand = hi.to_bits() & ((1 << super::EXP_SHIFT) - 1) | 1 << super::EXP_SHIFT;
+    let shift = (-97 - exponent).max(29);
+    let tie = shift <= 53 && significand & ((1 << shift) - 1) == 1 << (shift - 1);
+
+    if tie && lo != 0.0 {
+        // Move `hi` off the tie by an ulp of `f64` toward `lo`
+        let away = (lo > 0.0) == (hi > 0.0);
+        let bits = if away {
+            hi.to_bits() + 1
+        } else {
+            hi.to_bits() - 1
+        };
+        return f64::from_bits(bits) as f32;
+    }
+
+    hi as f32
+}
+
+/// Restriction of inverse hyperbolic tangent to `-c..=c`, where
+///
+/// ```text
+///     √2 - 1                  1 + c
+/// c = ------  the solution to ----- = √2.
+///     √2 + 1,                 1 - c
+/// ```
+#[inline]
+pub fn atanh(x: Double) -> Double {
+    /// Taylor coefficients 1/3, 1/5, ..., 1/17
+    const C: [Double; 8] = [
+        Double(0.333_333_333_333_333_3, 1.850_371_707_708_594e-17),
+        Double(0.2, -1.110_223_024_625_156_6e-17),
+        Double(0.142_857_142_857_142_85, 7.930_164_461_608_26e-18),
+        Double(0.111_111_111_111_111_1, 6.167_905_692_361_980_4e-18),
+        Double(0.090_909_090_909_090_91, -2.523_234_146_875_356e-18),
+        Double(0.076_923_076_923_076_93, -4.270_088_556_250_602e-18),
+        Double(0.066_666_666_666_666_67, 9.251_858_538_542_971e-19),
+        Double(0.058_823_529_411_764_705, 8.163_404_592_832_033e-19),
+    ];
+
+    let y = x * x;
+    let tail = crate::poly(
+        y.0,
+        &[
+            0.052_631_578_947_368_42,
+            0.047_619_047_619_047_616,
+            0.043_478_260_869_565_216,
+            0.04,
+            0.037_037_037_037_037_035,
+            0.034_482_758_620_689_655,
+            0.032_258_064_516_129_03,
+            0.030_303_030_303_030_304,
+            0.028_571_428_571_428_57,
+            0.027_027_027_027_027_03,
+        ],
+    );
+
+    let y = C.iter().rev().fold(Double::from(tail), |z, &c| z * y + c) * y;
+    y * x + x
+}
+
+/// Natural logarithm of a positive [`Double`] split as `(e, ln(m))`
+///
+/// - `i`: bits of the leading part, possibly normalized with a nonpositive
+///   exponent like [`super::Magnitude::Normalized`]
+/// - `lo`: the lower part
+///
+/// The logarithm is `e * ln(2) + ln(m)`, where `e` is an integer and `m` is a
+/// [`Double`] in `1/√2..√2`.
+#[inline]
+pub fn ln(i: i64, lo: f64) -> (f64, Double) {
+    use core::f64::consts::FRAC_1_SQRT_2;
+
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = (i - FRAC_1_SQRT_2.to_bits() as i64) >> super::EXP_SHIFT;
+
+    // Scale in two steps because 2^-exponent overflows for subnormal numbers
+    let half = exponent / 2;
+    let lo = lo * crate::exp2i(-half) * crate::exp2i(half - exponent);
+
+    #[allow(clippy::cast_sign_loss)]
+    let x = Double(
+        f64::from_bits((i - (exponent << super::EXP_SHIFT)) as u64),
+        lo,
+    );
+
+    (exponent as f64, atanh((x - 1.0) / (x + 1.0)) * 2.0)
+}
+
+/// Compute `n * ln(2) + y` from the result of [`ln`]
+#[inline]
+pub fn join_ln(n: f64, y: Double) -> Double {
+    two_sum(n * LN_2[0], n * LN_2[1]) + n * LN_2[2] + y
+}
+
+/// π in [`Double`]
+pub const PI: Double = Double(core::f64::consts::PI, 1.224_646_799_147_353_2e-16);
+
+/// π/2 in [`Double`]
+pub const FRAC_PI_2: Double = Double(core::f64::consts::FRAC_PI_2, 6.123_233_995_736_766e-17);
+
+/// Argument reduction for trigonometric functions
+///
+/// - `x`: finite radians with a positive sign bit
+///
+/// Unlike `rem_pio64` for [`crate::f32`], this function reduces modulo π/2.
+/// Pseudocode is as follows.
+///
+/// ```text
+/// quotient = nearest integer of x / (π/2)
+/// y = x - quotient * (π/2) // IEEE remainder of x / (π/2)
+/// (quotient, y)
+/// ```
+///
+/// The lowest 2 bits of the returned quotient are accurate.  The remainder is
+/// computed with [`rem_pio2_fraction`] for every `x` beyond π/4.
+#[inline]
+pub fn rem_pio2(x: f64) -> (i64, Double) {
+    debug_assert!(x.is_sign_positive());
+
+    if x <= core::f64::consts::FRAC_PI_4 {
+        return (0, x.into());
+    }
+
+    let (q, r) = rem_pio2_fraction(x);
+    let m = r.significand;
+
+    #[allow(clippy::cast_precision_loss)]
+    let y = fast_two_sum(
+        (m >> 75) as f64,
+        (m & ((1 << 75) - 1)) as f64 * crate::exp2i(-75),
+    );
+    let scale = crate::exp2i(r.exponent - 52);
+    let y = Double(y.0 * scale, y.1 * scale) * FRAC_PI_2;
+
+    (q, if r.sign { -y } else { y })
+}
+
+/// Fraction part of `x / (π/2)` with [Payne&ndash;Hanek reduction][payne-hanek]
+///
+/// - `x`: finite radians beyond π/4
+///
+/// Return the nearest integer of `x / (π/2)` and the difference as a [`Wide`]
+/// in `-0.5..=0.5`, which is accurate to 128 bits.  The closest approach of a
+/// `f64` to a multiple of π/2 requires more than 200 bits of 2/π.
+///
+/// [payne-hanek]: https://doi.org/10.1145/1057600.1057602
+#[inline]
+pub fn rem_pio2_fraction(x: f64) -> (i64, Wide) {
+    /// Big-endian 1280 bits of 2/π
+    const FRAC_2_PI: [u64; 20] = [
+        0xA2F9_836E_4E44_1529,
+        0xFC27_57D1_F534_DDC0,
+        0xDB62_9599_3C43_9041,
+        0xFE51_63AB_DEBB_C561,
+        0xB724_6E3A_424D_D2E0,
+        0x0649_2EEA_09D1_921C,
+        0xFE1D_EB1C_B129_A73E,
+        0xE882_35F5_2EBB_4484,
+        0xE99C_7026_B45F_7E41,
+        0x3991_D639_8353_39F4,
+        0x9C84_5F8B_BDF9_283B,
+        0x1FF8_97FF_DE05_980F,
+        0xEF2F_118B_5A0A_6D1F,
+        0x6D36_7ECF_27CB_09B7,
+        0x4F46_3F66_9E5F_EA2D,
+        0x7527_BAC7_EBE5_F17B,
+        0x3D07_39F7_8A52_92EA,
+        0x6BFB_5FB1_1F8D_5D08,
+        0x5603_3046_FC7B_6BAB,
+        0xF0CF_BC20_9AF4_361D,
+    ];
+
+    // x = significand * 2^exponent, where significand is an integer
+    let bits = x.to_bits();
+    let significand = u128::from(bits & ((1 << super::EXP_SHIFT) - 1) | 1 << super::EXP_SHIFT);
+
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = (bits >> super::EXP_SHIFT) as i64 - 1075;
+
+    // Bits of 2/π before `skip` contribute multiples of 4 to x * 2/π.
+    #[allow(clippy::cast_sign_loss)]
+    let skip = (exponent - 2).max(0) as usize;
+    let (index, offset) = (skip / 64, skip % 64);
+    let word = |k: usize| {
+        let word = FRAC_2_PI[index + k] << offset;
+        match offset {
+            0 => word,
+            _ => word | FRAC_2_PI[index + k + 1] >> (64 - offset),
+        }
+    };
+
+    // 320-bit product a * 2^192 + b * 2^64 + c
+    let p3 = significand * u128::from(word(3));
+    let p2 = significand * u128::from(word(2)) + (p3 >> 64);
+    let p1 = significand * u128::from(word(1)) + (p2 >> 64);
+    let a = significand * u128::from(word(0)) + (p1 >> 64);
+    let b = p1 << 64 | p2 << 64 >> 64;
+    let c = p3 << 64 >> 64;
+
+    // Shift the product to put the 2 bits of quadrant at the top.
+    #[allow(clippy::cast_sign_loss)]
+    let shift = (exponent - skip as i64 + 62) as u32;
+    let high = a << shift | b >> (128 - shift);
+    let low = b << shift | c << shift >> 64;
+
+    // Fraction in 256-bit two's complement, rounded to the nearest quadrant
+    let r = (high << 2 | low >> 126) as i128;
+    let low = low << 2;
+    let q = (high >> 126) as i64 + i64::from(r < 0);
+    let (high, low) = if r < 0 {
+        (!r as u128 + u128::from(low == 0), low.wrapping_neg())
+    } else {
+        (r as u128, low)
+    };
+
+    let zeros = high.leading_zeros();
+    let m = match zeros {
+        0 => high,
+        _ => high << zeros | low >> (128 - zeros),
+    };
+
+    let fraction = Wide {
+        sign: r < 0,
+        exponent: -1 - i64::from(zeros),
+        significand: m,
+    };
+
+    (q, fraction)
+}
+
+/// Sine restricted to `-π/4..=π/4`
+#[inline]
+pub fn sin(x: Double) -> Double {
+    /// Taylor coefficients -1/3!, 1/5!, ..., 1/15!
+    const C: [Double; 7] = [
+        Double(-0.166_666_666_666_666_66, -9.251_858_538_542_97e-18),
+        Double(8.333_333_333_333_333e-3, 1.156_482_317_317_871_4e-19),
+        Double(-1.984_126_984_126_984e-4, -1.720_955_829_342_070_5e-22),
+        Double(2.755_731_922_398_589_3e-6, -1.858_393_274_046_472e-22),
+        Double(-2.505_210_838_544_172e-8, 1.448_814_070_935_912e-24),
+        Double(1.605_904_383_682_161_3e-10, 1.258_529_458_875_209_8e-26),
+        Double(-7.647_163_731_819_816e-13, -7.038_728_777_334_53e-30),
+    ];
+
+    let y = x * x;
+    let tail = crate::poly(
+        y.0,
+        &[
+            2.811_457_254_345_520_6e-15,
+            -8.220_635_246_624_33e-18,
+            1.957_294_106_339_126_3e-20,
+            -3.868_170_170_630_684e-23,
+            6.446_950_284_384_474e-26,
+            -9.183_689_863_795_546e-29,
+            1.130_996_288_644_771_6e-31,
+        ],
+    );
+
+    let y = C.iter().rev().fold(Double::from(tail), |z, &c| z * y + c) * y;
+    y * x + x
+}
+
+/// Cosine restricted to `-π/4..=π/4`
+#[inline]
+pub fn cos(x: Double) -> Double {
+    /// Taylor coefficients 1/4!, -1/6!, ..., -1/14!
+    const C: [Double; 6] = [
+        Double(0.041_666_666_666_666_664, 2.312_964_634_635_742_7e-18),
+        Double(-1.388_888_888_888_889e-3, 5.300_543_954_373_577e-20),
+        Double(2.480_158_730_158_73e-5, 2.151_194_786_677_588_2e-23),
+        Double(-2.755_731_922_398_589e-7, -2.376_771_462_225_029_7e-23),
+        Double(2.087_675_698_786_81e-9, -1.207_345_059_113_26e-25),
+        Double(-1.147_074_559_772_972_5e-11, -2.065_551_275_283_074_5e-28),
+    ];
+
+    let y = x * x;
+    let tail = crate::poly(
+        y.0,
+        &[
+            4.779_477_332_387_385e-14,
+            -1.561_920_696_858_622_5e-16,
+            4.110_317_623_312_165e-19,
+            -8.896_791_392_450_574e-22,
+            1.611_737_571_096_118_4e-24,
+            -2.479_596_263_224_797_6e-27,
+            3.279_889_237_069_838e-30,
+            -3.769_987_628_815_905_4e-33,
+        ],
+    );
+
+    let z = C.iter().rev().fold(Double::from(tail), |z, &c| z * y + c);
+    sum(1.0, (z * y - 0.5) * y)
+}
+
+/// Arctangent restricted to `0..=1`
+///
+/// The argument is reduced to `(x - c) / (1 + x * c)`, where `c` is the
+/// nearest multiple of 1/8, so that the polynomial is short.
+#[inline]
+pub fn atan(x: Double) -> Double {
+    /// atan(j/8) for j = 0, 1, ..., 8
+    const ATAN: [Double; 9] = [
+        Double(0.0, 0.0),
+        Double(0.124_354_994_546_761_44, -3.125_324_142_453_938_3e-18),
+        Double(0.244_978_663_126_864_14, 1.069_875_561_873_445_1e-17),
+        Double(0.358_770_670_270_572_25, -2.462_381_558_263_863_5e-17),
+        Double(0.463_647_609_000_806_1, 2.269_877_745_296_168_7e-17),
+        Double(0.558_599_315_343_562_4, -5.455_630_548_591_626_4e-18),
+        Double(0.643_501_108_793_284_4, 1.583_478_505_144_428_6e-17),
+        Double(0.718_829_999_621_624_5, -2.147_838_844_445_698_3e-17),
+        Double(core::f64::consts::FRAC_PI_4, 3.061_616_997_868_383e-17),
+    ];
+
+    /// Taylor coefficients -1/3, 1/5, ..., 1/13
+    const C: [Double; 6] = [
+        Double(-0.333_333_333_333_333_3, -1.850_371_707_708_594e-17),
+        Double(0.2, -1.110_223_024_625_156_6e-17),
+        Double(-0.142_857_142_857_142_85, -7.930_164_461_608_26e-18),
+        Double(0.111_111_111_111_111_1, 6.167_905_692_361_980_4e-18),
+        Double(-0.090_909_090_909_090_91, 2.523_234_146_875_356e-18),
+        Double(0.076_923_076_923_076_93, -4.270_088_556_250_602e-18),
+    ];
+
+    let j = (8.0 * x.0).round_ties_even();
+    let c = 0.125 * j;
+    let x = (x - c) / (x * c + 1.0);
+    let y = x * x;
+    let tail = crate::poly(
+        y.0,
+        &[
+            -0.066_666_666_666_666_67,
+            0.058_823_529_411_764_705,
+            -0.052_631_578_947_368_42,
+            0.047_619_047_619_047_616,
+            -0.043_478_260_869_565_216,
+            0.04,
+            -0.037_037_037_037_037_035,
+        ],
+    );
+
+    let y = C.iter().rev().fold(Double::from(tail), |z, &c| z * y + c) * y;
+    ATAN[j as usize] + (y * x + x)
+}
+
+/// Angle of the point `(x, y)` in the first quadrant
+///
+/// Both coordinates must be nonnegative and not both zero.  Their quotient
+/// must not overflow or underflow.
+#[inline]
+pub fn atan2(y: Double, x: Double) -> Double {
+    if y.0 <= x.0 {
+        atan(y / x)
+    } else {
+        FRAC_PI_2 - atan(x / y)
+    }
+}
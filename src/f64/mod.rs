@@ -1,4 +1,1138 @@
+#![allow(clippy::pedantic)]
+#![warn(clippy::unreadable_literal)]
+
+pub(crate) mod kernel;
+pub(crate) mod wide;
+use core::cmp::Ordering;
+use core::num::FpCategory;
+use kernel::Double;
+
 /// Explicitly stored significand bits in [`prim@f64`]
 ///
 /// This constant is usually used as a shift to access the exponent bits.
 pub const EXP_SHIFT: u32 = f64::MANTISSA_DIGITS - 1;
+
+/// Magnitude of `f64`
+///
+/// Nonzero subnormal numbers are normalized to have an implicit leading bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Magnitude {
+    /// NaN, see [`FpCategory::Nan`]
+    Nan,
+
+    /// Infinity, see [`FpCategory::Infinite`]
+    Infinite,
+
+    /// Zero, see [`FpCategory::Zero`]
+    ///
+    /// Zero cannot be normalized.  A normalized magnitude has an implicit
+    /// leading bit.
+    Zero,
+
+    /// Normalized magnitude
+    ///
+    /// The layout of the bits is the same as a normal positive `f64`.  For
+    /// subnormal numbers, the stored exponent becomes zero or negative while
+    /// the significand is normalized to have an implicit leading bit.
+    Normalized(i64),
+}
+
+/// Break a `f64` into its sign and magnitude
+#[inline]
+pub(crate) fn normalize(x: f64) -> (bool, Magnitude) {
+    let sign = x.is_sign_negative();
+    let magnitude = x.abs().to_bits() as i64;
+
+    match x.classify() {
+        FpCategory::Nan => (sign, Magnitude::Nan),
+        FpCategory::Infinite => (sign, Magnitude::Infinite),
+        FpCategory::Zero => (sign, Magnitude::Zero),
+        FpCategory::Normal => (sign, Magnitude::Normalized(magnitude)),
+        FpCategory::Subnormal => {
+            let shift = magnitude.leading_zeros() as i64 - 11;
+            let magnitude = (magnitude << shift) - (shift << EXP_SHIFT);
+            (sign, Magnitude::Normalized(magnitude))
+        }
+    }
+}
+
+/// The least number greater than `x`
+///
+/// This is a less careful version of [`f64::next_up`] regarding subnormal
+/// numbers.
+#[must_use]
+#[inline]
+pub fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x == 0.0 {
+        f64::from_bits(1)
+    } else if x.is_sign_negative() {
+        f64::from_bits(x.to_bits() - 1)
+    } else {
+        f64::from_bits(x.to_bits() + 1)
+    }
+}
+
+/// The greatest number less than `x`
+///
+/// This is a less careful version of [`f64::next_down`] regarding subnormal
+/// numbers.
+#[must_use]
+#[inline]
+pub fn next_down(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        x
+    } else if x == 0.0 {
+        f64::from_bits(0x8000_0000_0000_0001)
+    } else if x.is_sign_negative() {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+/// Rounds half-way cases away from zero
+#[must_use]
+#[inline]
+pub fn round(x: f64) -> f64 {
+    let r = x.abs();
+    let i = r.trunc();
+
+    (i + f64::from(u8::from(r - i >= 0.5))).copysign(x)
+}
+
+/// The cube root
+#[must_use]
+#[inline]
+pub fn cbrt(x: f64) -> f64 {
+    let (_, Magnitude::Normalized(magnitude)) = normalize(x) else {
+        return x;
+    };
+
+    // |x| = m * 2^(3q), where m is in 1..8
+    let exponent = (magnitude >> EXP_SHIFT) - 1023;
+    let q = exponent.div_euclid(3);
+    let m = magnitude - ((3 * q) << EXP_SHIFT);
+    let m = f64::from_bits(m as u64);
+
+    let y = f64::from_bits(0x2A9F_7893_782D_A1CE + m.to_bits() / 3);
+    let y = y * (0.5 + 1.5 * m / crate::mul_add(2.0 * y, y * y, m));
+    let y = y * (0.5 + 1.5 * m / crate::mul_add(2.0 * y, y * y, m));
+    let y = y * (0.5 + 1.5 * m / crate::mul_add(2.0 * y, y * y, m));
+
+    // Newton's step with the exact residual y^3 - m
+    let residual = kernel::two_product(y, y) * y - m;
+    let y = y - residual.0 / (3.0 * y * y);
+
+    (y * crate::exp2i(q)).copysign(x)
+}
+
+/// Hypotenuse of a right-angled triangle with sides `x` and `y`
+#[must_use]
+#[inline]
+pub fn hypot(x: f64, y: f64) -> f64 {
+    if x.is_infinite() || y.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    let (a, b) = (x.abs().max(y.abs()), x.abs().min(y.abs()));
+
+    let (Magnitude::Normalized(i), Magnitude::Normalized(j)) = (normalize(a).1, normalize(b).1)
+    else {
+        return a + b;
+    };
+
+    // The result is a subnormal number, an integer multiple of 2^-1074.
+    if a < f64::MIN_POSITIVE {
+        let a = u128::from(a.to_bits());
+        let b = u128::from(b.to_bits());
+        let n = a * a + b * b;
+        let r = n.isqrt();
+        return f64::from_bits((r + u128::from(n - r * r > r)) as u64);
+    }
+
+    let exponent = (i >> EXP_SHIFT) - 1023;
+    let difference = exponent - ((j >> EXP_SHIFT) - 1023);
+
+    // b^2 / 2a is less than 1/4 ulp of a.
+    if difference > 27 {
+        return a;
+    }
+
+    // Integer significands scaled by 2^(52 - exponent) and 2^(80 - exponent)
+    let mask = (1 << EXP_SHIFT) - 1;
+    let a = (i & mask | 1 << EXP_SHIFT) as u128;
+    let b = ((j & mask | 1 << EXP_SHIFT) as u128) << (28 - difference);
+
+    // Candidate within 1.5 ulp of the result
+    let r = {
+        let a = a as f64 * crate::exp2i(-52);
+        let b = b as f64 * crate::exp2i(-80);
+        crate::mul_add(a, a, b * b).sqrt()
+    };
+
+    // Sign of (a^2 + b^2 - m^2) * 2^160, where m = k / 2^54 is a midpoint
+    // next to r.  The wrapping arithmetic is exact because the true result
+    // is small.
+    let residual = |k: u128| {
+        ((a * a) << 56)
+            .wrapping_add(b.wrapping_mul(b))
+            .wrapping_sub(k.wrapping_mul(k) << 52) as i128
+    };
+
+    let ulp = (r.to_bits() >> EXP_SHIFT) - 1022;
+    let k = (r * crate::exp2i(54)) as u128;
+    let up = residual(k + (1 << ulp));
+    let down = residual(k - (1 << (ulp - u64::from(r.to_bits() & mask as u64 == 0))));
+
+    let r = r.to_bits();
+    let even = r & 1 == 0;
+    let r = match (up.cmp(&0), down.cmp(&0)) {
+        (Ordering::Greater, _) => r + 1,
+        (Ordering::Equal, _) => r + u64::from(!even),
+        (_, Ordering::Less) => r - 1,
+        (_, Ordering::Equal) => r - u64::from(!even),
+        _ => r,
+    };
+
+    f64::from_bits(r) * crate::exp2i(exponent)
+}
+
+/// Natural logarithm of the hypotenuse, i.e. ln |x + iy|
+///
+/// This function is the real part of [`crate::c64::ln`].  The squares are
+/// exact near the unit circle, where the result suffers from cancellation.
+/// Elsewhere, the hypotenuse is scaled to avoid overflow and underflow.
+pub(crate) fn ln_hypot(x: f64, y: f64) -> f64 {
+    const SCALE: i64 = 600;
+
+    if x.is_infinite() || y.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    let (a, b) = (x.abs().max(y.abs()), x.abs().min(y.abs()));
+    let (aa, bb) = (kernel::two_product(a, a), kernel::two_product(b, b));
+    let Double(s, t) = kernel::two_sum(aa.0, bb.0);
+
+    // s - 1 is exact by Sterbenz lemma.
+    if (0.5..=2.0).contains(&s) {
+        let Double(d, e) = kernel::two_sum(s - 1.0, t + aa.1 + bb.1);
+        return 0.5 * (ln_1p(d) + e / (1.0 + d));
+    }
+
+    if a > crate::exp2i(1000) {
+        let r = hypot(a * crate::exp2i(-SCALE), b * crate::exp2i(-SCALE));
+        return crate::mul_add(SCALE as f64, core::f64::consts::LN_2, ln(r));
+    }
+
+    if a < crate::exp2i(-1000) {
+        let r = hypot(a * crate::exp2i(SCALE), b * crate::exp2i(SCALE));
+        return crate::mul_add(-SCALE as f64, core::f64::consts::LN_2, ln(r));
+    }
+
+    ln(hypot(a, b))
+}
+
+/// Multiply `x` by 2 raised to the power of `n`
+#[must_use]
+#[inline]
+pub fn ldexp(x: f64, n: i32) -> f64 {
+    const MIN_EXP: i32 = f64::MIN_EXP - 1;
+    const MAX_EXP: i32 = f64::MAX_EXP - 1;
+
+    // Steps that keep the intermediate results normal, so that only the last
+    // multiplication rounds
+    const UP: i32 = MAX_EXP;
+    const DOWN: i32 = MIN_EXP + f64::MANTISSA_DIGITS as i32;
+
+    let (x, n) = match n {
+        ..MIN_EXP => (x * crate::exp2i(DOWN.into()), n - DOWN),
+        MIN_EXP..=MAX_EXP => (x, n),
+        _ => (x * crate::exp2i(UP.into()), n - UP),
+    };
+
+    let (x, n) = match n {
+        ..MIN_EXP => (x * crate::exp2i(DOWN.into()), (n - DOWN).max(MIN_EXP)),
+        MIN_EXP..=MAX_EXP => (x, n),
+        _ => (x * crate::exp2i(UP.into()), (n - UP).min(MAX_EXP)),
+    };
+
+    x * crate::exp2i(n.into())
+}
+
+/// Decompose into a significand and an exponent
+///
+/// The absolute value of the significand is in the range of [0.5, 1) for
+/// nonzero finite `x` for historical reasons.  This function also explains how
+/// [`f64::MAX_EXP`] and [`f64::MIN_EXP`] are defined.
+#[must_use]
+#[inline]
+pub fn frexp(x: f64) -> (f64, i32) {
+    let (sign, Magnitude::Normalized(magnitude)) = normalize(x) else {
+        return (x, 0);
+    };
+
+    let mask = f64::MIN_POSITIVE.to_bits() - 1;
+    let significand = magnitude as u64 & mask | 0.5f64.to_bits();
+
+    (
+        f64::from_bits(u64::from(sign) << 63 | significand),
+        f64::MIN_EXP - 1 + (magnitude >> EXP_SHIFT) as i32,
+    )
+}
+
+/// Split `exp(x)` into `(n, y)` such that `exp(x) = 2^n * y`
+///
+/// - `x`: a finite [`Double`] not exceeding 745.2 in magnitude
+///
+/// The returned [`Double`] `y` is within `1/√2..=√2`.
+#[inline]
+pub(crate) fn exp_split(x: Double) -> (i64, Double) {
+    let n = (x.0 * core::f64::consts::LOG2_E).round_ties_even();
+    let y = kernel::exp_m1(kernel::reduce_ln_2(x, n));
+    (n as i64, kernel::sum(1.0, y))
+}
+
+/// Split `2^x` into `(n, y)` such that `2^x = 2^n * (1 + y)`
+///
+/// - `x`: a finite `f64` not exceeding 1075 in magnitude
+///
+/// The integer `n` is rounded from `x`, so `1 + y` is within `1/√2..=√2`.
+#[inline]
+pub(crate) fn exp2_split(x: f64) -> (f64, Double) {
+    /// ln(2) in [`Double`]
+    const LN_2: Double = Double(core::f64::consts::LN_2, 2.319_046_813_846_299_6e-17);
+
+    let n = x.round_ties_even();
+    (n, kernel::exp_m1(LN_2 * (x - n)))
+}
+
+/// Split `10^x` into `(n, y)` such that `10^x = 2^n * (1 + y)`
+///
+/// - `x`: a finite `f64` not exceeding 324 in magnitude
+///
+/// The integer `n` is rounded from `x * log2(10)`, so `1 + y` is within
+/// `1/√2..=√2`.
+#[inline]
+pub(crate) fn exp10_split(x: f64) -> (f64, Double) {
+    /// ln(10) split into three parts
+    const LN_10: [f64; 3] = [
+        core::f64::consts::LN_10,
+        -2.170_756_223_382_249_4e-16,
+        -9.984_262_454_465_777e-33,
+    ];
+
+    let n = (x * core::f64::consts::LOG2_10).round_ties_even();
+    let Double(hi, lo) = kernel::two_product(x, LN_10[0]);
+    let y = kernel::two_product(x, LN_10[1]) + x.mul_add(LN_10[2], lo);
+    let y = kernel::exp_m1(kernel::reduce_ln_2(Double(hi, 0.0), n) + y);
+    (n, y)
+}
+
+/// log2(e) in [`Double`]
+pub(crate) const LOG2_E: Double = Double(core::f64::consts::LOG2_E, 2.035_527_374_093_103_3e-17);
+
+/// log10(e) in [`Double`]
+pub(crate) const LOG10_E: Double = Double(core::f64::consts::LOG10_E, 1.098_319_650_216_765e-17);
+
+/// Natural logarithm of a positive normalized [`Double`]
+#[inline]
+pub(crate) fn ln_double(x: Double) -> Double {
+    let (n, y) = kernel::ln(x.0.to_bits() as i64, x.1);
+    kernel::join_ln(n, y)
+}
+
+/// Compute `x + x^3 * (c + tail(x^2))` for tiny `x`
+///
+/// This is the Taylor expansion of odd functions near 0 such as `tanh`.
+/// The cubic term is computed in [`Double`] and added to `x` with
+/// [`kernel::sum`], so the result is ready for [`kernel::round`].
+#[inline]
+fn odd_taylor(x: f64, c: Double, tail: &[f64; 2]) -> Double {
+    let y = kernel::two_product(x, x);
+    let p = c + y.0 * crate::poly(y.0, tail);
+    kernel::sum(x, y * x * p)
+}
+
+/// Bound of the relative error of [`Double`] results before rounding
+///
+/// If the rounding of a result cannot be determined within this bound, it
+/// is recomputed with [`wide`] arithmetic.
+const ERROR: f64 = crate::exp2i(-80);
+
+/// The exponential function
+#[must_use]
+#[inline]
+pub fn exp(x: f64) -> f64 {
+    if x < -745.2 {
+        return 0.0;
+    }
+
+    if x > 709.8 {
+        return f64::INFINITY;
+    }
+
+    let (n, y) = exp_split(x.into());
+    kernel::round(y, n, ERROR).unwrap_or_else(|| wide::exp(x.into()).round())
+}
+
+/// Raise 2 to the power of `x`
+#[must_use]
+#[inline]
+pub fn exp2(x: f64) -> f64 {
+    if x <= (f64::MIN_EXP - f64::MANTISSA_DIGITS as i32 - 1).into() {
+        return 0.0;
+    }
+
+    if x >= f64::MAX_EXP.into() {
+        return f64::INFINITY;
+    }
+
+    let (n, y) = exp2_split(x);
+    kernel::round(kernel::sum(1.0, y), n as i64, ERROR).unwrap_or_else(|| wide::exp2(x).round())
+}
+
+/// Raise 10 to the power of `x`
+#[must_use]
+#[inline]
+pub fn exp10(x: f64) -> f64 {
+    if x < -324.0 {
+        return 0.0;
+    }
+
+    if x > 309.0 {
+        return f64::INFINITY;
+    }
+
+    let (n, y) = exp10_split(x);
+    let y = kernel::round(kernel::sum(1.0, y), n as i64, ERROR);
+
+    y.unwrap_or_else(|| {
+        // Exact powers of 10, where 10^23 is a tie
+        if x == x.trunc() && (0.0..=23.0).contains(&x) {
+            return 10_u128.pow(x as u32) as f64;
+        }
+        wide::exp10(x).round()
+    })
+}
+
+/// Compute `exp(x) - 1` accurately especially for small `x`
+#[must_use]
+#[inline]
+pub fn exp_m1(x: f64) -> f64 {
+    let s = x.abs();
+
+    if s < crate::exp2i(-54) {
+        return x;
+    }
+
+    if x < -38.0 {
+        return -1.0;
+    }
+
+    if x > 709.8 {
+        return f64::INFINITY;
+    }
+
+    // x + x^2 / 2 is exact in three parts, where ties can happen.
+    if s < crate::exp2i(-30) {
+        let Double(hi, lo) = kernel::two_product(x, x);
+        let tail = x * hi * crate::poly(x, &[1.0 / 6.0, 1.0 / 24.0]);
+        return kernel::sum(x, Double(0.5 * hi, crate::mul_add(0.5, lo, tail))).0;
+    }
+
+    let n = (x * core::f64::consts::LOG2_E).round_ties_even();
+    let y = kernel::exp_m1(kernel::reduce_ln_2(x.into(), n));
+
+    let y = if n == 0.0 {
+        kernel::round(y, 0, ERROR)
+    } else if n > 1000.0 {
+        kernel::round(kernel::sum(1.0, y), n as i64, ERROR)
+    } else {
+        let scale = crate::exp2i(n as i64);
+        let Double(hi, lo) = kernel::two_sum(scale, -1.0);
+        kernel::round(kernel::sum(hi, y * scale + lo), 0, ERROR)
+    };
+
+    y.unwrap_or_else(|| wide::exp_m1(x).round())
+}
+
+/// Natural logarithm
+#[must_use]
+#[inline]
+pub fn ln(x: f64) -> f64 {
+    match normalize(x) {
+        (false, Magnitude::Infinite) => f64::INFINITY,
+        (_, Magnitude::Zero) => f64::NEG_INFINITY,
+        (true, _) | (_, Magnitude::Nan) => f64::NAN,
+
+        (false, Magnitude::Normalized(i)) => {
+            let (n, y) = kernel::ln(i, 0.0);
+            kernel::round(kernel::join_ln(n, y), 0, ERROR).unwrap_or_else(|| wide::ln(i).round())
+        }
+    }
+}
+
+/// Compute `ln(1 + x)` accurately especially for small `x`
+#[must_use]
+#[inline]
+pub fn ln_1p(x: f64) -> f64 {
+    if x.abs() < crate::exp2i(-54) {
+        return x;
+    }
+
+    match x {
+        f64::INFINITY => f64::INFINITY,
+        -1.0 => f64::NEG_INFINITY,
+        x if x < -1.0 || x.is_nan() => f64::NAN,
+        _ => kernel::round(ln_1p_double(x), 0, ERROR)
+            .unwrap_or_else(|| wide::ln_1p(x.into()).round()),
+    }
+}
+
+/// `ln(1 + x)` in [`Double`] for a finite `x > -1`
+#[inline]
+pub(crate) fn ln_1p_double(x: f64) -> Double {
+    // x - x^2 / 2 is exact in three parts, where ties can happen.
+    if x.abs() < crate::exp2i(-30) {
+        let Double(hi, lo) = kernel::two_product(x, x);
+        let tail = x * hi * crate::poly(x, &[1.0 / 3.0, -0.25]);
+        return kernel::sum(x, Double(-0.5 * hi, crate::mul_add(-0.5, lo, tail)));
+    }
+
+    let Double(hi, lo) = kernel::two_sum(1.0, x);
+    let (n, y) = kernel::ln(hi.to_bits() as i64, lo);
+    kernel::join_ln(n, y)
+}
+
+/// Base 2 logarithm
+#[must_use]
+#[inline]
+pub fn log2(x: f64) -> f64 {
+    match normalize(x) {
+        (false, Magnitude::Infinite) => f64::INFINITY,
+        (_, Magnitude::Zero) => f64::NEG_INFINITY,
+        (true, _) | (_, Magnitude::Nan) => f64::NAN,
+
+        (false, Magnitude::Normalized(i)) => {
+            let (n, y) = kernel::ln(i, 0.0);
+            kernel::round(kernel::sum(n, y * LOG2_E), 0, ERROR)
+                .unwrap_or_else(|| (wide::ln(i) * wide::Wide::LOG2_E).round())
+        }
+    }
+}
+
+/// Base 10 logarithm
+#[must_use]
+#[inline]
+pub fn log10(x: f64) -> f64 {
+    /// log10(2) split like [`kernel::LN_2`]
+    const LOG10_2: [f64; 3] = [
+        0.301_029_995_663_952_83,
+        2.836_339_455_104_226_3e-14,
+        2.701_342_905_898_053_4e-27,
+    ];
+
+    match normalize(x) {
+        (false, Magnitude::Infinite) => f64::INFINITY,
+        (_, Magnitude::Zero) => f64::NEG_INFINITY,
+        (true, _) | (_, Magnitude::Nan) => f64::NAN,
+
+        (false, Magnitude::Normalized(i)) => {
+            let (n, y) = kernel::ln(i, 0.0);
+            let [hi, mid, lo] = LOG10_2;
+            let y = kernel::two_sum(n * hi, n * mid) + n * lo + y * LOG10_E;
+            kernel::round(y, 0, ERROR)
+                .unwrap_or_else(|| (wide::ln(i) * wide::Wide::LOG10_E).round())
+        }
+    }
+}
+
+/// Sine
+#[must_use]
+#[inline]
+pub fn sin(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+
+    let (q, y) = kernel::rem_pio2(x.abs());
+    let y = match q & 1 {
+        0 => kernel::sin(y),
+        _ => kernel::cos(y),
+    };
+    let y = if (q & 2 == 2) != x.is_sign_negative() {
+        -y
+    } else {
+        y
+    };
+
+    kernel::round(y, 0, ERROR).unwrap_or_else(|| wide::sin_cos(x).0.round())
+}
+
+/// Cosine
+#[must_use]
+#[inline]
+pub fn cos(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+
+    let (q, y) = kernel::rem_pio2(x.abs());
+
+    let y = match q & 3 {
+        0 => kernel::cos(y),
+        1 => -kernel::sin(y),
+        2 => -kernel::cos(y),
+        _ => kernel::sin(y),
+    };
+
+    kernel::round(y, 0, ERROR).unwrap_or_else(|| wide::sin_cos(x).1.round())
+}
+
+/// Compute sine and cosine simultaneously
+#[must_use]
+#[inline]
+pub fn sin_cos(x: f64) -> (f64, f64) {
+    if !x.is_finite() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let (q, y) = kernel::rem_pio2(x.abs());
+    let s = kernel::sin(y);
+    let c = kernel::cos(y);
+
+    let (s, c) = match q & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+    let s = if x.is_sign_negative() { -s } else { s };
+
+    match (kernel::round(s, 0, ERROR), kernel::round(c, 0, ERROR)) {
+        (Some(s), Some(c)) => (s, c),
+        (s, c) => {
+            let (t, d) = wide::sin_cos(x);
+            (
+                s.unwrap_or_else(|| t.round()),
+                c.unwrap_or_else(|| d.round()),
+            )
+        }
+    }
+}
+
+/// Tangent function
+#[must_use]
+#[inline]
+pub fn tan(x: f64) -> f64 {
+    if !x.is_finite() {
+        return f64::NAN;
+    }
+
+    let (q, y) = kernel::rem_pio2(x.abs());
+    let s = kernel::sin(y);
+    let c = kernel::cos(y);
+
+    let y = match q & 1 {
+        0 => s / c,
+        _ => -(c / s),
+    };
+    let y = if x.is_sign_negative() { -y } else { y };
+
+    kernel::round(y, 0, ERROR).unwrap_or_else(|| {
+        let (s, c) = wide::sin_cos(x);
+        (s / c).round()
+    })
+}
+
+/// `sqrt(1 - x^2)` in [`wide::Wide`] for `x` in `0..=1`
+///
+/// Both `1 - x` and `1 + x` are exact, so this function is as accurate as
+/// [`wide::sqrt`].
+#[inline]
+fn wide_sqrt_1m_sq(x: f64) -> wide::Wide {
+    let one = wide::Wide::from(1.0);
+    let x = wide::Wide::from(x);
+    wide::sqrt((one - x) * (one + x))
+}
+
+/// Arccosine
+#[must_use]
+#[inline]
+pub fn acos(x: f64) -> f64 {
+    let s = x.abs();
+
+    if s > 1.0 || s.is_nan() {
+        return f64::NAN;
+    }
+
+    let c = kernel::sqrt(kernel::two_sum(1.0, -s) * kernel::two_sum(1.0, s));
+    let y = kernel::atan2(c, s.into());
+    let y = if x.is_sign_negative() {
+        kernel::PI - y
+    } else {
+        y
+    };
+
+    kernel::round(y, 0, ERROR).unwrap_or_else(|| {
+        let y = wide::atan2(wide_sqrt_1m_sq(s), s.into());
+        if x.is_sign_negative() {
+            (wide::Wide::PI - y).round()
+        } else {
+            y.round()
+        }
+    })
+}
+
+/// Arcsine
+#[must_use]
+#[inline]
+pub fn asin(x: f64) -> f64 {
+    let s = x.abs();
+
+    if s < crate::exp2i(-27) {
+        return x;
+    }
+
+    if s > 1.0 || s.is_nan() {
+        return f64::NAN;
+    }
+
+    let c = kernel::sqrt(kernel::two_sum(1.0, -s) * kernel::two_sum(1.0, s));
+    let y = kernel::round(kernel::atan2(s.into(), c), 0, ERROR);
+    y.unwrap_or_else(|| wide::atan2(s.into(), wide_sqrt_1m_sq(s)).round())
+        .copysign(x)
+}
+
+/// Arctangent
+#[must_use]
+#[inline]
+pub fn atan(x: f64) -> f64 {
+    let s = x.abs();
+
+    if s < crate::exp2i(-27) || s.is_nan() {
+        return x;
+    }
+
+    if s > crate::exp2i(64) {
+        return kernel::FRAC_PI_2.0.copysign(x);
+    }
+
+    let y = kernel::round(kernel::atan2(s.into(), 1.0.into()), 0, ERROR);
+    y.unwrap_or_else(|| wide::atan2(s.into(), 1.0.into()).round())
+        .copysign(x)
+}
+
+/// Angle of the point `(x, y)` in radians
+///
+/// The result is in `-π..=π`.  Signed zeros and infinities follow Annex F of
+/// the C standard, e.g. `atan2(±0, -0) = ±π` and `atan2(±∞, -∞) = ±3π/4`.
+#[must_use]
+#[inline]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    use kernel::FRAC_PI_2;
+
+    let angle = match (normalize(y), normalize(x)) {
+        ((_, Magnitude::Nan), _) | (_, (_, Magnitude::Nan)) => return f64::NAN,
+        ((_, Magnitude::Infinite), (_, Magnitude::Infinite)) => FRAC_PI_2 * 0.5,
+        ((_, Magnitude::Infinite), _) => FRAC_PI_2,
+        (_, (_, Magnitude::Infinite)) | ((_, Magnitude::Zero), _) => Double(0.0, 0.0),
+        (_, (_, Magnitude::Zero)) => FRAC_PI_2,
+
+        ((_, Magnitude::Normalized(i)), (_, Magnitude::Normalized(j))) => {
+            let mask = (1 << EXP_SHIFT) - 1;
+            let exponent = (i >> EXP_SHIFT) - (j >> EXP_SHIFT);
+            let a = f64::from_bits((i & mask | 1.0_f64.to_bits() as i64) as u64);
+            let b = f64::from_bits((j & mask | 1.0_f64.to_bits() as i64) as u64);
+
+            match exponent {
+                ..-60 if x.is_sign_positive() => {
+                    // atan(r) = r - r^3 / 3 is slightly less than r.
+                    let r = Double::from(a) / b.into();
+                    let r = Double(r.0, r.1 - r.0 * crate::exp2i(-120));
+                    return kernel::ldexp(r, exponent).copysign(y);
+                }
+                ..-60 => (Double::from(a) / b.into()) * crate::exp2i(exponent),
+                61.. => FRAC_PI_2 - (Double::from(b) / a.into()) * crate::exp2i(-exponent),
+                -60..=0 => kernel::atan2((a * crate::exp2i(exponent)).into(), b.into()),
+                1..=60 => kernel::atan2(a.into(), (b * crate::exp2i(-exponent)).into()),
+            }
+        }
+    };
+
+    let angle = if x.is_sign_negative() {
+        kernel::PI - angle
+    } else {
+        angle
+    };
+
+    let angle = kernel::round(angle, 0, ERROR).unwrap_or_else(|| {
+        let angle = wide::atan2(y.abs().into(), x.abs().into());
+        if x.is_sign_negative() {
+            (wide::Wide::PI - angle).round()
+        } else {
+            angle.round()
+        }
+    });
+
+    angle.copysign(y)
+}
+
+/// Hyperbolic sine
+#[must_use]
+#[inline]
+pub fn sinh(x: f64) -> f64 {
+    let s = x.abs();
+
+    if s < crate::exp2i(-27) || !s.is_finite() {
+        return x;
+    }
+
+    if s > 710.5 {
+        return f64::INFINITY.copysign(x);
+    }
+
+    let (magnitude, n) = match s {
+        s if s <= 0.5 * core::f64::consts::LN_2 => (kernel::sum(s, kernel::sinh_tail(s)), 0),
+        s => {
+            let (n, y) = exp_split(s.into());
+
+            // exp(-s) is negligible
+            if s > 40.0 {
+                (y, n - 1)
+            } else {
+                let y = y * crate::exp2i(n);
+                ((y - Double::from(1.0) / y) * 0.5, 0)
+            }
+        }
+    };
+
+    kernel::round(magnitude, n, ERROR)
+        .unwrap_or_else(|| wide::sinh(s).round())
+        .copysign(x)
+}
+
+/// Hyperbolic cosine
+#[must_use]
+#[inline]
+pub fn cosh(x: f64) -> f64 {
+    let (y, n) = match x.abs() {
+        s if s < crate::exp2i(-27) => return 1.0,
+
+        // cosh(s) - 1 = sinh(s)^2 / (cosh(s) + 1)
+        s if s <= 0.5 * core::f64::consts::LN_2 => {
+            let y = kernel::sinh_tail(s) + s;
+            let y = y * y;
+            (kernel::sum(1.0, y / (kernel::sqrt(y + 1.0) + 1.0)), 0)
+        }
+        s if s <= 40.0 => {
+            let (n, y) = exp_split(s.into());
+            let y = y * crate::exp2i(n);
+            ((y + Double::from(1.0) / y) * 0.5, 0)
+        }
+        s if s <= 710.5 => {
+            let (n, y) = exp_split(s.into());
+            (y, n - 1)
+        }
+        s if s.is_nan() => return s,
+        _ => return f64::INFINITY,
+    };
+
+    kernel::round(y, n, ERROR).unwrap_or_else(|| wide::cosh(x.abs()).round())
+}
+
+/// Hyperbolic tangent
+#[must_use]
+#[inline]
+pub fn tanh(x: f64) -> f64 {
+    let s = x.abs();
+
+    if s < crate::exp2i(-27) || s.is_nan() {
+        return x;
+    }
+
+    let magnitude = match s {
+        s if s < crate::exp2i(-16) => {
+            const C: Double = Double(-0.333_333_333_333_333_3, -1.850_371_707_708_594e-17);
+            odd_taylor(s, C, &[2.0 / 15.0, -17.0 / 315.0])
+        }
+
+        // tanh(s) = E / (E + 2), where E = exp(2s) - 1
+        s if s < 0.18 => {
+            let y = kernel::exp_m1((2.0 * s).into());
+            y / (y + 2.0)
+        }
+        s if s < 19.1 => {
+            let (n, y) = exp_split((2.0 * s).into());
+            let y = y * crate::exp2i(n);
+            (y - 1.0) / (y + 1.0)
+        }
+        _ => return 1.0_f64.copysign(x),
+    };
+
+    kernel::round(magnitude, 0, ERROR)
+        .unwrap_or_else(|| wide::tanh(s).round())
+        .copysign(x)
+}
+
+/// Inverse hyperbolic sine
+#[must_use]
+#[inline]
+pub fn asinh(x: f64) -> f64 {
+    let s = x.abs();
+
+    if s < crate::exp2i(-27) || !s.is_finite() {
+        return x;
+    }
+
+    let magnitude = match s {
+        s if s < crate::exp2i(-16) => {
+            const C: Double = Double(-0.166_666_666_666_666_66, -9.251_858_538_542_97e-18);
+            odd_taylor(s, C, &[3.0 / 40.0, -5.0 / 112.0])
+        }
+
+        // asinh(s) = 2 atanh(s / (√(s^2 + 1) + 1))
+        s if s < 0.35 => {
+            let c = kernel::sqrt(kernel::two_product(s, s) + 1.0);
+            kernel::atanh(Double::from(s) / (c + 1.0)) * 2.0
+        }
+        s if s < crate::exp2i(28) => {
+            let c = kernel::sqrt(kernel::two_product(s, s) + 1.0);
+            ln_double(c + s)
+        }
+
+        // asinh(s) = ln(2s) + 1 / (4s^2) + O(s^-4)
+        s => {
+            let (n, y) = kernel::ln(s.to_bits() as i64, 0.0);
+            kernel::join_ln(n + 1.0, y + 0.25 / (s * s))
+        }
+    };
+
+    kernel::round(magnitude, 0, ERROR)
+        .unwrap_or_else(|| wide::asinh(s).round())
+        .copysign(x)
+}
+
+/// Inverse hyperbolic cosine
+#[must_use]
+#[inline]
+pub fn acosh(x: f64) -> f64 {
+    let y = match x {
+        f64::INFINITY => return f64::INFINITY,
+
+        // acosh(x) = 2 atanh(d / (d + 2)), where d = x - 1 + √(x^2 - 1)
+        1.0..1.06 => {
+            let d = x - 1.0;
+            let d = kernel::sqrt(kernel::two_sum(x, 1.0) * d) + d;
+            kernel::atanh(d / (d + 2.0)) * 2.0
+        }
+        1.06..268_435_456.0 => {
+            let s = kernel::sqrt(kernel::two_product(x, x) - 1.0);
+            ln_double(s + x)
+        }
+
+        // acosh(x) = ln(2x) - 1 / (4x^2) + O(x^-4)
+        268_435_456.0.. => {
+            let (n, y) = kernel::ln(x.to_bits() as i64, 0.0);
+            kernel::join_ln(n + 1.0, y - 0.25 / (x * x))
+        }
+
+        _ => return f64::NAN,
+    };
+
+    kernel::round(y, 0, ERROR).unwrap_or_else(|| wide::acosh(x).round())
+}
+
+/// Inverse hyperbolic tangent
+#[must_use]
+#[inline]
+pub fn atanh(x: f64) -> f64 {
+    let s = x.abs();
+
+    if s < crate::exp2i(-27) || s.is_nan() {
+        return x;
+    }
+
+    let magnitude = match s {
+        s if s < crate::exp2i(-16) => {
+            const C: Double = Double(0.333_333_333_333_333_3, 1.850_371_707_708_594e-17);
+            odd_taylor(s, C, &[0.2, 1.0 / 7.0])
+        }
+        s if s < 0.17 => kernel::atanh(s.into()),
+
+        // atanh(s) = ln((1 + s) / (1 - s)) / 2
+        s if s < 1.0 => {
+            let y = kernel::two_sum(1.0, s) / kernel::two_sum(1.0, -s);
+            ln_double(y) * 0.5
+        }
+        1.0 => return f64::INFINITY.copysign(x),
+        _ => return f64::NAN,
+    };
+
+    kernel::round(magnitude, 0, ERROR)
+        .unwrap_or_else(|| wide::atanh(s).round())
+        .copysign(x)
+}
+
+/// Compute `x^y` exactly if it is a dyadic rational with a short significand
+///
+/// - `x`: a positive finite number
+///
+/// Exact results are the hardest cases of correct rounding because they can
+/// be ties, e.g. `pow(25.0, 11.5)` = 5<sup>23</sup>.  If `x^y` is a dyadic
+/// rational, `y = n / 2^k` and the significand of `x` is a perfect
+/// (2<sup>k</sup>)th power.  Its odd part has at most 53 bits, so `k` is
+/// small and the result fits in 64 bits unless it is irrelevant to ties.
+///
+/// The exact result is returned as `(z, n)` meaning `z * 2^n`.
+pub(crate) fn exact_pow(x: f64, y: f64) -> Option<(Double, i64)> {
+    let Magnitude::Normalized(i) = normalize(x).1 else {
+        return None;
+    };
+
+    // x = w * 2^exponent, where w is odd
+    let w = (i & ((1 << EXP_SHIFT) - 1)) | 1 << EXP_SHIFT;
+    let zeros = w.trailing_zeros();
+    let exponent = (i >> EXP_SHIFT) - 1023 - i64::from(EXP_SHIFT - zeros);
+    let mut w = (w >> zeros) as u64;
+
+    // y = n / 2^k
+    let k = (0..6).find(|&k| (y * crate::exp2i(k)).fract() == 0.0)?;
+    let n = y * crate::exp2i(k);
+
+    if n.abs() > 1_048_576.0 {
+        return None;
+    }
+
+    for _ in 0..k {
+        let root = (w as f64).sqrt() as u64;
+
+        if root * root != w {
+            return None;
+        }
+        w = root;
+    }
+
+    let n = n as i64;
+
+    if n < 0 && w != 1 {
+        return None;
+    }
+
+    let exponent = exponent * n;
+
+    if exponent & ((1 << k) - 1) != 0 {
+        return None;
+    }
+
+    let significand = w.checked_pow(n.unsigned_abs() as u32)?;
+    let hi = significand as f64;
+    let lo = (i128::from(significand) - hi as i128) as f64;
+    Some((Double(hi, lo), exponent >> k))
+}
+
+/// Power function
+///
+/// Special cases follow Annex F of the C standard.  Finite results are first
+/// computed in double-double.  If they are too close to a tie, they are
+/// checked for exact results and then recomputed with about 120 correct bits.
+#[must_use]
+#[inline]
+pub fn pow(x: f64, y: f64) -> f64 {
+    #[inline]
+    fn magnitude(x: f64, y: f64) -> f64 {
+        match x.classify() {
+            FpCategory::Nan => f64::NAN,
+            FpCategory::Infinite => match y.partial_cmp(&0.0) {
+                Some(Ordering::Greater) => f64::INFINITY,
+                Some(Ordering::Less) => 0.0,
+                Some(Ordering::Equal) => 1.0,
+                None => f64::NAN,
+            },
+            FpCategory::Zero => match y.partial_cmp(&0.0) {
+                Some(Ordering::Greater) => 0.0,
+                Some(Ordering::Less) => f64::INFINITY,
+                Some(Ordering::Equal) => 1.0,
+                None => f64::NAN,
+            },
+            _ => match x {
+                1.0 => 1.0,
+                x if x.is_sign_negative() => f64::NAN,
+                _ if y.is_nan() => f64::NAN,
+                x if y.is_infinite() => {
+                    if (x < 1.0) == (y < 0.0) {
+                        f64::INFINITY
+                    } else {
+                        0.0
+                    }
+                }
+                _ => finite(x, y),
+            },
+        }
+    }
+
+    #[inline]
+    fn finite(x: f64, y: f64) -> f64 {
+        let Magnitude::Normalized(i) = normalize(x).1 else {
+            return f64::NAN;
+        };
+
+        let (n, z) = kernel::ln(i, 0.0);
+        let z = kernel::join_ln(n, z);
+
+        // Check the rough product first because it can overflow.
+        if z.0 * y > 709.8 {
+            return f64::INFINITY;
+        }
+
+        if z.0 * y < -745.2 {
+            return 0.0;
+        }
+
+        let z = z * y;
+
+        // Error bound of exp(z) relative to `w`, which is close to 1
+        let error = crate::exp2i(-85);
+
+        let n = (z.0 * core::f64::consts::LOG2_E).round_ties_even();
+        let w = kernel::sum(1.0, kernel::exp_m1(kernel::reduce_ln_2(z, n)));
+        let lower = kernel::ldexp(kernel::fast_two_sum(w.0, w.1 - error), n as i64);
+        let upper = kernel::ldexp(kernel::fast_two_sum(w.0, w.1 + error), n as i64);
+
+        if lower == upper {
+            return lower;
+        }
+
+        if let Some((z, n)) = exact_pow(x, y) {
+            return kernel::ldexp(z, n);
+        }
+
+        wide::exp(wide::ln(i) * wide::Wide::from(y)).round()
+    }
+
+    #[inline]
+    fn is_integer(x: f64) -> bool {
+        x.trunc().eq(&x)
+    }
+
+    if y == 0.0 {
+        return 1.0;
+    }
+
+    if x.is_sign_negative() && is_integer(y) {
+        let sign = if is_integer(0.5 * y) { 1.0 } else { -1.0 };
+        return sign * magnitude(-x, y);
+    }
+
+    magnitude(x, y)
+}
@@ -0,0 +1,657 @@
+use super::kernel::{self, Double};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Floating-point number with a 128-bit significand
+///
+/// The value is `(-1)^sign * significand * 2^(exponent - 127)`.  A nonzero
+/// significand is normalized to have its highest bit set, so the exponent is
+/// the same as an unbiased IEEE 754 exponent.
+///
+/// This type is slow but accurate.  It is the fallback for hard cases of
+/// correct rounding, where [`Double`] is not accurate enough.  Arithmetic
+/// truncates instead of rounding, so each operation has a relative error
+/// below 2<sup>-126</sup>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wide {
+    pub sign: bool,
+    pub exponent: i64,
+    pub significand: u128,
+}
+
+impl Wide {
+    /// Zero in [`Wide`]
+    pub const ZERO: Self = Self {
+        sign: false,
+        exponent: 0,
+        significand: 0,
+    };
+
+    /// ln(2) split into parts
+    ///
+    /// The first part has only 115 significant bits, so that its product with
+    /// an integer not exceeding 2<sup>11</sup> in magnitude is exact.  The
+    /// product also has a spare bit, so subtracting it from a nearby `f64` is
+    /// exact.
+    pub const LN_2: [Self; 2] = [
+        Self {
+            sign: false,
+            exponent: -1,
+            significand: 0xB172_17F7_D1CF_79AB_C9E3_B398_03F2_E000,
+        },
+        Self {
+            sign: false,
+            exponent: -116,
+            significand: 0xB57A_079A_1933_94C5_B16C_5068_BADC_5D58,
+        },
+    ];
+
+    /// ln(10) split into parts
+    ///
+    /// The first part has only 72 significant bits, so that its product with
+    /// a `f64` is exact with a spare bit like [`Self::LN_2`].
+    pub const LN_10: [Self; 2] = [
+        Self {
+            sign: false,
+            exponent: 1,
+            significand: 0x935D_8DDD_AAA8_AC16_EA00_0000_0000_0000,
+        },
+        Self {
+            sign: false,
+            exponent: -72,
+            significand: 0xADAC_5705_A614_51C5_1FD9_F3B4_BBF2_1D08,
+        },
+    ];
+
+    /// log<sub>2</sub>(e) in [`Wide`]
+    pub const LOG2_E: Self = Self {
+        sign: false,
+        exponent: 0,
+        significand: 0xB8AA_3B29_5C17_F0BB_BE87_FED0_691D_3E89,
+    };
+
+    /// log<sub>10</sub>(e) in [`Wide`]
+    pub const LOG10_E: Self = Self {
+        sign: false,
+        exponent: -2,
+        significand: 0xDE5B_D8A9_3728_7195_355B_AAAF_AD33_DC32,
+    };
+
+    /// π/2 in [`Wide`]
+    pub const FRAC_PI_2: Self = Self {
+        sign: false,
+        exponent: 0,
+        significand: 0xC90F_DAA2_2168_C234_C4C6_628B_80DC_1CD1,
+    };
+
+    /// π in [`Wide`]
+    pub const PI: Self = Self {
+        exponent: 1,
+        ..Self::FRAC_PI_2
+    };
+
+    /// Multiply by 2<sup>`n`</sup>
+    #[inline]
+    fn scale(self, n: i64) -> Self {
+        if self.significand == 0 {
+            return self;
+        }
+        Self {
+            exponent: self.exponent + n,
+            ..self
+        }
+    }
+
+    /// Divide by a positive integer not exceeding 2<sup>64</sup>
+    #[inline]
+    pub fn div_small(self, n: u128) -> Self {
+        if self.significand == 0 {
+            return self;
+        }
+
+        let quotient = self.significand / n;
+        let remainder = self.significand % n;
+        let shift = quotient.leading_zeros();
+
+        Self {
+            exponent: self.exponent - i64::from(shift),
+            significand: (quotient << shift) | ((remainder << shift) / n),
+            ..self
+        }
+    }
+
+    /// Rough conversion to `f64` by truncation
+    #[inline]
+    fn approx(self) -> f64 {
+        let x = (self.significand >> 64) as f64 * crate::exp2i(self.exponent - 63);
+        if self.sign {
+            -x
+        } else {
+            x
+        }
+    }
+
+    /// Correctly rounded conversion to `f64`
+    ///
+    /// Overflow and gradual underflow are handled.  The significand beyond
+    /// 128 bits is considered zero, so ties are broken to even.
+    #[inline]
+    pub fn round(self) -> f64 {
+        self.round_to(f64::MANTISSA_DIGITS, f64::MIN_EXP)
+    }
+
+    /// Correctly rounded conversion to `f32`
+    ///
+    /// This method works like [`Self::round`] but for `f32`.
+    #[inline]
+    pub fn round_f32(self) -> f32 {
+        // The rounded value is exactly representable in `f32` unless it
+        // overflows, where the conversion also gives infinity.
+        #[allow(clippy::cast_possible_truncation)]
+        return self.round_to(f32::MANTISSA_DIGITS, f32::MIN_EXP) as f32;
+    }
+
+    /// Round to `digits` significant bits with gradual underflow below
+    /// 2<sup>`min_exp - 1`</sup>
+    ///
+    /// The arguments are like [`f64::MANTISSA_DIGITS`] and [`f64::MIN_EXP`].
+    /// The result is exact in `f64` as long as `digits` does not exceed 53.
+    fn round_to(self, digits: u32, min_exp: i32) -> f64 {
+        let min_exp = i64::from(min_exp) - 1;
+
+        if self.significand == 0 {
+            return if self.sign { -0.0 } else { 0.0 };
+        }
+
+        // Number of bits to discard, more for subnormal numbers
+        let shift = 128 - i64::from(digits) + (min_exp - self.exponent).max(0);
+
+        let magnitude = if shift > 128 {
+            0.0
+        } else {
+            let (kept, rest) = match shift {
+                128 => (0, self.significand),
+                _ => (self.significand >> shift, self.significand << (128 - shift)),
+            };
+            let tie = 1 << 127;
+            let carry = rest > tie || (rest == tie && kept & 1 == 1);
+            let kept = kept + u128::from(carry);
+
+            // `kept` has at most 54 bits, so the conversion is exact.
+            #[allow(clippy::cast_precision_loss)]
+            let kept = kept as f64;
+            let exponent = self.exponent + shift - 127;
+            let half = exponent / 2;
+            kept * crate::exp2i(half) * crate::exp2i(exponent - half)
+        };
+
+        if self.sign {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl From<f64> for Wide {
+    /// Exact conversion from a finite `f64`
+    #[inline]
+    fn from(x: f64) -> Self {
+        match super::normalize(x) {
+            (sign, super::Magnitude::Normalized(i)) => {
+                let mask = (1 << super::EXP_SHIFT) - 1;
+
+                #[allow(clippy::cast_sign_loss)]
+                let significand = ((i & mask | 1 << super::EXP_SHIFT) as u128) << 75;
+
+                Self {
+                    sign,
+                    exponent: (i >> super::EXP_SHIFT) - 1023,
+                    significand,
+                }
+            }
+            _ => Self::ZERO,
+        }
+    }
+}
+
+impl From<Double> for Wide {
+    #[inline]
+    fn from(x: Double) -> Self {
+        Self::from(x.0) + Self::from(x.1)
+    }
+}
+
+impl From<Wide> for Double {
+    /// Conversion to [`Double`] by truncation
+    ///
+    /// The result is accurate to about 106 bits but not normalized.  The
+    /// magnitude of `x` must be within the normal range of `f64`.
+    #[inline]
+    fn from(x: Wide) -> Self {
+        let hi = x.approx();
+        Self(hi, (x - Wide::from(hi)).approx())
+    }
+}
+
+impl Neg for Wide {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            sign: !self.sign,
+            ..self
+        }
+    }
+}
+
+impl Add for Wide {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        if other.significand == 0 {
+            return self;
+        }
+
+        if self.significand == 0 {
+            return other;
+        }
+
+        let (a, b) = if (self.exponent, self.significand) >= (other.exponent, other.significand) {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let same_sign = a.sign == b.sign;
+        let b = u32::try_from(a.exponent - b.exponent)
+            .ok()
+            .and_then(|shift| b.significand.checked_shr(shift))
+            .unwrap_or(0);
+
+        if same_sign {
+            return match a.significand.overflowing_add(b) {
+                (sum, false) => Self {
+                    significand: sum,
+                    ..a
+                },
+                (sum, true) => Self {
+                    exponent: a.exponent + 1,
+                    significand: (sum >> 1) | (1 << 127),
+                    ..a
+                },
+            };
+        }
+
+        let difference = a.significand - b;
+
+        if difference == 0 {
+            return Self::ZERO;
+        }
+
+        let shift = difference.leading_zeros();
+
+        Self {
+            exponent: a.exponent - i64::from(shift),
+            significand: difference << shift,
+            ..a
+        }
+    }
+}
+
+impl Sub for Wide {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for Wide {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        const MASK: u128 = u64::MAX as u128;
+
+        if self.significand == 0 || other.significand == 0 {
+            return Self::ZERO;
+        }
+
+        let (a, b) = (self.significand, other.significand);
+        let (a1, a0) = (a >> 64, a & MASK);
+        let (b1, b0) = (b >> 64, b & MASK);
+
+        let low = a0 * b0;
+        let cross = [a0 * b1, a1 * b0];
+        let middle = (low >> 64) + (cross[0] & MASK) + (cross[1] & MASK);
+        let high = a1 * b1 + (cross[0] >> 64) + (cross[1] >> 64) + (middle >> 64);
+
+        let sign = self.sign != other.sign;
+        let exponent = self.exponent + other.exponent;
+
+        if high >> 127 == 1 {
+            Self {
+                sign,
+                exponent: exponent + 1,
+                significand: high,
+            }
+        } else {
+            Self {
+                sign,
+                exponent,
+                significand: (high << 1) | ((middle & MASK) >> 63),
+            }
+        }
+    }
+}
+
+impl Div for Wide {
+    type Output = Self;
+
+    /// Division by a nonzero number
+    ///
+    /// The reciprocal of the divisor is refined from `f64` by two steps of
+    /// Newton's method, so the quotient has a few more bits of error than
+    /// other operations.
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        let two = Self::from(2.0);
+        let exponent = other.exponent;
+        let other = other.scale(-exponent);
+        let mut r = Self::from(other.approx().recip());
+
+        for _ in 0..2 {
+            r = r * (two - other * r);
+        }
+        (self * r).scale(-exponent)
+    }
+}
+
+/// Restriction of `exp(x) - 1` to `-0.36..=0.36`
+///
+/// This function works like [`kernel::exp_m1`] but with [`Wide`].
+#[inline]
+fn reduced_exp_m1(x: Wide) -> Wide {
+    let one = Wide::from(1.0);
+    let two = Wide::from(2.0);
+    let x = x.scale(-8);
+
+    let y = (2..=12).rev().fold(one, |y, n| one + (y * x).div_small(n));
+    let mut y = y * x;
+
+    for _ in 0..8 {
+        y = y * (y + two);
+    }
+    y
+}
+
+/// Natural logarithm of a normalized positive number
+///
+/// - `i`: bits like [`super::Magnitude::Normalized`]
+///
+/// The approximation from [`kernel::ln`] is refined by a step of Newton's
+/// method, which needs [`reduced_exp_m1`] of the approximation.
+#[inline]
+pub fn ln(i: i64) -> Wide {
+    use core::f64::consts::FRAC_1_SQRT_2;
+
+    #[allow(clippy::cast_possible_wrap)]
+    let exponent = (i - FRAC_1_SQRT_2.to_bits() as i64) >> super::EXP_SHIFT;
+
+    #[allow(clippy::cast_sign_loss)]
+    let m = f64::from_bits((i - (exponent << super::EXP_SHIFT)) as u64);
+    let y = Wide::from(kernel::ln(i, 0.0).1);
+
+    // ln(m) = y + ln(m * exp(-y)), where m * exp(-y) - 1 is tiny.
+    let t = Wide::from(m - 1.0) + Wide::from(m) * reduced_exp_m1(-y);
+    let n = Wide::from(exponent as f64);
+
+    n * Wide::LN_2[0] + n * Wide::LN_2[1] + (y + t)
+}
+
+/// Restriction of `ln(1 + x)` to `1/√2 - 1..=√2 - 1`
+///
+/// The approximation from [`super::ln_1p_double`] is refined by two steps of
+/// Newton's method.  The first step makes up for the truncation of `x` to
+/// `f64`.
+#[inline]
+fn reduced_ln_1p(x: Wide) -> Wide {
+    let mut y = Wide::from(super::ln_1p_double(x.approx()));
+
+    // ln(1 + x) = y + ln((1 + x) * exp(-y)), where the latter is tiny.
+    for _ in 0..2 {
+        let t = reduced_exp_m1(-y);
+        y = y + (x + t + x * t);
+    }
+    y
+}
+
+/// Compute `ln(1 + x)` for `x > -1`
+///
+/// Beyond the domain of [`reduced_ln_1p`], `1 + x` is split into a power of 2
+/// and a significand in `1/√2..√2`, which minus 1 goes to [`reduced_ln_1p`].
+#[inline]
+pub fn ln_1p(x: Wide) -> Wide {
+    use core::f64::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+    if (FRAC_1_SQRT_2 - 1.0..=SQRT_2 - 1.0).contains(&x.approx()) {
+        return reduced_ln_1p(x);
+    }
+
+    let y = Wide::from(1.0) + x;
+    let n = y.exponent + i64::from(y.scale(-y.exponent).approx() > SQRT_2);
+    let k = Wide::from(n as f64);
+
+    k * Wide::LN_2[0] + k * Wide::LN_2[1] + reduced_ln_1p(y.scale(-n) - Wide::from(1.0))
+}
+
+/// Approximate `exp(x)` for `x` within `-746.0..=710.0`
+///
+/// The result has about 120 correct bits and is meant to be rounded by
+/// [`Wide::round`] or [`Wide::round_f32`].
+#[inline]
+pub fn exp(x: Wide) -> Wide {
+    let n = (x.approx() * core::f64::consts::LOG2_E).round_ties_even();
+    let k = Wide::from(n);
+    let y = x - k * Wide::LN_2[0] - k * Wide::LN_2[1];
+
+    (Wide::from(1.0) + reduced_exp_m1(y)).scale(n as i64)
+}
+
+/// Approximate `2^x` for `x` within `-1076.0..=1024.0`
+///
+/// This function works like [`exp`].  The fraction `x - n` is exact, where
+/// `n` is the nearest integer to `x`.
+#[inline]
+pub fn exp2(x: f64) -> Wide {
+    let n = x.round_ties_even();
+    let y = Wide::from(x - n) * (Wide::LN_2[0] + Wide::LN_2[1]);
+
+    (Wide::from(1.0) + reduced_exp_m1(y)).scale(n as i64)
+}
+
+/// Approximate `10^x` for `x` within `-324.0..=309.0`
+///
+/// This function works like [`exp`].  The reduced argument is exact up to
+/// the lower parts of [`Wide::LN_10`] and [`Wide::LN_2`].
+#[inline]
+pub fn exp10(x: f64) -> Wide {
+    let n = (x * core::f64::consts::LOG2_10).round_ties_even();
+    let (x, k) = (Wide::from(x), Wide::from(n));
+    let hi = x * Wide::LN_10[0] - k * Wide::LN_2[0];
+    let y = hi + (x * Wide::LN_10[1] - k * Wide::LN_2[1]);
+
+    (Wide::from(1.0) + reduced_exp_m1(y)).scale(n as i64)
+}
+
+/// Approximate `exp(x) - 1` for `x` within `-38.0..=710.0`
+///
+/// The result is `2^n * (1 + y) - 1 = 2^n * y + (2^n - 1)`, where `y` is from
+/// the reduced argument like [`exp`].  Both terms are exact for moderate `n`,
+/// and their sum does not cancel.
+#[inline]
+pub fn exp_m1(x: f64) -> Wide {
+    let n = (x * core::f64::consts::LOG2_E).round_ties_even();
+    let k = Wide::from(n);
+    let y = reduced_exp_m1(Wide::from(x) - k * Wide::LN_2[0] - k * Wide::LN_2[1]);
+
+    let n = n as i64;
+    y.scale(n) + (Wide::from(1.0).scale(n) - Wide::from(1.0))
+}
+
+/// Sine and cosine restricted to `-π/4..=π/4`
+///
+/// Both come from the Taylor series in Horner's scheme.
+#[inline]
+fn reduced_sin_cos(x: Wide) -> (Wide, Wide) {
+    let one = Wide::from(1.0);
+    let y = x * x;
+
+    let s = (1..=16)
+        .rev()
+        .fold(one, |s, n| one - (y * s).div_small(2 * n * (2 * n + 1)));
+    let c = (1..=16)
+        .rev()
+        .fold(one, |c, n| one - (y * c).div_small(2 * n * (2 * n - 1)));
+    (s * x, c)
+}
+
+/// Argument reduction for trigonometric functions
+///
+/// This function works like [`kernel::rem_pio2`] but with [`Wide`].
+#[inline]
+pub fn rem_pio2(x: f64) -> (i64, Wide) {
+    if x <= core::f64::consts::FRAC_PI_4 {
+        return (0, x.into());
+    }
+
+    let (q, r) = kernel::rem_pio2_fraction(x);
+    (q, r * Wide::FRAC_PI_2)
+}
+
+/// Compute `sin(x)` and `cos(x)` for a finite `x`
+#[inline]
+pub fn sin_cos(x: f64) -> (Wide, Wide) {
+    let (q, y) = rem_pio2(x.abs());
+    let (s, c) = reduced_sin_cos(y);
+
+    let (s, c) = match q & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    };
+
+    (if x.is_sign_negative() { -s } else { s }, c)
+}
+
+/// Square root of a nonnegative number
+///
+/// The square root of `f64` is refined by two steps of Heron's method.
+#[inline]
+pub fn sqrt(x: Wide) -> Wide {
+    if x.significand == 0 {
+        return x;
+    }
+
+    // x = m * 2^(2n), where m is in 1..4
+    let n = x.exponent.div_euclid(2);
+    let m = x.scale(-2 * n);
+    let mut y = Wide::from(m.approx().sqrt());
+
+    for _ in 0..2 {
+        y = (y + m / y).scale(-1);
+    }
+    y.scale(n)
+}
+
+/// Restriction of `atan2(y, x)` to `0 <= y <= x`
+///
+/// The approximation `t` from [`kernel::atan`] is refined by a step of
+/// Newton's method.  With `q = y / x`, the correction to `t` is
+/// `atan((q - tan(t)) / (1 + q * tan(t)))`, where the arctangent of such a
+/// tiny number is itself.
+#[inline]
+fn reduced_atan2(y: Wide, x: Wide) -> Wide {
+    let q = y / x;
+    let t = Wide::from(kernel::atan(q.into()));
+    let (s, c) = reduced_sin_cos(t);
+
+    t + (q * c - s) / (c + q * s)
+}
+
+/// Angle of the point `(x, y)` for nonnegative `x` and `y`
+///
+/// This function works like [`kernel::atan2`] but with [`Wide`].  The result
+/// is in `0..=π/2`.
+#[inline]
+pub fn atan2(y: Wide, x: Wide) -> Wide {
+    if (y.exponent, y.significand) <= (x.exponent, x.significand) {
+        reduced_atan2(y, x)
+    } else {
+        Wide::FRAC_PI_2 - reduced_atan2(x, y)
+    }
+}
+
+/// Approximate `sinh(x)` for `x` within `0.0..=710.5`
+///
+/// The result is `(E + E / (E + 1)) / 2`, where `E = exp(x) - 1` from
+/// [`exp_m1`], so there is no cancellation for small `x`.
+#[inline]
+pub fn sinh(x: f64) -> Wide {
+    let y = exp_m1(x);
+    (y + y / (y + Wide::from(1.0))).scale(-1)
+}
+
+/// Approximate `cosh(x)` for `x` within `0.0..=710.5`
+///
+/// The result is `(y + 1 / y) / 2`, where `y = exp(x)` from [`exp`].
+#[inline]
+pub fn cosh(x: f64) -> Wide {
+    let y = exp(x.into());
+    (y + Wide::from(1.0) / y).scale(-1)
+}
+
+/// Approximate `tanh(x)` for `x` within `0.0..=19.1`
+///
+/// The result is `E / (E + 2)`, where `E = exp(2x) - 1` from [`exp_m1`].
+#[inline]
+pub fn tanh(x: f64) -> Wide {
+    let y = exp_m1(2.0 * x);
+    y / (y + Wide::from(2.0))
+}
+
+/// Approximate `asinh(x)` for finite nonnegative `x`
+///
+/// The result is `ln_1p(x + x^2 / (√(x^2 + 1) + 1))` from [`ln_1p`].
+#[inline]
+pub fn asinh(x: f64) -> Wide {
+    let one = Wide::from(1.0);
+    let x = Wide::from(x);
+    let y = x * x;
+    ln_1p(x + y / (sqrt(y + one) + one))
+}
+
+/// Approximate `acosh(x)` for finite `x >= 1`
+///
+/// The result is `ln_1p(d + √(d * (x + 1)))` from [`ln_1p`], where
+/// `d = x - 1` is exact.
+#[inline]
+pub fn acosh(x: f64) -> Wide {
+    let one = Wide::from(1.0);
+    let x = Wide::from(x);
+    let d = x - one;
+    ln_1p(d + sqrt(d * (x + one)))
+}
+
+/// Approximate `atanh(x)` for `x` within `0.0..1.0`
+///
+/// The result is `ln_1p(2x / (1 - x)) / 2` from [`ln_1p`].
+#[inline]
+pub fn atanh(x: f64) -> Wide {
+    let x = Wide::from(x);
+    ln_1p(x.scale(1) / (Wide::from(1.0) - x)).scale(-1)
+}
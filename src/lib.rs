@@ -8,6 +8,33 @@ pub mod f32;
 /// Real functions for `f64`s
 pub mod f64;
 
+/// Real functions for half-precision [`half::f16`]s
+#[cfg(feature = "half")]
+pub mod f16;
+
+/// Real functions for brain floating-point [`half::bf16`]s
+#[cfg(feature = "half")]
+pub mod bf16;
+
+/// Real functions for quadruple-precision [`f128::F128`]s
+pub mod f128;
+
+/// Complex functions for `f32`s
+///
+/// These functions are evaluated in `f64` with [`crate::f64`] and rounded
+/// once to `f32` at the end.  The extra precision absorbs the cancellation
+/// that [`crate::f32`] functions would suffer in the same formulas.
+pub mod c32;
+
+/// Complex functions for `f64`s
+pub mod c64;
+
+#[cfg(feature = "half")]
+mod binary16;
+
+mod complex;
+pub use complex::Complex;
+
 /// Fast multiply-add
 ///
 /// This function picks the faster way to compute `x * y + a` depending on the
@@ -33,7 +60,7 @@ const fn exp2i(n: i64) -> f64 {
         s @ -63..=0 => 1 << (f64::EXP_SHIFT - 1) >> -s,
         _ => 0,
     };
-    unsafe { core::mem::transmute(bits) }
+    f64::from_bits(bits as u64)
 }
 
 #[allow(clippy::float_cmp)]
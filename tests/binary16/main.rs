@@ -0,0 +1,149 @@
+/// Exhaustively test for every `u16` value
+///
+/// - `error`: function returning `Some` if there is an error
+fn exhaustively_test_u16(error: impl Fn(u16) -> Option<()>) {
+    const LIMIT: usize = 250;
+    let count = (0..=u16::MAX).filter_map(error).take(LIMIT).count();
+
+    assert!(
+        count < LIMIT,
+        "Too many (>= {LIMIT}) mismatches!  Aborting...",
+    );
+    assert!(count == 0, "There are {count} mismatches");
+}
+
+/// Define tests for a 16-bit type from [`half`]
+///
+/// - `$t`: the type and the module of functions in `metallic`
+/// - `$infinity`: bits of positive infinity, the largest non-NaN magnitude
+macro_rules! tests {
+    ($t:ident, $infinity:literal) => {
+        mod $t {
+            use half::$t;
+            use metallic::$t as metal;
+
+            /// Semantic identity like `Object.is` in JavaScript
+            ///
+            /// This function works around comparison issues with NaNs and
+            /// signed zeros.
+            fn is(x: $t, y: $t) -> bool {
+                x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan())
+            }
+
+            /// Value of `x` in `f64`, where infinity is replaced by the next
+            /// power of 2 after the largest finite value
+            fn unbounded(x: $t) -> f64 {
+                if x.is_infinite() {
+                    let (_, exponent) = metallic::f64::frexp($t::MAX.to_f64());
+                    return metallic::f64::ldexp(1.0, exponent).copysign(x.to_f64());
+                }
+                x.to_f64()
+            }
+
+            /// Round `y` from the correctly rounded `f32` path once
+            ///
+            /// If `y` is a tie between two 16-bit values, the true result may
+            /// be on either side.  The tie is broken by `exact`, an accurate
+            /// `f64` result.
+            fn round_once(y: f32, exact: impl FnOnce() -> f64) -> $t {
+                let lower = $t::from_f32(metallic::f32::next_down(y));
+                let upper = $t::from_f32(metallic::f32::next_up(y));
+                let tie = (unbounded(lower) + unbounded(upper)) / 2.0;
+
+                if y.is_nan() || lower == upper || f64::from(y) != tie {
+                    return $t::from_f32(y);
+                }
+
+                match exact().partial_cmp(&tie) {
+                    Some(core::cmp::Ordering::Less) => lower,
+                    Some(core::cmp::Ordering::Greater) => upper,
+                    _ => $t::from_f32(y),
+                }
+            }
+
+            /// Check if `f` is `g` from the `f32` path rounded once for every
+            /// 16-bit value
+            ///
+            /// The accurate `f64` path `h` only breaks ties.
+            fn test_correct_rounding(
+                f: impl Fn($t) -> $t,
+                g: impl Fn(f32) -> f32,
+                h: impl Fn(f64) -> f64,
+            ) {
+                super::exhaustively_test_u16(|i| {
+                    let x = $t::from_bits(i);
+                    let f = f(x);
+                    let g = round_once(g(x.to_f32()), || h(x.to_f64()));
+
+                    (!is(f, g)).then(|| println!("{x:e}: {f:e} != {g:e}"))
+                });
+            }
+
+            #[test]
+            fn test_exp() {
+                test_correct_rounding(metal::exp, metallic::f32::exp, metallic::f64::exp);
+            }
+
+            #[test]
+            fn test_ln() {
+                test_correct_rounding(metal::ln, metallic::f32::ln, metallic::f64::ln);
+            }
+
+            #[test]
+            fn test_log2() {
+                test_correct_rounding(metal::log2, metallic::f32::log2, metallic::f64::log2);
+            }
+
+            #[test]
+            fn test_tanh() {
+                test_correct_rounding(metal::tanh, metallic::f32::tanh, metallic::f64::tanh);
+            }
+
+            #[test]
+            fn test_sin() {
+                test_correct_rounding(metal::sin, metallic::f32::sin, metallic::f64::sin);
+            }
+
+            #[test]
+            fn test_cos() {
+                test_correct_rounding(metal::cos, metallic::f32::cos, metallic::f64::cos);
+            }
+
+            #[test]
+            fn test_erf() {
+                test_correct_rounding(metal::erf, metallic::f32::erf, libm::erf);
+            }
+
+            #[test]
+            fn test_sqrt() {
+                test_correct_rounding(metal::sqrt, f32::sqrt, f64::sqrt);
+            }
+
+            #[test]
+            fn test_rsqrt() {
+                test_correct_rounding(metal::rsqrt, metallic::f32::rsqrt, |x| 1.0 / x.sqrt());
+            }
+
+            #[test]
+            fn test_hypot() {
+                // `hypot` is even in `x`, so it suffices to test nonnegative `x`.
+                (0..=$infinity).for_each(|i| {
+                    let x = $t::from_bits(i);
+
+                    super::exhaustively_test_u16(|j| {
+                        let y = $t::from_bits(j);
+                        let f = metal::hypot(x, y);
+                        let g = round_once(metallic::f32::hypot(x.to_f32(), y.to_f32()), || {
+                            metallic::f64::hypot(x.to_f64(), y.to_f64())
+                        });
+
+                        (!is(f, g)).then(|| println!("{x:e}, {y:e}: {f:e} != {g:e}"))
+                    });
+                });
+            }
+        }
+    };
+}
+
+tests!(f16, 0x7C00);
+tests!(bf16, 0x7F80);
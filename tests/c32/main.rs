@@ -0,0 +1,622 @@
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use metallic::c32 as metal;
+use metallic::Complex;
+use rand::{Rng as _, SeedableRng as _};
+
+const INF: f32 = f32::INFINITY;
+const NAN: f32 = f32::NAN;
+
+/// Semantic identity like `Object.is` in JavaScript
+///
+/// This function works around comparison issues with NaNs and signed zeros.
+/// To be specific, `is(f32::NAN, f32::NAN)` but not `is(0.0, -0.0)`.
+trait Identity {
+    fn is(&self, other: &Self) -> bool;
+}
+
+impl Identity for f32 {
+    fn is(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+    }
+}
+
+impl Identity for Complex<f32> {
+    fn is(&self, other: &Self) -> bool {
+        self.re.is(&other.re) && self.im.is(&other.im)
+    }
+}
+
+/// Check if `result` is within the nearby `f32` representations of `expected`
+fn is_faithful_rounding(result: f32, expected: f64) -> bool {
+    #[allow(clippy::cast_possible_truncation)]
+    let rounded = expected as f32;
+
+    if result.is(&rounded) {
+        return true;
+    }
+
+    let (lo, hi) = if f64::from(rounded) < expected {
+        (rounded, rounded.next_up())
+    } else {
+        (rounded.next_down(), rounded)
+    };
+
+    result == lo || result == hi
+}
+
+/// Random complex numbers with parts in `range`
+///
+/// The random number generator is seeded so that failures are reproducible.
+fn samples(range: core::ops::Range<f32>) -> impl Iterator<Item = Complex<f32>> {
+    const SAMPLES: usize = 1 << 20;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0x6D65_7461_6C6C_6963);
+    (0..SAMPLES)
+        .map(move |_| Complex::new(rng.gen_range(range.clone()), rng.gen_range(range.clone())))
+}
+
+/// Test `samples` where an error is found by `error`
+///
+/// - `error`: function returning `Some` if there is an error
+fn test_samples<T>(samples: impl IntoIterator<Item = T>, error: impl Fn(T) -> Option<()>) {
+    const LIMIT: usize = 250;
+    let count = samples.into_iter().filter_map(error).take(LIMIT).count();
+
+    assert!(
+        count < LIMIT,
+        "Too many (>= {LIMIT}) mismatches!  Aborting...",
+    );
+    assert!(count == 0, "There are {count} mismatches");
+}
+
+/// Check faithful rounding of both parts against a reference in `f64`
+fn test_faithful(
+    f: impl Fn(Complex<f32>) -> Complex<f32>,
+    g: impl Fn(f64, f64) -> (f64, f64),
+    range: core::ops::Range<f32>,
+) {
+    test_samples(samples(range), |z| {
+        let f = f(z);
+        let g = g(z.re.into(), z.im.into());
+        let correct = is_faithful_rounding(f.re, g.0) && is_faithful_rounding(f.im, g.1);
+
+        (!correct).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
+
+/// Real and imaginary parts
+type Parts = (f32, f32);
+
+/// Check special cases in a table of `(z, f(z))`
+fn test_special(f: impl Fn(Complex<f32>) -> Complex<f32>, table: &[(Parts, Parts)]) {
+    for &((x, y), (re, im)) in table {
+        let z = Complex::new(x, y);
+        let expected = Complex::new(re, im);
+
+        // Annex G: f(conj z) = conj f(z) for all functions tested here
+        for (z, expected) in [(z, expected), (conj(z), conj(expected))] {
+            let result = f(z);
+            assert!(result.is(&expected), "{z:?}: {result:?} != {expected:?}");
+        }
+    }
+}
+
+fn conj(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(z.re, -z.im)
+}
+
+#[test]
+fn test_abs() {
+    test_samples(samples(-1e3..1e3), |z| {
+        let f = metal::abs(z);
+        let g = metallic::f32::hypot(z.re, z.im);
+        (!f.is(&g)).then(|| println!("{z:?}: {f:e} != {g:e}"))
+    });
+
+    assert!(metal::abs(Complex::new(NAN, -INF)).is(&INF));
+}
+
+#[test]
+fn test_arg() {
+    test_samples(samples(-1e3..1e3), |z| {
+        let f = metal::arg(z);
+        let g = metallic::f32::atan2(z.im, z.re);
+        (!f.is(&g)).then(|| println!("{z:?}: {f:e} != {g:e}"))
+    });
+
+    assert!(metal::arg(Complex::new(-1.0, -0.0)).is(&-PI));
+}
+
+#[test]
+fn test_exp() {
+    test_faithful(
+        metal::exp,
+        |x, y| (x.exp() * y.cos(), x.exp() * y.sin()),
+        -100.0..100.0,
+    );
+
+    test_special(
+        metal::exp,
+        &[
+            ((0.0, 0.0), (1.0, 0.0)),
+            ((-0.0, 0.0), (1.0, 0.0)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 0.0), (INF, 0.0)),
+            ((-INF, 1.0), (0.0, 0.0)),
+            ((-INF, 3.0), (-0.0, 0.0)),
+            ((INF, 1.0), (INF, INF)),
+            ((INF, 3.0), (-INF, INF)),
+            ((-INF, INF), (0.0, 0.0)),
+            ((INF, INF), (INF, NAN)),
+            ((-INF, NAN), (0.0, 0.0)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((100.0, 1.0), (INF, INF)),
+            ((-200.0, 1.0), (0.0, 0.0)),
+        ],
+    );
+}
+
+#[test]
+fn test_ln() {
+    // Cancellation near the unit circle is avoided with exact squares.
+    let ln_abs = |x: f64, y: f64| {
+        let (x, y) = (x.abs().max(y.abs()), x.abs().min(y.abs()));
+        match x.mul_add(x, y * y) {
+            s if s < 0.5 => 0.5 * s.ln(),
+            _ => 0.5 * (x.mul_add(x, -1.0) + y * y).ln_1p(),
+        }
+    };
+
+    test_faithful(metal::ln, |x, y| (ln_abs(x, y), y.atan2(x)), -2.0..2.0);
+    test_faithful(metal::ln, |x, y| (ln_abs(x, y), y.atan2(x)), -1e30..1e30);
+
+    test_special(
+        metal::ln,
+        &[
+            ((-0.0, 0.0), (-INF, PI)),
+            ((0.0, 0.0), (-INF, 0.0)),
+            ((1.0, INF), (INF, FRAC_PI_2)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (INF, PI)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((-INF, INF), (INF, 3.0 * FRAC_PI_4)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((-INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (INF, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((1.0, 0.0), (0.0, 0.0)),
+            ((-1.0, 0.0), (0.0, PI)),
+        ],
+    );
+}
+
+#[test]
+fn test_sqrt() {
+    let sqrt = |x: f64, y: f64| {
+        let t = (0.5 * (x.abs() + x.hypot(y))).sqrt();
+        let u = y.abs() / (2.0 * t);
+
+        if x.is_sign_positive() {
+            (t, u.copysign(y))
+        } else {
+            (u, t.copysign(y))
+        }
+    };
+
+    test_faithful(metal::sqrt, sqrt, -1e3..1e3);
+    test_faithful(metal::sqrt, sqrt, -1e38..1e38);
+
+    test_special(
+        metal::sqrt,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, 0.0), (0.0, 0.0)),
+            ((1.0, INF), (INF, INF)),
+            ((-INF, INF), (INF, INF)),
+            ((NAN, INF), (INF, INF)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (0.0, INF)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((-4.0, 0.0), (0.0, 2.0)),
+            ((0.0, 2.0), (1.0, 1.0)),
+            ((f32::MAX, f32::MAX), (2.026_714_4e19, 8.394_926e18)),
+        ],
+    );
+
+    let z = metal::sqrt(Complex::new(-INF, NAN));
+    assert!(z.re.is_nan() && z.im.abs().is(&INF));
+}
+
+#[test]
+fn test_pow() {
+    let pow = |z: Complex<f32>, w: Complex<f32>| {
+        let (x, y) = (f64::from(z.re), f64::from(z.im));
+        let (a, b) = (f64::from(w.re), f64::from(w.im));
+        let (u, v) = (x.hypot(y).ln(), y.atan2(x));
+        let (re, im) = (a * u - b * v, a * v + b * u);
+        (re.exp() * im.cos(), re.exp() * im.sin())
+    };
+
+    let z = samples(0.25..4.0);
+    let w = samples(-4.0..4.0).skip(1);
+
+    test_samples(z.zip(w), |(z, w)| {
+        let f = metal::pow(z, w);
+        let g = pow(z, w);
+        let correct = is_faithful_rounding(f.re, g.0) && is_faithful_rounding(f.im, g.1);
+
+        (!correct).then(|| println!("{z:?}, {w:?}: {f:?} != {g:?}"))
+    });
+
+    let one = Complex::new(1.0, 0.0);
+    let i = Complex::new(0.0, 1.0);
+    let nan = Complex::new(NAN, NAN);
+
+    assert!(metal::pow(nan, Complex::new(0.0, -0.0)).is(&one));
+    assert!(metal::pow(Complex::new(0.0, 0.0), Complex::new(2.0, 0.0)).is(&Complex::new(0.0, 0.0)));
+    assert!(metal::pow(Complex::new(0.0, 0.0), Complex::new(-2.0, 0.0))
+        .re
+        .is(&INF));
+    assert!(metal::pow(Complex::new(4.0, 0.0), Complex::new(0.5, 0.0)).is(&Complex::new(2.0, 0.0)));
+    assert_eq!(metal::pow(i, Complex::new(2.0, 0.0)).re, -1.0);
+    assert!(metal::pow(nan, i).re.is_nan());
+}
+
+/// Multiply by the imaginary unit
+fn mul_i(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(-z.im, z.re)
+}
+
+/// Divide by the imaginary unit
+fn div_i(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(z.im, -z.re)
+}
+
+/// Reference functions by textbook formulas in `f64`
+///
+/// These formulas are accurate only away from the branch cuts, so they are
+/// tested in the first quadrant away from the axes.
+mod reference {
+    pub type Parts = (f64, f64);
+
+    pub fn add((a, b): Parts, (c, d): Parts) -> Parts {
+        (a + c, b + d)
+    }
+
+    pub fn mul((a, b): Parts, (c, d): Parts) -> Parts {
+        (a * c - b * d, a * d + b * c)
+    }
+
+    pub fn sqrt((x, y): Parts) -> Parts {
+        let t = (0.5 * (x.abs() + x.hypot(y))).sqrt();
+        let u = y.abs() / (2.0 * t);
+
+        if x.is_sign_positive() {
+            (t, u.copysign(y))
+        } else {
+            (u, t.copysign(y))
+        }
+    }
+
+    pub fn ln((x, y): Parts) -> Parts {
+        (x.hypot(y).ln(), y.atan2(x))
+    }
+
+    /// ln(z + sqrt(z^2 + 1)), odd
+    pub fn asinh((x, y): Parts) -> Parts {
+        if x < 0.0 {
+            let (re, im) = asinh((-x, -y));
+            return (-re, -im);
+        }
+
+        let z = (x, y);
+        ln(add(z, sqrt(add(mul(z, z), (1.0, 0.0)))))
+    }
+
+    /// ln(z + sqrt(z + 1) sqrt(z - 1))
+    pub fn acosh(z: Parts) -> Parts {
+        let s = mul(sqrt(add(z, (1.0, 0.0))), sqrt(add(z, (-1.0, 0.0))));
+        ln(add(z, s))
+    }
+
+    /// (ln(1 + z) - ln(1 - z)) / 2
+    pub fn atanh((x, y): Parts) -> Parts {
+        let (a, b) = ln((1.0 + x, y));
+        let (c, d) = ln((1.0 - x, -y));
+        (0.5 * (a - c), 0.5 * (b - d))
+    }
+}
+
+#[test]
+fn test_sinh() {
+    test_faithful(
+        metal::sinh,
+        |x, y| (x.sinh() * y.cos(), x.cosh() * y.sin()),
+        -10.0..10.0,
+    );
+
+    test_special(
+        metal::sinh,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((0.0, INF), (0.0, NAN)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 0.0), (INF, 0.0)),
+            ((INF, 1.0), (INF, INF)),
+            ((INF, 3.0), (-INF, INF)),
+            ((INF, INF), (INF, NAN)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ],
+    );
+}
+
+#[test]
+fn test_cosh() {
+    test_faithful(
+        metal::cosh,
+        |x, y| (x.cosh() * y.cos(), x.sinh() * y.sin()),
+        -10.0..10.0,
+    );
+
+    test_special(
+        metal::cosh,
+        &[
+            ((0.0, 0.0), (1.0, 0.0)),
+            ((-0.0, 0.0), (1.0, -0.0)),
+            ((0.0, INF), (NAN, 0.0)),
+            ((0.0, NAN), (NAN, 0.0)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 0.0), (INF, 0.0)),
+            ((INF, 1.0), (INF, INF)),
+            ((INF, 3.0), (-INF, INF)),
+            ((INF, INF), (INF, NAN)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ],
+    );
+}
+
+#[test]
+fn test_tanh() {
+    let tanh = |x: f64, y: f64| {
+        let d = (2.0 * x).cosh() + (2.0 * y).cos();
+        ((2.0 * x).sinh() / d, (2.0 * y).sin() / d)
+    };
+
+    test_faithful(metal::tanh, tanh, -4.0..4.0);
+    test_faithful(metal::tanh, tanh, -30.0..30.0);
+
+    test_special(
+        metal::tanh,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((0.0, INF), (0.0, NAN)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (1.0, 0.0)),
+            ((INF, 2.0), (1.0, -0.0)),
+            ((-INF, 1.0), (-1.0, 0.0)),
+            ((INF, INF), (1.0, 0.0)),
+            ((INF, NAN), (1.0, 0.0)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ],
+    );
+}
+
+#[test]
+fn test_sin() {
+    test_faithful(
+        metal::sin,
+        |x, y| (x.sin() * y.cosh(), x.cos() * y.sinh()),
+        -10.0..10.0,
+    );
+
+    test_samples(samples(-1e30..1e30), |z| {
+        let f = metal::sin(z);
+        let g = div_i(metal::sinh(mul_i(z)));
+        (!f.is(&g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
+
+#[test]
+fn test_cos() {
+    test_faithful(
+        metal::cos,
+        |x, y| (x.cos() * y.cosh(), -x.sin() * y.sinh()),
+        -10.0..10.0,
+    );
+
+    assert!(metal::cos(Complex::new(0.0, 0.0)).is(&Complex::new(1.0, -0.0)));
+}
+
+#[test]
+fn test_tan() {
+    let tan = |x: f64, y: f64| {
+        let d = (2.0 * x).cos() + (2.0 * y).cosh();
+        ((2.0 * x).sin() / d, (2.0 * y).sinh() / d)
+    };
+
+    test_faithful(metal::tan, tan, -4.0..4.0);
+
+    test_samples(samples(-1e30..1e30), |z| {
+        let f = metal::tan(z);
+        let g = div_i(metal::tanh(mul_i(z)));
+        (!f.is(&g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
+
+#[test]
+fn test_asinh() {
+    test_faithful(metal::asinh, |x, y| reference::asinh((x, y)), 0.5..4.0);
+    test_faithful(metal::asinh, |x, y| reference::asinh((x, y)), 0.5..1e30);
+
+    test_special(
+        metal::asinh,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, 0.0), (-0.0, 0.0)),
+            ((1.0, INF), (INF, FRAC_PI_2)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (INF, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            // Both sides of the branch cut
+            ((0.0, 2.0), (1.316_958, FRAC_PI_2)),
+            ((-0.0, 2.0), (-1.316_958, FRAC_PI_2)),
+        ],
+    );
+}
+
+#[test]
+fn test_asin() {
+    test_faithful(
+        metal::asin,
+        |x, y| {
+            let (re, im) = reference::asinh((-y, x));
+            (im, -re)
+        },
+        0.5..4.0,
+    );
+
+    test_samples(samples(-1e30..1e30), |z| {
+        let f = metal::asin(z);
+        let g = div_i(metal::asinh(mul_i(z)));
+        (!f.is(&g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
+
+#[test]
+fn test_acos() {
+    // -i ln(z + i sqrt(1 - z^2))
+    let acos = |x, y| {
+        let z = (x, y);
+        let (a, b) = reference::sqrt(reference::add((1.0, 0.0), reference::mul(z, (-x, -y))));
+        let (re, im) = reference::ln(reference::add(z, (-b, a)));
+        (im, -re)
+    };
+
+    test_faithful(metal::acos, acos, 0.5..4.0);
+
+    test_special(
+        metal::acos,
+        &[
+            ((0.0, 0.0), (FRAC_PI_2, -0.0)),
+            ((-0.0, 0.0), (FRAC_PI_2, -0.0)),
+            ((0.0, NAN), (FRAC_PI_2, NAN)),
+            ((1.0, INF), (FRAC_PI_2, -INF)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (PI, -INF)),
+            ((INF, 1.0), (0.0, -INF)),
+            ((-INF, INF), (3.0 * FRAC_PI_4, -INF)),
+            ((INF, INF), (FRAC_PI_4, -INF)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (NAN, -INF)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((1.0, 0.0), (0.0, -0.0)),
+            // Both sides of the branch cut
+            ((2.0, 0.0), (0.0, -1.316_958)),
+            ((-2.0, 0.0), (PI, -1.316_958)),
+        ],
+    );
+
+    let z = metal::acos(Complex::new(INF, NAN));
+    assert!(z.re.is_nan() && z.im.abs().is(&INF));
+}
+
+#[test]
+fn test_acosh() {
+    test_faithful(metal::acosh, |x, y| reference::acosh((x, y)), 0.5..4.0);
+    test_faithful(metal::acosh, |x, y| reference::acosh((x, y)), 0.5..1e30);
+
+    test_special(
+        metal::acosh,
+        &[
+            ((0.0, 0.0), (0.0, FRAC_PI_2)),
+            ((-0.0, 0.0), (0.0, FRAC_PI_2)),
+            ((1.0, INF), (INF, FRAC_PI_2)),
+            ((0.0, NAN), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (INF, PI)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((-INF, INF), (INF, 3.0 * FRAC_PI_4)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((-INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (INF, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((1.0, 0.0), (0.0, 0.0)),
+            // Both sides of the branch cut
+            ((-1.0, 0.0), (0.0, PI)),
+            ((-2.0, 0.0), (1.316_958, PI)),
+        ],
+    );
+}
+
+#[test]
+fn test_atanh() {
+    test_faithful(metal::atanh, |x, y| reference::atanh((x, y)), 0.5..4.0);
+
+    test_special(
+        metal::atanh,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((1.0, 0.0), (INF, 0.0)),
+            ((-1.0, 0.0), (-INF, 0.0)),
+            ((1.0, INF), (0.0, FRAC_PI_2)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (0.0, FRAC_PI_2)),
+            ((INF, INF), (0.0, FRAC_PI_2)),
+            ((INF, NAN), (0.0, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (0.0, FRAC_PI_2)),
+            ((NAN, NAN), (NAN, NAN)),
+            // Both sides of the branch cut
+            ((2.0, 0.0), (0.549_306_15, FRAC_PI_2)),
+            ((-2.0, 0.0), (-0.549_306_15, FRAC_PI_2)),
+        ],
+    );
+}
+
+#[test]
+fn test_atan() {
+    test_faithful(
+        metal::atan,
+        |x, y| {
+            let (re, im) = reference::atanh((-y, x));
+            (im, -re)
+        },
+        0.5..4.0,
+    );
+
+    test_samples(samples(-1e30..1e30), |z| {
+        let f = metal::atan(z);
+        let g = div_i(metal::atanh(mul_i(z)));
+        (!f.is(&g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
@@ -0,0 +1,671 @@
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use metallic::c64 as metal;
+use metallic::Complex;
+use rand::{Rng as _, SeedableRng as _};
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+
+/// Semantic identity like `Object.is` in JavaScript
+///
+/// This function works around comparison issues with NaNs and signed zeros.
+/// To be specific, `is(f64::NAN, f64::NAN)` but not `is(0.0, -0.0)`.
+trait Identity {
+    fn is(&self, other: &Self) -> bool;
+}
+
+impl Identity for f64 {
+    fn is(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+    }
+}
+
+impl Identity for Complex<f64> {
+    fn is(&self, other: &Self) -> bool {
+        self.re.is(&other.re) && self.im.is(&other.im)
+    }
+}
+
+/// Check if `result` is close to `expected` in the complex plane
+///
+/// There is no wider type for reference results, so textbook formulas in
+/// `f64` are only accurate relative to the magnitude of the whole complex
+/// number.  Hence the error of each part is also measured against `|expected|`.
+fn is_close(result: Complex<f64>, expected: (f64, f64)) -> bool {
+    const TOLERANCE: f64 = 1.0 / (1u64 << 46) as f64;
+
+    let (re, im) = expected;
+    let tolerance = TOLERANCE * re.hypot(im);
+
+    (result.re - re).abs() <= tolerance && (result.im - im).abs() <= tolerance
+}
+
+/// Random complex numbers with parts in `range`
+///
+/// The random number generator is seeded so that failures are reproducible.
+fn samples(range: core::ops::Range<f64>) -> impl Iterator<Item = Complex<f64>> {
+    const SAMPLES: usize = 1 << 20;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0x6D65_7461_6C6C_6963);
+    (0..SAMPLES)
+        .map(move |_| Complex::new(rng.gen_range(range.clone()), rng.gen_range(range.clone())))
+}
+
+/// Test `samples` where an error is found by `error`
+///
+/// - `error`: function returning `Some` if there is an error
+fn test_samples<T>(samples: impl IntoIterator<Item = T>, error: impl Fn(T) -> Option<()>) {
+    const LIMIT: usize = 250;
+    let count = samples.into_iter().filter_map(error).take(LIMIT).count();
+
+    assert!(
+        count < LIMIT,
+        "Too many (>= {LIMIT}) mismatches!  Aborting...",
+    );
+    assert!(count == 0, "There are {count} mismatches");
+}
+
+/// Check both parts against a reference by [`is_close`]
+fn test_close(
+    f: impl Fn(Complex<f64>) -> Complex<f64>,
+    g: impl Fn(f64, f64) -> (f64, f64),
+    range: core::ops::Range<f64>,
+) {
+    test_samples(samples(range), |z| {
+        let f = f(z);
+        let g = g(z.re, z.im);
+        (!is_close(f, g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
+
+/// Real and imaginary parts
+type Parts = (f64, f64);
+
+/// Check special cases in a table of `(z, f(z))`
+fn test_special(f: impl Fn(Complex<f64>) -> Complex<f64>, table: &[(Parts, Parts)]) {
+    for &((x, y), (re, im)) in table {
+        let z = Complex::new(x, y);
+        let expected = Complex::new(re, im);
+
+        // Annex G: f(conj z) = conj f(z) for all functions tested here
+        for (z, expected) in [(z, expected), (metal::conj(z), metal::conj(expected))] {
+            let result = f(z);
+            assert!(result.is(&expected), "{z:?}: {result:?} != {expected:?}");
+        }
+    }
+}
+
+#[test]
+fn test_conj() {
+    assert!(metal::conj(Complex::new(1.0, 2.0)).is(&Complex::new(1.0, -2.0)));
+    assert!(metal::conj(Complex::new(-0.0, 0.0)).is(&Complex::new(-0.0, -0.0)));
+    assert!(metal::conj(Complex::new(NAN, -INF)).is(&Complex::new(NAN, INF)));
+}
+
+#[test]
+fn test_proj() {
+    for &(x, y) in &[(1.0, 2.0), (-0.0, 0.0), (NAN, 1.0), (f64::MAX, -f64::MAX)] {
+        let z = Complex::new(x, y);
+        assert!(metal::proj(z).is(&z), "{z:?}");
+    }
+
+    for &(x, y) in &[
+        (INF, 1.0),
+        (-INF, -1.0),
+        (1.0, INF),
+        (NAN, -INF),
+        (-INF, NAN),
+    ] {
+        let z = Complex::new(x, y);
+        let expected = Complex::new(INF, 0.0_f64.copysign(y));
+        assert!(metal::proj(z).is(&expected), "{z:?}");
+    }
+}
+
+#[test]
+fn test_abs() {
+    test_samples(samples(-1e300..1e300), |z| {
+        let f = metal::abs(z);
+        let g = metallic::f64::hypot(z.re, z.im);
+        (!f.is(&g)).then(|| println!("{z:?}: {f:e} != {g:e}"))
+    });
+
+    assert!(metal::abs(Complex::new(NAN, -INF)).is(&INF));
+}
+
+#[test]
+fn test_arg() {
+    test_samples(samples(-1e300..1e300), |z| {
+        let f = metal::arg(z);
+        let g = metallic::f64::atan2(z.im, z.re);
+        (!f.is(&g)).then(|| println!("{z:?}: {f:e} != {g:e}"))
+    });
+
+    assert!(metal::arg(Complex::new(-1.0, -0.0)).is(&-PI));
+}
+
+#[test]
+fn test_exp() {
+    test_close(
+        metal::exp,
+        |x, y| (x.exp() * y.cos(), x.exp() * y.sin()),
+        -700.0..700.0,
+    );
+
+    // exp(x) overflows, but exp(x) cos(y) does not.
+    let z = metal::exp(Complex::new(709.9, 0.8));
+    let r = (354.95_f64).exp();
+    assert!(is_close(z, (r * 0.8_f64.cos() * r, r * 0.8_f64.sin() * r)));
+
+    test_special(
+        metal::exp,
+        &[
+            ((0.0, 0.0), (1.0, 0.0)),
+            ((-0.0, 0.0), (1.0, 0.0)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 0.0), (INF, 0.0)),
+            ((-INF, 1.0), (0.0, 0.0)),
+            ((-INF, 3.0), (-0.0, 0.0)),
+            ((INF, 1.0), (INF, INF)),
+            ((INF, 3.0), (-INF, INF)),
+            ((-INF, INF), (0.0, 0.0)),
+            ((INF, INF), (INF, NAN)),
+            ((-INF, NAN), (0.0, 0.0)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((800.0, 1.0), (INF, INF)),
+            ((-800.0, 1.0), (0.0, 0.0)),
+        ],
+    );
+}
+
+#[test]
+fn test_ln() {
+    let ln = |x: f64, y: f64| (x.hypot(y).ln(), y.atan2(x));
+
+    test_close(metal::ln, ln, -1e300..1e300);
+    test_close(metal::ln, ln, -1e-300..1e-300);
+
+    // Cancellation near the unit circle
+    let z = Complex::new(0.6, 0.8);
+    let w = metal::ln(z);
+    assert!(
+        (w.re / 2.220_446_049_250_313_2e-17 - 1.0).abs() < 1e-15,
+        "{w:?}"
+    );
+
+    test_special(
+        metal::ln,
+        &[
+            ((-0.0, 0.0), (-INF, PI)),
+            ((0.0, 0.0), (-INF, 0.0)),
+            ((1.0, INF), (INF, FRAC_PI_2)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (INF, PI)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((-INF, INF), (INF, 3.0 * FRAC_PI_4)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((-INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (INF, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((1.0, 0.0), (0.0, 0.0)),
+            ((-1.0, 0.0), (0.0, PI)),
+            ((f64::MAX, f64::MAX), (710.129_286_483_663_9, FRAC_PI_4)),
+        ],
+    );
+}
+
+#[test]
+fn test_sqrt() {
+    test_close(metal::sqrt, reference::sqrt, -1e3..1e3);
+    test_close(metal::sqrt, reference::sqrt, -1e300..1e300);
+    test_close(metal::sqrt, reference::sqrt, -1e-300..1e-300);
+
+    test_special(
+        metal::sqrt,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, 0.0), (0.0, 0.0)),
+            ((1.0, INF), (INF, INF)),
+            ((-INF, INF), (INF, INF)),
+            ((NAN, INF), (INF, INF)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (0.0, INF)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((-4.0, 0.0), (0.0, 2.0)),
+            ((0.0, 2.0), (1.0, 1.0)),
+            ((f64::MAX, 0.0), (1.340_780_792_994_259_6e154, 0.0)),
+            (
+                (f64::from_bits(1), 0.0),
+                (2.222_758_749_485_077_5e-162, 0.0),
+            ),
+        ],
+    );
+
+    let z = metal::sqrt(Complex::new(-INF, NAN));
+    assert!(z.re.is_nan() && z.im.abs().is(&INF));
+
+    // Huge parts overflow the textbook formula.
+    let z = metal::sqrt(Complex::new(f64::MAX, f64::MAX));
+    assert!(z.re.is_finite() && z.im.is_finite(), "{z:?}");
+}
+
+#[test]
+fn test_pow() {
+    let pow = |z: Complex<f64>, w: Complex<f64>| {
+        let (u, v) = reference::ln((z.re, z.im));
+        let (a, b) = (w.re, w.im);
+        let (re, im) = (a * u - b * v, a * v + b * u);
+        (re.exp() * im.cos(), re.exp() * im.sin())
+    };
+
+    let z = samples(0.25..4.0);
+    let w = samples(-4.0..4.0).skip(1);
+
+    test_samples(z.zip(w), |(z, w)| {
+        let f = metal::pow(z, w);
+        let g = pow(z, w);
+        (!is_close(f, g)).then(|| println!("{z:?}, {w:?}: {f:?} != {g:?}"))
+    });
+
+    let one = Complex::new(1.0, 0.0);
+    let i = Complex::new(0.0, 1.0);
+    let nan = Complex::new(NAN, NAN);
+
+    assert!(metal::pow(nan, Complex::new(0.0, -0.0)).is(&one));
+    assert!(metal::pow(Complex::new(0.0, 0.0), Complex::new(2.0, 0.0)).is(&Complex::new(0.0, 0.0)));
+    assert!(metal::pow(Complex::new(0.0, 0.0), Complex::new(-2.0, 0.0))
+        .re
+        .is(&INF));
+    assert!(metal::pow(Complex::new(4.0, 0.0), Complex::new(0.5, 0.0)).is(&Complex::new(2.0, 0.0)));
+    assert_eq!(metal::pow(i, Complex::new(2.0, 0.0)).re, -1.0);
+    assert!(metal::pow(nan, i).re.is_nan());
+}
+
+/// Multiply by the imaginary unit
+fn mul_i(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(-z.im, z.re)
+}
+
+/// Divide by the imaginary unit
+fn div_i(z: Complex<f64>) -> Complex<f64> {
+    Complex::new(z.im, -z.re)
+}
+
+/// Reference functions by textbook formulas
+///
+/// These formulas are accurate only away from the branch cuts, so they are
+/// tested in the first quadrant away from the axes.
+mod reference {
+    pub type Parts = (f64, f64);
+
+    pub fn add((a, b): Parts, (c, d): Parts) -> Parts {
+        (a + c, b + d)
+    }
+
+    pub fn mul((a, b): Parts, (c, d): Parts) -> Parts {
+        (a * c - b * d, a * d + b * c)
+    }
+
+    pub fn sqrt(x: f64, y: f64) -> Parts {
+        let t = (0.5 * (x.abs() + x.hypot(y))).sqrt();
+        let u = y.abs() / (2.0 * t);
+
+        if x.is_sign_positive() {
+            (t, u.copysign(y))
+        } else {
+            (u, t.copysign(y))
+        }
+    }
+
+    /// (tanh x + i tan y) / (1 + i tanh x tan y), which has no cancellation
+    /// near the poles
+    pub fn tanh(x: f64, y: f64) -> Parts {
+        let (a, b) = (x.tanh(), y.tan());
+        let d = a.mul_add(a * b * b, 1.0);
+        let c = x.cosh();
+        (a * b.mul_add(b, 1.0) / d, b / (c * c) / d)
+    }
+
+    pub fn ln((x, y): Parts) -> Parts {
+        (x.hypot(y).ln(), y.atan2(x))
+    }
+
+    /// ln(z + sqrt(z^2 + 1)), odd
+    pub fn asinh((x, y): Parts) -> Parts {
+        if x < 0.0 {
+            let (re, im) = asinh((-x, -y));
+            return (-re, -im);
+        }
+
+        let z = (x, y);
+        let (a, b) = add(mul(z, z), (1.0, 0.0));
+        ln(add(z, sqrt(a, b)))
+    }
+
+    /// ln(z + sqrt(z + 1) sqrt(z - 1))
+    pub fn acosh(z: Parts) -> Parts {
+        let (a, b) = add(z, (1.0, 0.0));
+        let (c, d) = add(z, (-1.0, 0.0));
+        ln(add(z, mul(sqrt(a, b), sqrt(c, d))))
+    }
+
+    /// (ln(1 + z) - ln(1 - z)) / 2
+    pub fn atanh((x, y): Parts) -> Parts {
+        let (a, b) = ln((1.0 + x, y));
+        let (c, d) = ln((1.0 - x, -y));
+        (0.5 * (a - c), 0.5 * (b - d))
+    }
+}
+
+#[test]
+fn test_sinh() {
+    test_close(
+        metal::sinh,
+        |x, y| (x.sinh() * y.cos(), x.cosh() * y.sin()),
+        -700.0..700.0,
+    );
+
+    test_special(
+        metal::sinh,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((0.0, INF), (0.0, NAN)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 0.0), (INF, 0.0)),
+            ((INF, 1.0), (INF, INF)),
+            ((INF, 3.0), (-INF, INF)),
+            ((INF, INF), (INF, NAN)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ],
+    );
+
+    // sinh(x) overflows, but sinh(x) cos(y) does not.
+    let z = metal::sinh(Complex::new(-710.0, 3.0));
+    let r = (355.0_f64).exp();
+    assert!(is_close(
+        z,
+        (
+            -r * (0.5 * 3.0_f64.cos()) * r,
+            r * (0.5 * 3.0_f64.sin()) * r
+        )
+    ));
+}
+
+#[test]
+fn test_cosh() {
+    test_close(
+        metal::cosh,
+        |x, y| (x.cosh() * y.cos(), x.sinh() * y.sin()),
+        -700.0..700.0,
+    );
+
+    test_special(
+        metal::cosh,
+        &[
+            ((0.0, 0.0), (1.0, 0.0)),
+            ((-0.0, 0.0), (1.0, -0.0)),
+            ((0.0, INF), (NAN, 0.0)),
+            ((0.0, NAN), (NAN, 0.0)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 0.0), (INF, 0.0)),
+            ((INF, 1.0), (INF, INF)),
+            ((INF, 3.0), (-INF, INF)),
+            ((INF, INF), (INF, NAN)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ],
+    );
+}
+
+#[test]
+fn test_tanh() {
+    test_close(metal::tanh, reference::tanh, -4.0..4.0);
+    test_close(metal::tanh, reference::tanh, -300.0..300.0);
+
+    test_special(
+        metal::tanh,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((0.0, INF), (0.0, NAN)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((1.0, INF), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (1.0, 0.0)),
+            ((INF, 2.0), (1.0, -0.0)),
+            ((-INF, 1.0), (-1.0, 0.0)),
+            ((INF, INF), (1.0, 0.0)),
+            ((INF, NAN), (1.0, 0.0)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+        ],
+    );
+}
+
+#[test]
+fn test_sin() {
+    test_close(
+        metal::sin,
+        |x, y| (x.sin() * y.cosh(), x.cos() * y.sinh()),
+        -10.0..10.0,
+    );
+
+    test_samples(samples(-1e300..1e300), |z| {
+        let f = metal::sin(z);
+        let g = div_i(metal::sinh(mul_i(z)));
+        (!f.is(&g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
+
+#[test]
+fn test_cos() {
+    test_close(
+        metal::cos,
+        |x, y| (x.cos() * y.cosh(), -x.sin() * y.sinh()),
+        -10.0..10.0,
+    );
+
+    assert!(metal::cos(Complex::new(0.0, 0.0)).is(&Complex::new(1.0, -0.0)));
+}
+
+#[test]
+fn test_tan() {
+    test_close(
+        metal::tan,
+        |x, y| {
+            let (re, im) = reference::tanh(-y, x);
+            (im, -re)
+        },
+        -4.0..4.0,
+    );
+
+    test_samples(samples(-1e300..1e300), |z| {
+        let f = metal::tan(z);
+        let g = div_i(metal::tanh(mul_i(z)));
+        (!f.is(&g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
+
+#[test]
+fn test_asinh() {
+    test_close(metal::asinh, |x, y| reference::asinh((x, y)), 0.5..4.0);
+    test_close(metal::asinh, |x, y| reference::asinh((x, y)), 0.5..1e150);
+
+    // The textbook formula overflows, so compare with ln 2z instead.
+    test_close(
+        metal::asinh,
+        |x, y| reference::add(reference::ln((x, y)), (core::f64::consts::LN_2, 0.0)),
+        1e200..1e300,
+    );
+
+    test_special(
+        metal::asinh,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((-0.0, 0.0), (-0.0, 0.0)),
+            ((1.0, INF), (INF, FRAC_PI_2)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((NAN, 0.0), (NAN, 0.0)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (INF, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            // Both sides of the branch cut
+            ((0.0, 2.0), (1.316_957_896_924_816_6, FRAC_PI_2)),
+            ((-0.0, 2.0), (-1.316_957_896_924_816_6, FRAC_PI_2)),
+        ],
+    );
+}
+
+#[test]
+fn test_asin() {
+    test_close(
+        metal::asin,
+        |x, y| {
+            let (re, im) = reference::asinh((-y, x));
+            (im, -re)
+        },
+        0.5..4.0,
+    );
+
+    test_samples(samples(-1e300..1e300), |z| {
+        let f = metal::asin(z);
+        let g = div_i(metal::asinh(mul_i(z)));
+        (!f.is(&g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
+
+#[test]
+fn test_acos() {
+    // -i ln(z + i sqrt(1 - z^2))
+    let acos = |x, y| {
+        let z = (x, y);
+        let (a, b) = reference::add((1.0, 0.0), reference::mul(z, (-x, -y)));
+        let (a, b) = reference::sqrt(a, b);
+        let (re, im) = reference::ln(reference::add(z, (-b, a)));
+        (im, -re)
+    };
+
+    test_close(metal::acos, acos, 0.5..4.0);
+
+    test_special(
+        metal::acos,
+        &[
+            ((0.0, 0.0), (FRAC_PI_2, -0.0)),
+            ((-0.0, 0.0), (FRAC_PI_2, -0.0)),
+            ((0.0, NAN), (FRAC_PI_2, NAN)),
+            ((1.0, INF), (FRAC_PI_2, -INF)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (PI, -INF)),
+            ((INF, 1.0), (0.0, -INF)),
+            ((-INF, INF), (3.0 * FRAC_PI_4, -INF)),
+            ((INF, INF), (FRAC_PI_4, -INF)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (NAN, -INF)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((1.0, 0.0), (0.0, -0.0)),
+            // Both sides of the branch cut
+            ((2.0, 0.0), (0.0, -1.316_957_896_924_816_6)),
+            ((-2.0, 0.0), (PI, -1.316_957_896_924_816_6)),
+            // Huge numbers
+            ((f64::MAX, 0.0), (0.0, -710.475_860_073_943_9)),
+        ],
+    );
+
+    let z = metal::acos(Complex::new(INF, NAN));
+    assert!(z.re.is_nan() && z.im.abs().is(&INF));
+}
+
+#[test]
+fn test_acosh() {
+    test_close(metal::acosh, |x, y| reference::acosh((x, y)), 0.5..4.0);
+    test_close(metal::acosh, |x, y| reference::acosh((x, y)), 0.5..1e150);
+
+    test_special(
+        metal::acosh,
+        &[
+            ((0.0, 0.0), (0.0, FRAC_PI_2)),
+            ((-0.0, 0.0), (0.0, FRAC_PI_2)),
+            ((1.0, INF), (INF, FRAC_PI_2)),
+            ((0.0, NAN), (NAN, NAN)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((-INF, 1.0), (INF, PI)),
+            ((INF, 1.0), (INF, 0.0)),
+            ((-INF, INF), (INF, 3.0 * FRAC_PI_4)),
+            ((INF, INF), (INF, FRAC_PI_4)),
+            ((INF, NAN), (INF, NAN)),
+            ((-INF, NAN), (INF, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (INF, NAN)),
+            ((NAN, NAN), (NAN, NAN)),
+            ((1.0, 0.0), (0.0, 0.0)),
+            // Both sides of the branch cut
+            ((-1.0, 0.0), (0.0, PI)),
+            ((-2.0, 0.0), (1.316_957_896_924_816_6, PI)),
+        ],
+    );
+}
+
+#[test]
+fn test_atanh() {
+    test_close(metal::atanh, |x, y| reference::atanh((x, y)), 0.5..4.0);
+    test_close(metal::atanh, |x, y| reference::atanh((x, y)), 0.5..1e300);
+
+    test_special(
+        metal::atanh,
+        &[
+            ((0.0, 0.0), (0.0, 0.0)),
+            ((0.0, NAN), (0.0, NAN)),
+            ((1.0, 0.0), (INF, 0.0)),
+            ((-1.0, 0.0), (-INF, 0.0)),
+            ((1.0, INF), (0.0, FRAC_PI_2)),
+            ((1.0, NAN), (NAN, NAN)),
+            ((INF, 1.0), (0.0, FRAC_PI_2)),
+            ((INF, INF), (0.0, FRAC_PI_2)),
+            ((INF, NAN), (0.0, NAN)),
+            ((NAN, 1.0), (NAN, NAN)),
+            ((NAN, INF), (0.0, FRAC_PI_2)),
+            ((NAN, NAN), (NAN, NAN)),
+            // Both sides of the branch cut
+            ((2.0, 0.0), (0.549_306_144_334_054_9, FRAC_PI_2)),
+            ((-2.0, 0.0), (-0.549_306_144_334_054_9, FRAC_PI_2)),
+        ],
+    );
+}
+
+#[test]
+fn test_atan() {
+    test_close(
+        metal::atan,
+        |x, y| {
+            let (re, im) = reference::atanh((-y, x));
+            (im, -re)
+        },
+        0.5..4.0,
+    );
+
+    test_samples(samples(-1e300..1e300), |z| {
+        let f = metal::atan(z);
+        let g = div_i(metal::atanh(mul_i(z)));
+        (!f.is(&g)).then(|| println!("{z:?}: {f:?} != {g:?}"))
+    });
+}
@@ -0,0 +1,879 @@
+use core::num::FpCategory;
+use metallic::f128::{self as metal, F128};
+use rand::{Rng as _, SeedableRng as _};
+
+/// Semantic identity like `Object.is` in JavaScript
+///
+/// This function works around comparison issues with NaNs and signed zeros.
+/// To be specific, `is(F128::NAN, F128::NAN)` but not `is(0.0, -0.0)`.
+trait Identity {
+    fn is(&self, other: &Self) -> bool;
+}
+
+impl Identity for f64 {
+    fn is(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+    }
+}
+
+impl Identity for F128 {
+    fn is(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+    }
+}
+
+/// Special values with both signs tested for every function
+fn special() -> impl Iterator<Item = f64> + Clone {
+    [
+        0.0,
+        f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        f64::EPSILON,
+        0.5,
+        1.0,
+        2.0,
+        10.0,
+        f64::MAX,
+        // Closest approach to a multiple of π/2 in `f64`
+        6_381_956_970_095_103.0 * 2.0_f64.powi(797),
+        f64::INFINITY,
+        f64::NAN,
+    ]
+    .into_iter()
+    .flat_map(|x| [x, -x])
+}
+
+/// Sample `f64` values for testing
+///
+/// This function returns special values, random bit patterns, and random
+/// values uniformly distributed in `range`.  The random number generator is
+/// seeded so that failures are reproducible.  There are fewer samples than
+/// for `f64` because binary128 functions are much slower.
+fn samples(range: core::ops::Range<f64>) -> Vec<f64> {
+    const SAMPLES: usize = 1 << 16;
+
+    let rng = &mut rand::rngs::StdRng::seed_from_u64(0x6D65_7461_6C6C_6963);
+    let mut samples: Vec<_> = special().collect();
+    samples.extend((0..SAMPLES).map(|_| f64::from_bits(rng.gen())));
+    samples.extend((0..SAMPLES).map(|_| rng.gen_range(range.clone())));
+    samples
+}
+
+/// Test `samples` where an error is found by `error`
+///
+/// - `error`: function returning `Some` if there is an error
+fn test_samples<T>(samples: impl IntoIterator<Item = T>, error: impl Fn(T) -> Option<()>) {
+    const LIMIT: usize = 250;
+    let count = samples.into_iter().filter_map(error).take(LIMIT).count();
+
+    assert!(
+        count < LIMIT,
+        "Too many (>= {LIMIT}) mismatches!  Aborting...",
+    );
+    assert!(count == 0, "There are {count} mismatches");
+}
+
+/// Check `f` against its `f64` counterpart `g` for sampled `f64` values
+///
+/// Both `f` and `g` are correctly rounded.  Rounding the result of `f` again
+/// to `f64` agrees with `g` unless it is extremely close to a tie of `f64`.
+fn test_against_f64(
+    f: impl Fn(F128) -> F128,
+    g: impl Fn(f64) -> f64,
+    range: core::ops::Range<f64>,
+) {
+    test_samples(samples(range), |x| {
+        let f = f(F128::from(x)).to_f64();
+        let g = g(x);
+
+        (!f.is(&g)).then(|| println!("{x:e}: {f:e} != {g:e}"))
+    });
+}
+
+/// Bivariate version of [`test_against_f64`]
+///
+/// Every pair of special values is tested.  Other samples are drawn from `x`
+/// and `y` by [`samples`] and paired up.
+fn test_bivariate_against_f64(
+    f: impl Fn(F128, F128) -> F128,
+    g: impl Fn(f64, f64) -> f64,
+    x: core::ops::Range<f64>,
+    y: core::ops::Range<f64>,
+) {
+    let x = samples(x);
+    let y = samples(y);
+    let special = special().flat_map(|x| special().map(move |y| (x, y)));
+    let pairs = x.iter().copied().zip(y.iter().copied().rev());
+
+    test_samples(special.chain(pairs), |(x, y)| {
+        let f = f(F128::from(x), F128::from(y)).to_f64();
+        let g = g(x, y);
+
+        (!f.is(&g)).then(|| println!("{x:e}, {y:e}: {f:e} != {g:e}"))
+    });
+}
+
+/// Check `f` against known answers in bits
+fn test_table(f: impl Fn(F128) -> F128, table: &[(u128, u128)]) {
+    test_samples(table, |&(x, y)| {
+        let x = F128::from_bits(x);
+        let f = f(x).to_bits();
+
+        (f != y).then(|| println!("{x:?}: {f:#034x} != {y:#034x}"))
+    });
+}
+
+// Known answers computed by mpmath with 1500 bits of precision and then
+// rounded to binary128.  Each table begins with hard cases such as overflow,
+// underflow, cancellation near 1, and arguments near multiples of π/2,
+// followed by random samples.
+
+const EXP: [(u128, u128); 30] = [
+    (
+        0x400C_62E4_0000_0000_0000_0000_0000_0000,
+        0x7FFE_F427_B746_53BA_ED19_6748_0B30_8DEF,
+    ),
+    (
+        0x400C_62E4_2FEF_A39E_F342_340D_9DBE_BD66,
+        0x7FFE_FFFF_FFFF_FFFA_A811_8DA3_AD67_2809,
+    ),
+    (
+        0x400C_62E4_2FEF_A39E_F2E8_219F_833A_0EFA,
+        0x7FFE_FFFF_FFFF_FFE4_2376_0702_8287_156E,
+    ),
+    (
+        0xC00C_62D8_0000_0000_0000_0000_0000_0000,
+        0x0001_259E_F157_0032_B389_31DF_9C2D_C574,
+    ),
+    (
+        0xC00C_6444_0000_0000_0000_0000_0000_0000,
+        0x0000_0000_0000_0000_0000_5E09_F7DB_A6FE,
+    ),
+    (
+        0xC00C_6545_9999_9999_9999_9999_9999_999A,
+        0x0000_0000_0000_0000_0000_0000_0000_0001,
+    ),
+    (
+        0xC00C_654B_3333_3333_3333_3333_3333_3333,
+        0x0000_0000_0000_0000_0000_0000_0000_0001,
+    ),
+    (
+        0xC00C_654C_0000_0000_0000_0000_0000_0000,
+        0x0000_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0xC00C_6580_0000_0000_0000_0000_0000_0000,
+        0x0000_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3F7A_16C2_6277_7579_C58C_4647_5896_767B,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0xBF7A_16C2_6277_7579_C58C_4647_5896_767B,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3F87_5448_4932_D2E7_25A5_BBCA_17A3_ABA1,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3FFE_0000_0000_0000_0000_0000_0000_0000,
+        0x3FFF_A612_98E1_E069_BC97_2DFE_FAB6_DF34,
+    ),
+    (
+        0xBFFD_62E4_2FEF_A39E_F356_F106_B0A1_52AC,
+        0x3FFE_6A09_E667_F3BC_C908_EC85_C6C0_C708,
+    ),
+    (
+        0x4002_8A00_F116_12B0_3BB7_5106_0892_EC2C,
+        0x4010_B28A_7FE4_E834_678D_5C4E_D7B2_0C92,
+    ),
+    (
+        0xC004_F396_8B7E_AC4C_9A1E_5878_A4A6_9148,
+        0x3FA4_DFA7_217A_C898_2B49_C7A3_C301_556F,
+    ),
+    (
+        0x4001_FF1C_2C8D_A40E_2E15_04B0_DA18_2852,
+        0x400A_6F79_6409_907F_E202_8FC8_8263_57CA,
+    ),
+    (
+        0xBFEE_2146_4111_329A_6126_3FDD_9093_11ED,
+        0x3FFE_FFFE_DEBA_10A6_AA8D_E7B9_0F28_C85B,
+    ),
+    (
+        0xBFFC_DC79_FC28_6E97_A02A_C240_AE41_BC78,
+        0x3FFE_95B9_0724_9F89_6D93_2BE7_9141_0A95,
+    ),
+    (
+        0x3FFB_803D_8778_64A5_4707_4CE5_E79F_497A,
+        0x3FFF_192D_7049_1E38_665B_3A6E_39D3_9480,
+    ),
+    (
+        0xBFF1_212D_D573_4E1A_D4BE_2C52_AB56_46E1,
+        0x3FFE_FFF6_F6A5_BECF_1198_806A_E3AE_7588,
+    ),
+    (
+        0x3FFD_6570_DE80_E563_9173_07AE_08AD_DAD9,
+        0x3FFF_6AF0_F207_C44B_AD88_FF99_5EC5_4CAB,
+    ),
+    (
+        0x400B_4008_25FA_97DD_0771_48A5_2AF4_C782,
+        0x5CDA_428A_3697_A51C_0B92_3862_31CD_1911,
+    ),
+    (
+        0xBFEE_F17E_6829_4C26_6788_AAE2_EAA0_E3F5,
+        0x3FFE_FFFE_0E82_8989_6947_C8E2_0D06_C9CC,
+    ),
+    (
+        0xC003_05F4_B8E0_6CC1_9117_79F0_87E0_F91B,
+        0x3FE7_4D1B_571D_38C1_C067_CD67_59B2_A9B4,
+    ),
+    (
+        0x4006_DC7A_5604_C3B6_67BE_9998_F866_68C1,
+        0x4156_A1A5_255E_B486_88F6_00FB_30BF_C5D7,
+    ),
+    (
+        0x4005_FC72_2219_7C77_984F_BD65_2AAC_BA42,
+        0x40B6_4DDA_705F_0DBB_283D_D4B3_F45C_6B06,
+    ),
+    (
+        0xC00C_01EF_B0E9_C915_8EC1_915F_AD8C_A05A,
+        0x117B_092E_D8E1_FBEB_0A46_A314_DAFB_208F,
+    ),
+    (
+        0x3FEB_FB70_D4BE_B617_A231_9287_775C_5B87,
+        0x3FFF_0000_1FB7_0F42_D784_71FD_BBAA_7C92,
+    ),
+    (
+        0xC007_D7A6_E779_2A6F_C285_DF1A_4CC3_E668,
+        0x3D56_76D1_26D4_425B_DB04_A661_005B_CE1C,
+    ),
+];
+
+const LN: [(u128, u128); 28] = [
+    (
+        0x3FFF_0000_0000_0000_0000_0000_0000_0001,
+        0x3F8E_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF,
+    ),
+    (
+        0x3FFE_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF,
+        0xBF8E_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3FFF_0000_0000_0000_0000_0000_0000_0002,
+        0x3F8F_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFE,
+    ),
+    (
+        0x3FFE_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFE,
+        0xBF8F_0000_0000_0000_0000_0000_0000_0001,
+    ),
+    (
+        0x3FFF_0000_0000_0000_0000_0000_0000_0003,
+        0x3F90_7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFE,
+    ),
+    (
+        0x3FFE_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFD,
+        0xBF8F_8000_0000_0000_0000_0000_0000_0001,
+    ),
+    (
+        0x3FFF_0000_0000_0000_0000_0000_0000_3039,
+        0x3F9C_81C7_FFFF_FFFF_FFFF_FFFF_FFFF_DBAA,
+    ),
+    (
+        0x3FFE_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_CFC7,
+        0xBF9B_81C8_0000_0000_0000_0000_0000_122B,
+    ),
+    (
+        0x3FFF_0000_0000_0000_0000_0100_0000_0000,
+        0x3FB6_FFFF_FFFF_FFFF_FFFF_FF00_0000_0000,
+    ),
+    (
+        0x3FFE_FFFF_FFFF_FFFF_FFFF_FF00_0000_0000,
+        0xBFB6_0000_0000_0000_0000_0040_0000_0000,
+    ),
+    (
+        0x3FFF_0010_0000_0000_0000_0000_0000_0007,
+        0x3FF2_FFF0_00AA_A2AB_110B_BC04_DC4F_1DB3,
+    ),
+    (
+        0x3FFE_FFEF_FFFF_FFFF_FFFF_FFFF_FFFF_FFF9,
+        0xBFF2_0004_0015_55D5_5888_9DDE_702B_7290,
+    ),
+    (
+        0x54EB_E970_5478_ED04_BE8E_8D7E_3820_C8F0,
+        0x400A_D024_9EC4_FCF4_1377_C869_2759_BC71,
+    ),
+    (
+        0x1AA6_899D_9450_0102_CD3C_409B_1209_A614,
+        0xC00B_9E2B_FFFB_EEBF_B16D_5AD4_4BEA_492C,
+    ),
+    (
+        0x2DC8_65E7_20DE_2438_875E_3522_9ED0_13D1,
+        0xC00A_93F9_ED50_0907_EC14_2911_A64B_5C3B,
+    ),
+    (
+        0x5BD6_032D_BEC4_676D_B452_A4CF_154E_5D1D,
+        0x400B_34C1_2820_04F5_36D9_378A_37CB_4299,
+    ),
+    (
+        0x7A6C_B9CF_7B6E_B806_CC54_4333_3056_DDB0,
+        0x400C_43FF_95AC_A488_9CB8_BF8A_3241_03CD,
+    ),
+    (
+        0x4001_CA67_53AF_CB2A_668E_AF1B_E360_B5F0,
+        0x3FFF_F807_AA9B_CB0C_8CB9_510A_6841_2F41,
+    ),
+    (
+        0x3E0C_276D_6DA8_EC24_13C7_7DBD_4F4B_245B,
+        0xC007_59BC_B8F5_4BAA_19EB_5728_6537_1CEF,
+    ),
+    (
+        0x24C3_C5D6_380A_D728_A412_44F0_8012_5771,
+        0xC00B_2E00_CB17_30A4_4845_D90A_ECCE_C633,
+    ),
+    (
+        0x652C_B9D7_FE28_C940_18B0_BFC9_A9DA_20C8,
+        0x400B_9C53_A3D3_0C81_9D6C_E448_33BA_07CF,
+    ),
+    (
+        0x4001_94C0_A145_66E6_9A4F_17B6_6886_663A,
+        0x3FFF_D82A_1CDA_AE30_3ADE_B385_B7C8_9B99,
+    ),
+    (
+        0x35D3_F092_B324_6CD8_488F_19D1_FBC2_D7BE,
+        0xC009_C312_BBAD_DEBC_5436_9EBC_F367_EEA9,
+    ),
+    (
+        0x3FFF_F998_1A2B_9972_17E9_A6A5_AC67_D4B9,
+        0x3FFE_5C71_F1C2_2734_2B80_A925_9710_0B71,
+    ),
+    (
+        0x5C64_67AF_EE81_E0F0_CC3E_5B67_A1B8_1F41,
+        0x400B_3AED_3B09_0614_D9D5_F767_4655_CCF6,
+    ),
+    (
+        0x4455_5A6D_E7B1_28FD_0F90_E49C_F819_B750,
+        0x4008_80D9_1253_99B6_152F_EBBF_66DC_A548,
+    ),
+    (
+        0x357B_0115_ABF5_8A0D_0492_DF3C_AE9C_E2F8,
+        0xC009_D27C_ABBD_0BBF_8C31_53CD_75EF_0418,
+    ),
+    (
+        0x4000_363F_63BC_6601_9476_78F5_8C09_786B,
+        0x3FFE_C54B_58C1_7CBE_4E46_6FDD_E020_A72B,
+    ),
+];
+
+const SIN: [(u128, u128); 38] = [
+    (
+        0x4000_921F_B544_42D1_8469_898C_C517_01B8,
+        0x3F8D_CD12_9024_E088_A67C_C740_20BB_EA64,
+    ),
+    (
+        0xC000_921F_B544_42D1_8469_898C_C517_01B8,
+        0xBF8D_CD12_9024_E088_A67C_C740_20BB_EA64,
+    ),
+    (
+        0x3FFF_921F_B544_42D1_8469_898C_C517_01B8,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0xBFFF_921F_B544_42D1_8469_898C_C517_01B8,
+        0xBFFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x4001_921F_B544_42D1_8469_898C_C517_01B8,
+        0xBF8E_CD12_9024_E088_A67C_C740_20BB_EA64,
+    ),
+    (
+        0xC001_921F_B544_42D1_8469_898C_C517_01B8,
+        0x3F8E_CD12_9024_E088_A67C_C740_20BB_EA64,
+    ),
+    (
+        0x4001_2D97_C7F3_321D_234F_2729_93D1_414A,
+        0xBFFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0xC001_2D97_C7F3_321D_234F_2729_93D1_414A,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x4007_3A28_C59D_5433_AF72_7375_F9F9_F958,
+        0x3F93_2F92_FEC6_612A_7BDD_08AB_CCDA_61C4,
+    ),
+    (
+        0xC007_3A28_C59D_5433_AF72_7375_F9F9_F958,
+        0xBF93_2F92_FEC6_612A_7BDD_08AB_CCDA_61C4,
+    ),
+    (
+        0x4032_921F_B544_42D1_8469_898C_C517_01B8,
+        0xBFBF_CD12_9024_E088_A67C_C740_20BB_EA64,
+    ),
+    (
+        0xC032_921F_B544_42D1_8469_898C_C517_01B8,
+        0x3FBF_CD12_9024_E088_A67C_C740_20BB_EA64,
+    ),
+    (
+        0x0000_0000_0000_0000_0000_0000_0000_0003,
+        0x0000_0000_0000_0000_0000_0000_0000_0003,
+    ),
+    (
+        0x8000_0000_0000_0000_0000_0000_0000_0003,
+        0x8000_0000_0000_0000_0000_0000_0000_0003,
+    ),
+    (
+        0x0001_0000_0000_0000_0000_0000_0000_0000,
+        0x0001_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x8001_0000_0000_0000_0000_0000_0000_0000,
+        0x8001_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3F9B_4484_BFEE_BC29_F863_424B_06F3_529A,
+        0x3F9B_4484_BFEE_BC29_F863_424B_06F3_529A,
+    ),
+    (
+        0xBF9B_4484_BFEE_BC29_F863_424B_06F3_529A,
+        0xBF9B_4484_BFEE_BC29_F863_424B_06F3_529A,
+    ),
+    (
+        0x3FFE_8F5C_28F5_C28F_6000_0000_0000_0000,
+        0x3FFE_6814_3D72_D4CE_47BA_F520_4801_80E4,
+    ),
+    (
+        0xBFFE_8F5C_28F5_C28F_6000_0000_0000_0000,
+        0xBFFE_6814_3D72_D4CE_47BA_F520_4801_80E4,
+    ),
+    (
+        0x3FFE_921F_B544_42D1_8000_0000_0000_0000,
+        0x3FFE_6A09_E667_F3BC_C5E9_FEE3_52F5_0FD4,
+    ),
+    (
+        0xBFFE_921F_B544_42D1_8000_0000_0000_0000,
+        0xBFFE_6A09_E667_F3BC_C5E9_FEE3_52F5_0FD4,
+    ),
+    (
+        0x4045_FE44_CBB8_F084_EEBB_E74B_1C8A_A6FA,
+        0x3FFD_4DC3_786D_D192_8D50_378D_DB4D_983A,
+    ),
+    (
+        0xC054_5F85_96B2_853F_97E7_EE29_2A57_86C0,
+        0x3FFE_CABF_1149_234A_C2DE_7C71_C25A_5E50,
+    ),
+    (
+        0x400D_6CB5_21FF_CFF3_B3CB_6807_E822_4E8F,
+        0xBFFE_5D14_4115_9781_9F45_D7A8_66EC_4335,
+    ),
+    (
+        0x68D7_39C9_1CAF_3592_0E8F_B143_4ADE_ED17,
+        0x3FFE_9E8E_01D3_27C0_EE2E_706C_8245_E4C6,
+    ),
+    (
+        0x5016_E7C1_5229_4E6C_C7D7_57D7_0D37_8636,
+        0x3FFB_CCA2_3A81_C90F_6DD3_9B33_695C_6B72,
+    ),
+    (
+        0x41F5_8E4D_ED86_A757_AF23_723E_CA24_EB30,
+        0x3FFE_413A_4654_0ED5_F5B0_BC9B_A0C2_DB0E,
+    ),
+    (
+        0xC054_6821_53A1_B5B2_54D6_F493_090C_7E5C,
+        0xBFFE_9CC7_83A9_56FB_B0AF_BC75_1142_460D,
+    ),
+    (
+        0xC00D_6E63_081D_00C1_7538_4CC9_EEC0_A374,
+        0x3FFB_6EAF_ECD7_4BCE_B400_96FC_FD6A_4D6B,
+    ),
+    (
+        0xECC8_3BA8_2FD7_B50C_5D6E_73B0_FE7B_547A,
+        0xBFFD_E504_6E8D_AD1B_247E_5C58_FB11_67B6,
+    ),
+    (
+        0xD34E_F88B_1E7C_AD8A_4C94_E3F1_0041_335A,
+        0x3FFC_A7B9_C324_2A10_EBA8_D898_FC95_4A55,
+    ),
+    (
+        0xEACB_103E_3954_1DA8_DF74_3A44_67A0_1324,
+        0xBFFE_E21B_9643_C747_BBC3_106B_AA1E_A114,
+    ),
+    (
+        0xCB4A_446D_F3D0_5037_EFAA_4C5C_E786_BA33,
+        0x3FFE_F4A9_B52B_F1E7_5A1E_71E5_9CB0_E874,
+    ),
+    (
+        0xF1E9_9CFB_13D6_35D4_8EC0_C72C_C9BA_0C06,
+        0xBFFE_EDEE_4F4B_3994_52D5_0E24_B735_2F77,
+    ),
+    (
+        0xC037_140B_061F_1623_ECF0_B765_9094_8C4B,
+        0xBFFD_22FF_BB62_BE12_200C_7D4B_5A1A_F038,
+    ),
+    (
+        0x6591_C1C4_49E0_7282_7093_DE17_49D5_59C8,
+        0x3FFE_D8F5_0D20_FCC9_88CC_3D1D_ADF7_08C7,
+    ),
+    (
+        0x4B79_D8EB_30A3_E121_8083_1248_9866_BA58,
+        0x3FFE_FA56_501A_C663_5255_B8A8_E94A_CC86,
+    ),
+];
+
+const COS: [(u128, u128); 27] = [
+    (
+        0x4000_921F_B544_42D1_8469_898C_C517_01B8,
+        0xBFFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3FFF_921F_B544_42D1_8469_898C_C517_01B8,
+        0x3F8C_CD12_9024_E088_A67C_C740_20BB_EA64,
+    ),
+    (
+        0x4001_921F_B544_42D1_8469_898C_C517_01B8,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x4001_2D97_C7F3_321D_234F_2729_93D1_414A,
+        0xBF8E_59CD_EC1B_A866_7CDD_9570_188C_EFCB,
+    ),
+    (
+        0x4007_3A28_C59D_5433_AF72_7375_F9F9_F958,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x4032_921F_B544_42D1_8469_898C_C517_01B8,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x0000_0000_0000_0000_0000_0000_0000_0003,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x0001_0000_0000_0000_0000_0000_0000_0000,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3F9B_4484_BFEE_BC29_F863_424B_06F3_529A,
+        0x3FFF_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3FFE_8F5C_28F5_C28F_6000_0000_0000_0000,
+        0x3FFE_6BFC_DBF8_17BF_A549_0955_5A0F_E2C5,
+    ),
+    (
+        0x3FFE_921F_B544_42D1_8000_0000_0000_0000,
+        0x3FFE_6A09_E667_F3BC_CC27_6712_D3D8_C550,
+    ),
+    (
+        0xC04B_690B_75A3_6FF4_F873_9BA8_3022_C28E,
+        0x3FFE_AD96_D3C1_2277_D00A_FE99_53B5_D798,
+    ),
+    (
+        0x404A_498A_8387_A1E7_F065_DF4A_4207_158A,
+        0x3FFE_F84D_673F_EF45_BC3F_68B2_AF6B_5A29,
+    ),
+    (
+        0xC3C5_D21E_2EC0_6C4E_0EBB_0539_156C_9C9F,
+        0x3FFE_E66D_68B8_D879_2550_5FC3_205F_D5A7,
+    ),
+    (
+        0x6565_5A11_0A45_3E8C_6CF3_EEEA_95A8_303B,
+        0x3FFC_B864_849B_8CF6_4043_5C2B_8816_48C2,
+    ),
+    (
+        0x4017_2410_9ED7_CB37_EC1E_05C0_5418_1067,
+        0xBFFE_18C4_1571_DDC9_1E7C_452A_2DB3_44E1,
+    ),
+    (
+        0xC050_B8A2_0D3B_987A_7CEF_7335_4C59_0A39,
+        0x3FFE_F434_D130_007B_5081_0415_3FD4_75AD,
+    ),
+    (
+        0x4035_0523_1474_8E86_7CAA_CA07_40D7_B213,
+        0x3FFE_54F2_6632_922F_F978_E76B_4D3A_04DC,
+    ),
+    (
+        0xE5A7_385F_3A73_7DD0_5CC6_24CD_E1B8_1EBF,
+        0xBFFE_F171_563A_DFB3_C52F_E70A_F405_835C,
+    ),
+    (
+        0x5F3F_EA6E_9926_86D6_B8D3_04F8_B864_EB90,
+        0x3FFD_3A8B_FDDF_6BFD_DC0C_CC5F_A351_0437,
+    ),
+    (
+        0xC060_1159_7A7A_2C9E_8AD9_5A38_05EB_FCF8,
+        0xBFFE_22E9_444F_8F78_E3CE_AB8F_A0E9_DF86,
+    ),
+    (
+        0x7B33_C56D_CA2B_B148_3A31_BAC0_0479_92A6,
+        0x3FFE_B9A2_D47F_6003_7F15_A6AA_0A40_6D61,
+    ),
+    (
+        0xBFF6_37B2_E4AE_20F2_E775_9D3F_A7C3_BE47,
+        0x3FFE_FFFF_A11E_F9C5_B2B8_FA00_9087_4473,
+    ),
+    (
+        0xC041_4B10_83C0_4742_581E_3E09_04E0_F409,
+        0xBFFE_3505_EA3A_C58B_0435_2D90_9DCA_BDB8,
+    ),
+    (
+        0xBFFE_D0E0_CEC2_D9D4_9243_9337_B524_AF39,
+        0x3FFE_3B0F_495C_F7B1_7341_B491_F6EA_D7A5,
+    ),
+    (
+        0xC04F_A6BB_9940_2CDE_9EE1_3FA4_8441_9A74,
+        0xBFFE_D419_9883_86AD_7C0F_3A9B_0578_84F0,
+    ),
+    (
+        0x4058_1B98_EF63_D61A_A91B_9557_CFF5_A4B2,
+        0xBFFE_1196_BA15_C0FA_1C7E_5B18_D26F_DE4F,
+    ),
+];
+
+const POW: [(u128, u128, u128); 26] = [
+    (
+        0x4000_0000_0000_0000_0000_0000_0000_0000,
+        0x3FFE_0000_0000_0000_0000_0000_0000_0000,
+        0x3FFF_6A09_E667_F3BC_C908_B2FB_1366_EA95,
+    ),
+    (
+        0x4002_4000_0000_0000_0000_0000_0000_0000,
+        0xC000_0000_0000_0000_0000_0000_0000_0000,
+        0x3FF8_47AE_147A_E147_AE14_7AE1_47AE_147B,
+    ),
+    (
+        0x4000_8000_0000_0000_0000_0000_0000_0000,
+        0x4000_8000_0000_0000_0000_0000_0000_0000,
+        0x4003_B000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x20BF_0000_0000_0000_0000_0000_0000_0000,
+        0x4000_0800_0000_0000_0000_0000_0000_0000,
+        0x0000_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x331C_0000_0000_0000_0000_0000_0000_0000,
+        0x4001_4000_0000_0000_0000_0000_0000_0000,
+        0x0000_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x4003_9000_0000_0000_0000_0000_0000_0000,
+        0x4002_7000_0000_0000_0000_0000_0000_0000,
+        0x4034_52D0_2C7E_14AF_6800_0000_0000_0000,
+    ),
+    (
+        0x4037_FFFF_FFFF_FFFF_FF00_0000_0000_0000,
+        0x4000_0000_0000_0000_0000_0000_0000_0000,
+        0x4070_FFFF_FFFF_FFFF_FE00_0000_0000_0000,
+    ),
+    (
+        0x3FFE_0000_0000_0000_0000_0000_0000_0000,
+        0xC008_F400_0000_0000_0000_0000_0000_0000,
+        0x43E7_0000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x4002_2000_0000_0000_0000_0000_0000_0000,
+        0x3FFE_0000_0000_0000_0000_0000_0000_0000,
+        0x4000_8000_0000_0000_0000_0000_0000_0000,
+    ),
+    (
+        0x3FFF_0000_01AD_7F29_B000_0000_0000_0000,
+        0x401C_DCD6_5000_0000_0000_0000_0000_0000,
+        0x408F_3494_45C2_2879_1B6D_7375_7FA3_9D3C,
+    ),
+    (
+        0x3FF9_B1DB_9C77_7637_238B_A83E_66A8_D4DE,
+        0x3FFF_9BA4_2308_BB24_2126_0DAA_31BE_9BF2,
+        0x3FF6_7D9C_1C6F_10CF_B7DF_6171_E90D_F0DA,
+    ),
+    (
+        0x4002_4B3F_5BD6_A94D_6055_6919_6A39_32EB,
+        0x3FFF_3CF1_B301_6992_CC56_4CC8_70E6_B31E,
+        0x4003_20E8_0C1F_412A_1CB1_F15A_467B_2F2D,
+    ),
+    (
+        0x4009_456C_ADF4_9D06_4026_AED1_ACAA_807E,
+        0xC004_FAF8_2BFB_5DB5_ED6F_4CF5_5091_ACD3,
+        0x3D6F_4663_1F61_0A4A_F17E_3B03_CBFD_1900,
+    ),
+    (
+        0x4001_BBB0_EB51_5C58_6C78_ED36_18A4_AA36,
+        0x4006_00FA_2588_AA5D_F274_F0DB_37DE_FA80,
+        0x4165_E4E0_BB64_4976_9E97_2255_7342_3DA7,
+    ),
+    (
+        0x3FFC_DD11_DD14_474E_CC22_44B6_A648_2B62,
+        0x3FF7_33D0_144C_F976_FA02_61C9_CDC5_FC1F,
+        0x3FFE_FC82_1F53_3DBD_9426_FE43_D230_6523,
+    ),
+    (
+        0x3FF6_B11C_7A7E_0BBA_551F_48E9_0529_DC0C,
+        0xBFF5_0C9E_2024_8C87_8CCC_33F6_3F1B_0C2E,
+        0x3FFF_0180_BEBE_512C_DF96_C829_D59E_34C7,
+    ),
+    (
+        0x3FFC_5635_EF55_F99B_249B_C3DF_26A7_BAD4,
+        0x4006_9C58_4BF2_0770_AD4A_5C7F_E62B_126C,
+        0x3DEA_C288_DCE8_D83D_A8ED_7564_6BC6_C9E8,
+    ),
+    (
+        0x4009_B33A_A0AB_DE59_3545_A876_30DB_B90A,
+        0xBFFA_04D6_205F_AA1E_36B3_5B82_F5E3_5230,
+        0x3FFE_93B8_D0DA_74BB_7394_D4E7_9738_CFC7,
+    ),
+    (
+        0x4000_2649_D42E_DD95_A0F9_9AA7_EC6D_A0D9,
+        0xC003_AE43_53E6_2088_9C8F_6B4F_67FE_F4CB,
+        0x3FDE_A02C_F274_C85D_A93F_99F4_A985_CCBC,
+    ),
+    (
+        0x4009_ADFD_1807_4AE5_3DF7_B5A2_B0F9_2F03,
+        0xBFFF_E360_83E6_A37A_0209_DA6C_460C_D339,
+        0x3FEA_A170_B659_2737_BA4A_CB3F_4D74_2A0F,
+    ),
+    (
+        0x3FFF_1014_2573_D716_B9FA_521E_E0C8_0266,
+        0xC003_BF63_0B09_0ED6_3B1E_C2F0_266A_1D83,
+        0x3FFC_74EB_82FE_A126_5EA6_B49E_CEBB_B14D,
+    ),
+    (
+        0x3FFD_1DF9_2EA4_A927_B66B_A637_DABB_9F57,
+        0xBFF6_7F85_4945_93FB_094E_2DBF_1642_B200,
+        0x3FFF_00F5_0FB5_4AD0_93E4_DB32_72BD_A20C,
+    ),
+    (
+        0x3FF5_8490_0125_2E19_9733_12CE_6D2F_06DB,
+        0xBFF6_99F9_CAB5_50A3_AF8A_AC56_2C93_A434,
+        0x3FFF_0545_0726_B5F1_2C9E_90C1_038B_B799,
+    ),
+    (
+        0x4009_B387_4BC9_1581_E2D9_39DB_195D_D4A5,
+        0xC002_192D_557D_BF69_8419_F7F6_336E_6B51,
+        0x3FA0_50DC_467D_FD9F_CFC2_8B85_030F_4372,
+    ),
+    (
+        0x4005_DABE_6E20_9FD6_0B58_BAD5_01F1_2D28,
+        0x3FFD_3C56_DC1A_FEA1_21FE_B7C0_6A5A_A9CC,
+        0x4001_17E8_3B15_502D_7B2E_2753_4E6C_08EF,
+    ),
+    (
+        0x3FFD_6775_656B_8B2E_292D_84E1_9A62_1503,
+        0x4004_DBAA_DD9E_049D_E31E_5E2A_DED8_64DD,
+        0x3FA5_25D4_E466_BB66_7C48_6777_4EC2_A02A,
+    ),
+];
+
+#[test]
+fn test_conversion() {
+    test_samples(samples(-1.0..1.0), |x| {
+        let y = F128::from(x).to_f64();
+        (!y.is(&x)).then(|| println!("{x:e} != {y:e}"))
+    });
+
+    // Neighbors of 1 + 2^-53, a tie between 1 and 1 + 2^-52
+    let tie = F128::from_bits(0x3FFF_0000_0000_0000_0800_0000_0000_0000);
+    assert!(tie.to_f64().is(&1.0));
+    assert!(F128::from_bits(tie.to_bits() + 1)
+        .to_f64()
+        .is(&(1.0 + f64::EPSILON)));
+    assert!(F128::from_bits(tie.to_bits() - 1).to_f64().is(&1.0));
+
+    // Overflow and underflow
+    assert!(F128::MAX.to_f64().is(&f64::INFINITY));
+    assert!(F128::MIN.to_f64().is(&f64::NEG_INFINITY));
+    assert!(F128::MIN_POSITIVE.to_f64().is(&0.0));
+    assert!((-F128::MIN_POSITIVE).to_f64().is(&-0.0));
+    assert!(F128::EPSILON.to_f64().is(&2.0_f64.powi(-112)));
+}
+
+#[test]
+fn test_classify() {
+    assert_eq!(F128::from(0.0).classify(), FpCategory::Zero);
+    assert_eq!(F128::from_bits(1).classify(), FpCategory::Subnormal);
+    assert_eq!(F128::MIN_POSITIVE.classify(), FpCategory::Normal);
+    assert_eq!(F128::MAX.classify(), FpCategory::Normal);
+    assert_eq!(F128::INFINITY.classify(), FpCategory::Infinite);
+    assert_eq!(F128::NAN.classify(), FpCategory::Nan);
+
+    assert!(F128::from(-0.0) == F128::from(0.0));
+    assert!(F128::NAN != F128::NAN);
+    assert!(F128::MIN < F128::from(-1.0));
+    assert!(F128::from(f64::MAX) < F128::MAX);
+    assert!(F128::from_bits(1) > -F128::from_bits(1));
+}
+
+#[test]
+fn test_exp() {
+    test_table(metal::exp, &EXP);
+    test_against_f64(metal::exp, metallic::f64::exp, -746.0..710.0);
+
+    assert!(metal::exp(F128::from(0.0)).is(&F128::from(1.0)));
+    assert!(metal::exp(F128::INFINITY).is(&F128::INFINITY));
+    assert!(metal::exp(F128::NEG_INFINITY).is(&F128::from(0.0)));
+    assert!(metal::exp(F128::from(11357.0)).is(&F128::INFINITY));
+    assert!(metal::exp(F128::from(-11434.0)).is(&F128::from(0.0)));
+    assert!(metal::exp(F128::from(-11433.0)).is(&F128::from_bits(1)));
+}
+
+#[test]
+fn test_ln() {
+    test_table(metal::ln, &LN);
+    test_against_f64(metal::ln, metallic::f64::ln, 0.0..4.0);
+
+    assert!(metal::ln(F128::from(1.0)).is(&F128::from(0.0)));
+    assert!(metal::ln(F128::from(0.0)).is(&F128::NEG_INFINITY));
+    assert!(metal::ln(F128::from(-0.0)).is(&F128::NEG_INFINITY));
+    assert!(metal::ln(F128::from(-1.0)).is_nan());
+    assert!(metal::ln(F128::INFINITY).is(&F128::INFINITY));
+    assert!(metal::ln(F128::NEG_INFINITY).is_nan());
+}
+
+#[test]
+fn test_sin() {
+    test_table(metal::sin, &SIN);
+    test_against_f64(metal::sin, metallic::f64::sin, -10.0..10.0);
+
+    assert!(metal::sin(F128::from(-0.0)).is(&F128::from(-0.0)));
+    assert!(metal::sin(F128::INFINITY).is_nan());
+}
+
+#[test]
+fn test_cos() {
+    test_table(metal::cos, &COS);
+    test_against_f64(metal::cos, metallic::f64::cos, -10.0..10.0);
+
+    assert!(metal::cos(F128::from(-0.0)).is(&F128::from(1.0)));
+    assert!(metal::cos(F128::NEG_INFINITY).is_nan());
+}
+
+#[test]
+fn test_pow() {
+    test_samples(&POW, |&(x, y, z)| {
+        let (x, y) = (F128::from_bits(x), F128::from_bits(y));
+        let f = metal::pow(x, y).to_bits();
+
+        (f != z).then(|| println!("{x:?}, {y:?}: {f:#034x} != {z:#034x}"))
+    });
+
+    test_bivariate_against_f64(metal::pow, metallic::f64::pow, 0.0..4.0, -100.0..100.0);
+}
+
+#[test]
+fn test_pow_exact() {
+    // (2^57 - 1)^2 = 2^114 - 2^58 + 1 is a tie, which rounds to even.
+    let x = F128::from_bits(0x4037_FFFF_FFFF_FFFF_FF00_0000_0000_0000);
+    let y = F128::from_bits(0x4070_FFFF_FFFF_FFFF_FE00_0000_0000_0000);
+    assert!(metal::pow(x, F128::from(2.0)).is(&y));
+    assert!(metal::pow(-x, F128::from(2.0)).is(&y));
+
+    // 2^-16495 is a tie between 0 and the least subnormal number.
+    let x = F128::from_bits(0x0000_4000_0000_0000_0000_0000_0000_0000);
+    let y = F128::from(16495.0 / 16384.0);
+    assert!(metal::pow(x, y).is(&F128::from(0.0)));
+
+    // 5^21 is exact.
+    let z = metal::pow(F128::from(25.0), F128::from(10.5));
+    assert!(z.is(&F128::from(476_837_158_203_125.0)));
+}
@@ -0,0 +1,594 @@
+//! High-precision values of Bessel functions
+//!
+//! The values are computed with mpmath at 320-bit precision and rounded to
+//! `f64`.  Arguments are the nearest `f32`s to the first zeros and their
+//! neighbors within 2 ulps, followed by some large arguments.  Libraries like
+//! `libm` are inaccurate in relative error at these arguments.
+
+/// `(x, J₀(x))`
+pub const J0: &[(f32, f64)] = &[
+    (2.404_825, 3.039_832_526_197_434e-7),
+    (2.404_825_2, 1.802_088_199_700_468e-7),
+    (2.404_825_4, 5.643_439_959_155_312_5e-8),
+    (2.404_825_7, -6.734_000_851_573_297e-8),
+    (2.404_826, -1.911_144_043_518_068_9e-7),
+    (5.520_077, -3.000_251_716_855_460_4e-7),
+    (5.520_077_7, -1.377_742_493_806_867_9e-7),
+    (5.520_078, 2.447_665_890_859_218_3e-8),
+    (5.520_078_7, 1.867_275_531_822_563_4e-7),
+    (5.520_079, 3.489_784_334_402_713e-7),
+    (8.653_726, 6.213_073_104_651_925e-7),
+    (8.653_727, 3.624_301_696_886_786e-7),
+    (8.653_728, 1.035_530_574_411_008_5e-7),
+    (8.653_728_5, -1.553_240_262_773_114_2e-7),
+    (8.653_729, -4.142_010_814_663_291e-7),
+    (11.791_532_5, -4.469_121_493_756_603_7e-7),
+    (11.791_533, -2.252_211_514_268_743e-7),
+    (11.791_534, -3.530_171_407_782_237_7e-9),
+    (11.791_535, 2.181_607_906_814_171e-7),
+    (11.791_536, 4.398_517_348_405_249_3e-7),
+    (14.930_916, 3.874_745_759_169_221_5e-7),
+    (14.930_917, 1.904_965_290_870_987_8e-7),
+    (14.930_918, -6.481_505_161_426_715e-9),
+    (14.930_919, -2.034_595_268_284_768_4e-7),
+    (14.930_92, -4.004_375_359_138_74e-7),
+    (18.071_06, -7.109_753_943_542_713e-7),
+    (18.071_062, -3.529_110_623_919_774_6e-7),
+    (18.071_064, 5.153_231_778_941_749_4e-9),
+    (18.071_066, 3.632_174_881_571_917e-7),
+    (18.071_068, 7.212_817_067_414_778e-7),
+    (100.5, 0.054_436_573_814_413_594),
+    (-100.5, 0.054_436_573_814_413_594),
+    (1000.25, 0.022_846_535_354_858_314),
+    (-1000.25, 0.022_846_535_354_858_314),
+    (12345.678, 2.867_928_702_144_797_5e-5),
+    (-12345.678, 2.867_928_702_144_797_5e-5),
+    (1.0e5, -0.001_719_201_116_235_972_3),
+    (-1.0e5, -0.001_719_201_116_235_972_3),
+    (524288.5, 0.001_094_400_558_866_479_7),
+    (-524288.5, 0.001_094_400_558_866_479_7),
+    (1.0e6, 0.000_331_043_013_739_873_76),
+    (-1.0e6, 0.000_331_043_013_739_873_76),
+    (16777216.0, -2.110_761_888_226_554e-5),
+    (-16777216.0, -2.110_761_888_226_554e-5),
+    (10000001.0, -0.000_246_262_248_092_776_4),
+    (-10000001.0, -0.000_246_262_248_092_776_4),
+    (3.1e9, -1.387_742_178_988_314_6e-5),
+    (-3.1e9, -1.387_742_178_988_314_6e-5),
+    (1.0e10, 2.175_591_750_246_892e-6),
+    (-1.0e10, 2.175_591_750_246_892e-6),
+    (7.7e15, 6.492_434_350_145_504e-9),
+    (-7.7e15, 6.492_434_350_145_504e-9),
+    (9.223_372e18, 1.879_511_653_192_781_5e-10),
+    (-9.223_372e18, 1.879_511_653_192_781_5e-10),
+    (1.0e20, 7.959_789_452_722_445e-11),
+    (-1.0e20, 7.959_789_452_722_445e-11),
+    (5.0e25, -1.125_935_107_001_079_5e-13),
+    (-5.0e25, -1.125_935_107_001_079_5e-13),
+    (1.0e30, -7.914_272_141_241_796e-16),
+    (-1.0e30, -7.914_272_141_241_796e-16),
+    (2.7e34, 3.363_206_900_222_151e-18),
+    (-2.7e34, 3.363_206_900_222_151e-18),
+    (1.0e38, 6.409_059_189_637_007e-20),
+    (-1.0e38, 6.409_059_189_637_007e-20),
+    (3.402_823_5e38, 1.012_798_202_640_822e-20),
+    (-3.402_823_5e38, 1.012_798_202_640_822e-20),
+];
+
+/// `(x, J₁(x))`
+pub const J1: &[(f32, f64)] = &[
+    (3.831_705_6, 1.610_983_556_370_887e-7),
+    (3.831_705_8, 6.507_302_575_740_365e-8),
+    (3.831_706, -3.095_229_814_734_229_5e-8),
+    (3.831_706_3, -1.269_776_160_771_448_2e-7),
+    (3.831_706_5, -2.230_029_280_319_995_9e-7),
+    (7.015_586, -2.312_279_731_110_673e-7),
+    (7.015_586_4, -8.812_161_969_171_799e-8),
+    (7.015_587, 5.498_472_400_096_225e-8),
+    (7.015_587_3, 1.980_910_579_669_429e-7),
+    (7.015_588, 3.411_973_822_061_934e-7),
+    (10.173_467, 3.627_284_818_695_179e-7),
+    (10.173_468, 1.245_913_310_971_919e-7),
+    (10.173_469, -1.135_457_973_519_557_8e-7),
+    (10.173_47, -3.516_829_034_777_148e-7),
+    (10.173_470_5, -5.898_199_872_798_75e-7),
+    (13.323_69, -3.323_180_372_993_887e-7),
+    (13.323_691, -1.240_742_625_278_483e-7),
+    (13.323_692, 8.416_949_733_827_045e-8),
+    (13.323_693, 2.924_132_422_987_813_6e-7),
+    (13.323_694, 5.006_569_723_534_983e-7),
+    (16.470_627, 6.325_837_728_445_562e-7),
+    (16.470_629, 2.578_557_636_220_330_3e-7),
+    (16.470_63, -1.168_722_022_067_902_1e-7),
+    (16.470_633, -4.916_001_246_405_654e-7),
+    (16.470_634, -8.663_280_036_779_443e-7),
+    (19.615_854, -7.647_585_122_125_31e-7),
+    (19.615_856, -4.213_148_217_679_123e-7),
+    (19.615_858, -7.787_116_471_652_56e-8),
+    (19.615_86, 2.655_724_589_403_895e-7),
+    (19.615_862, 6.090_160_492_015_934e-7),
+    (100.5, -0.057_791_123_996_932_02),
+    (-100.5, 0.057_791_123_996_932_02),
+    (1000.25, 0.010_711_720_806_184_072),
+    (-1000.25, -0.010_711_720_806_184_072),
+    (12345.678, -0.007_180_902_990_506_633),
+    (-12345.678, 0.007_180_902_990_506_633),
+    (1.0e5, 0.001_846_757_562_882_567_7),
+    (-1.0e5, -0.001_846_757_562_882_567_7),
+    (524288.5, -0.000_128_615_095_237_587_9),
+    (-524288.5, 0.000_128_615_095_237_587_9),
+    (1.0e6, -0.000_725_968_356_813_763),
+    (-1.0e6, 0.000_725_968_356_813_763),
+    (16777216.0, -0.000_193_649_074_507_762_67),
+    (-16777216.0, 0.000_193_649_074_507_762_67),
+    (10000001.0, 5.492_608_380_569_952_4e-5),
+    (-10000001.0, -5.492_608_380_569_952_4e-5),
+    (3.1e9, 3.574_686_187_967_027_3e-6),
+    (-3.1e9, -3.574_686_187_967_027_3e-6),
+    (1.0e10, -7.676_508_175_684_158e-6),
+    (-1.0e10, 7.676_508_175_684_158e-6),
+    (7.7e15, 6.366_018_119_782_663e-9),
+    (-7.7e15, -6.366_018_119_782_663e-9),
+    (9.223_372e18, 1.835_669_182_612_740_8e-10),
+    (-9.223_372e18, -1.835_669_182_612_740_8e-10),
+    (1.0e20, -5.511_150_780_415_994e-12),
+    (-1.0e20, 5.511_150_780_415_994e-12),
+    (5.0e25, -7.422_699_809_242_395e-15),
+    (-5.0e25, 7.422_699_809_242_395e-15),
+    (1.0e30, -1.013_051_209_549_982_2e-16),
+    (-1.0e30, 1.013_051_209_549_982_2e-16),
+    (2.7e34, -3.502_477_661_094_891e-18),
+    (-2.7e34, 3.502_477_661_094_891e-18),
+    (1.0e38, 4.752_466_683_297_436e-20),
+    (-1.0e38, -4.752_466_683_297_436e-20),
+    (3.402_823_5e38, -4.205_093_883_237_571e-20),
+    (-3.402_823_5e38, 4.205_093_883_237_571e-20),
+];
+
+/// `(x, Y₀(x))`
+pub const Y0: &[(f32, f64)] = &[
+    (0.893_576_86, -9.308_785_044_557_437e-8),
+    (0.893_576_9, -4.067_028_144_357_73e-8),
+    (0.893_577, 1.174_728_406_198_844_4e-8),
+    (0.893_577_04, 6.416_484_607_112_312e-8),
+    (0.893_577_1, 1.165_824_045_838_270_3e-7),
+    (3.957_677_8, 2.327_213_413_683_293_7e-7),
+    (3.957_678, 1.367_476_784_072_094e-7),
+    (3.957_678_3, 4.077_402_122_773_018e-8),
+    (3.957_678_6, -5.519_963_017_010_350_5e-8),
+    (3.957_678_8, -1.511_732_757_862_869e-7),
+    (7.086_05, -3.081_199_777_453_816_4e-7),
+    (7.086_050_5, -1.650_222_679_485_487e-7),
+    (7.086_051, -2.192_456_778_106_248_8e-8),
+    (7.086_051_5, 1.211_731_227_570_458e-7),
+    (7.086_052, 2.642_708_036_657_449e-7),
+    (10.222_343, 3.991_904_575_026_107e-7),
+    (10.222_344, 1.610_567_743_765_544_6e-7),
+    (10.222_345, -7.707_688_653_341_705e-8),
+    (10.222_346, -3.152_105_252_270_913_6e-7),
+    (10.222_347, -5.533_441_417_042_56e-7),
+    (13.361_095, -4.466_313_969_045_498e-7),
+    (13.361_096, -2.383_886_732_180_752_6e-7),
+    (13.361_097, -3.014_596_439_511_393_6e-8),
+    (13.361_098, 1.780_967_295_641_468_5e-7),
+    (13.361_099, 3.863_394_086_595_198_4e-7),
+    (16.500_92, 6.089_405_929_113_873e-7),
+    (16.500_921, 2.342_134_134_923_860_7e-7),
+    (16.500_923, -1.405_137_226_125_928e-7),
+    (16.500_925, -5.152_408_154_021_96e-7),
+    (16.500_927, -8.899_678_648_750_704e-7),
+    (100.5, -0.058_061_227_570_355_75),
+    (1000.25, 0.010_700_299_059_596_676),
+    (12345.678, -0.007_180_904_146_128_635),
+    (1.0e5, 0.001_846_766_158_865_064),
+    (524288.5, -0.000_128_616_138_938_238_9),
+    (1.0e6, -0.000_725_968_522_335_179_1),
+    (16777216.0, -0.000_193_649_073_878_706_5),
+    (10000001.0, 5.492_609_611_881_063e-5),
+    (3.1e9, 3.574_686_190_205_321e-6),
+    (1.0e10, -7.676_508_175_792_937e-6),
+    (7.7e15, 6.366_018_119_782_663e-9),
+    (9.223_372e18, 1.835_669_182_612_740_8e-10),
+    (1.0e20, -5.511_150_780_415_994e-12),
+    (5.0e25, -7.422_699_809_242_395e-15),
+    (1.0e30, -1.013_051_209_549_982_2e-16),
+    (2.7e34, -3.502_477_661_094_891e-18),
+    (1.0e38, 4.752_466_683_297_436e-20),
+    (3.402_823_5e38, -4.205_093_883_237_571e-20),
+];
+
+/// `(x, Y₁(x))`
+pub const Y1: &[(f32, f64)] = &[
+    (2.197_141, -2.051_627_252_163_742_3e-7),
+    (2.197_141_2, -8.099_755_322_996_127e-8),
+    (2.197_141_4, 4.316_760_528_290_95e-8),
+    (2.197_141_6, 1.673_327_503_222_354e-7),
+    (2.197_142, 2.914_978_818_880_137_5e-7),
+    (5.429_68, 3.982_363_627_503_368e-7),
+    (5.429_680_3, 2.359_600_451_900_505_6e-7),
+    (5.429_681, 7.368_374_188_089_669e-8),
+    (5.429_681_3, -8.859_254_717_709_168e-8),
+    (5.429_682, -2.508_688_219_838_814e-7),
+    (8.596_004, -6.341_090_502_167_577e-7),
+    (8.596_004_5, -3.752_246_813_006_971e-7),
+    (8.596_005, -1.163_403_411_059_414e-7),
+    (8.596_006, 1.425_439_703_672_834_7e-7),
+    (8.596_007, 4.014_282_531_187_516e-7),
+    (11.749_153, 3.937_049_116_699_004e-7),
+    (11.749_154, 1.720_120_729_136_679_9e-7),
+    (11.749_155, -4.968_074_784_799_809e-8),
+    (11.749_156, -2.713_735_506_149_005_4e-7),
+    (11.749_157, -4.930_663_353_868_421e-7),
+    (14.897_44, -4.485_525_441_453_487_3e-7),
+    (14.897_441, -2.515_738_474_609_892_7e-7),
+    (14.897_442, -5.459_516_338_618_638_4e-8),
+    (14.897_443, 1.423_835_080_788_831_5e-7),
+    (14.897_444, 3.393_621_669_340_425_7e-7),
+    (18.043_398, 8.209_930_099_762_088e-7),
+    (18.043_4, 4.629_281_153_143_485e-7),
+    (18.043_402, 1.048_632_585_014_670_9e-7),
+    (18.043_404, -2.532_015_604_611_449e-7),
+    (18.043_406, -6.112_663_415_721_969e-7),
+    (100.5, -0.054_726_102_094_683_49),
+    (1000.25, -0.022_841_189_398_253_216),
+    (12345.678, -2.897_011_369_251_486_4e-5),
+    (1.0e5, 0.001_719_210_350_088_256_2),
+    (524288.5, -0.001_094_400_681_524_768_8),
+    (1.0e6, -0.000_331_043_376_724_176_26),
+    (16777216.0, 2.110_761_311_107_342e-5),
+    (10000001.0, 0.000_246_262_250_839_081_24),
+    (3.1e9, 1.387_742_179_045_970_8e-5),
+    (1.0e10, -2.175_591_750_630_717e-6),
+    (7.7e15, -6.492_434_350_145_503e-9),
+    (9.223_372e18, -1.879_511_653_192_781_5e-10),
+    (1.0e20, -7.959_789_452_722_445e-11),
+    (5.0e25, 1.125_935_107_001_079_5e-13),
+    (1.0e30, 7.914_272_141_241_796e-16),
+    (2.7e34, -3.363_206_900_222_151e-18),
+    (1.0e38, -6.409_059_189_637_007e-20),
+    (3.402_823_5e38, -1.012_798_202_640_822e-20),
+];
+
+/// `(n, x, Jₙ(x))`
+pub const JN: &[(i32, f32, f64)] = &[
+    (-7, 11.086_369, -3.093_195_265_921_622_6e-7),
+    (-7, 11.086_369_5, -1.070_573_496_629_919_7e-7),
+    (-7, 11.086_37, 9.520_480_986_719_25e-8),
+    (-7, 11.086_371, 2.974_669_519_982_835e-7),
+    (-7, 11.086_372, 4.997_290_767_301_735e-7),
+    (-7, 14.821_267, 3.114_763_254_818_685_6e-7),
+    (-7, 14.821_268, 1.257_084_655_568_295e-7),
+    (-7, 14.821_269, -6.005_938_241_506_803e-8),
+    (-7, 14.821_27, -2.458_272_184_336_943e-7),
+    (-7, 14.821_271, -4.315_950_424_989_197e-7),
+    (-7, 18.287_579, -7.624_723_853_215_871e-7),
+    (-7, 18.287_58, -4.202_612_700_994_466e-7),
+    (-7, 18.287_582, -7.805_019_056_775_766e-8),
+    (-7, 18.287_584, 2.641_608_532_724_246e-7),
+    (-7, 18.287_586, 6.063_718_614_200_451e-7),
+    (-7, 100.5, -0.043_251_760_087_699_126),
+    (-7, -100.5, 0.043_251_760_087_699_126),
+    (-7, 1000.25, 0.011_256_773_446_856_827),
+    (-7, -1000.25, -0.011_256_773_446_856_827),
+    (-7, 12345.678, -0.007_180_833_669_191_811_5),
+    (-7, -12345.678, 0.007_180_833_669_191_811_5),
+    (-7, 1.0e5, 0.001_846_344_901_431_354_2),
+    (-7, -1.0e5, -0.001_846_344_901_431_354_2),
+    (-7, 524288.5, -0.000_128_564_997_468_791_77),
+    (-7, -524288.5, 0.000_128_564_997_468_791_77),
+    (-7, 1.0e6, -0.000_725_960_411_572_355_1),
+    (-7, -1.0e6, 0.000_725_960_411_572_355_1),
+    (-7, 16777216.0, -0.000_193_649_104_702_255_55),
+    (-7, -16777216.0, 0.000_193_649_104_702_255_55),
+    (-7, 10000001.0, 5.492_549_277_620_502e-5),
+    (-7, -10000001.0, -5.492_549_277_620_502e-5),
+    (-7, 3.1e9, 3.574_686_080_528_922_7e-6),
+    (-7, -3.1e9, -3.574_686_080_528_922_7e-6),
+    (-7, 1.0e10, -7.676_508_170_462_737e-6),
+    (-7, -1.0e10, 7.676_508_170_462_737e-6),
+    (-7, 7.7e15, 6.366_018_119_782_683e-9),
+    (-7, -7.7e15, -6.366_018_119_782_683e-9),
+    (-7, 9.223_372e18, 1.835_669_182_612_740_8e-10),
+    (-7, -9.223_372e18, -1.835_669_182_612_740_8e-10),
+    (-7, 1.0e20, -5.511_150_780_415_994e-12),
+    (-7, -1.0e20, 5.511_150_780_415_994e-12),
+    (-7, 5.0e25, -7.422_699_809_242_395e-15),
+    (-7, -5.0e25, 7.422_699_809_242_395e-15),
+    (-7, 1.0e30, -1.013_051_209_549_982_2e-16),
+    (-7, -1.0e30, 1.013_051_209_549_982_2e-16),
+    (-7, 2.7e34, -3.502_477_661_094_891e-18),
+    (-7, -2.7e34, 3.502_477_661_094_891e-18),
+    (-7, 1.0e38, 4.752_466_683_297_436e-20),
+    (-7, -1.0e38, -4.752_466_683_297_436e-20),
+    (-7, 3.402_823_5e38, -4.205_093_883_237_571e-20),
+    (-7, -3.402_823_5e38, 4.205_093_883_237_571e-20),
+    (2, 5.135_621_5, 2.561_583_846_193_678e-7),
+    (2, 5.135_622, 9.419_169_031_991_286e-8),
+    (2, 5.135_622_5, -6.777_498_894_112_203e-8),
+    (2, 5.135_623, -2.297_416_531_637_084e-7),
+    (2, 5.135_623_5, -3.917_083_023_478_178e-7),
+    (2, 8.417_242, -5.672_518_198_081_288e-7),
+    (2, 8.417_243, -3.084_411_647_558_856_5e-7),
+    (2, 8.417_244, -4.963_053_902_664_445e-8),
+    (2, 8.417_245, 2.091_800_573_793_794e-7),
+    (2, 8.417_246, 4.679_906_244_619_704_3e-7),
+    (2, 11.619_84, 3.495_654_640_867_606e-7),
+    (2, 11.619_841, 1.278_904_024_833_988_6e-7),
+    (2, 11.619_842, -9.378_464_092_655_564e-8),
+    (2, 11.619_843, -3.154_596_661_429_102_3e-7),
+    (2, 11.619_843_5, -5.371_346_731_654_722e-7),
+    (2, 100.5, -0.055_586_645_933_755_52),
+    (2, -100.5, -0.055_586_645_933_755_52),
+    (2, 1000.25, -0.022_825_117_267_767_717),
+    (2, -1000.25, -0.022_825_117_267_767_717),
+    (2, 12345.678, -2.984_259_342_633_043_8e-5),
+    (2, -12345.678, -2.984_259_342_633_043_8e-5),
+    (2, 1.0e5, 0.001_719_238_051_387_229_9),
+    (2, -1.0e5, 0.001_719_238_051_387_229_9),
+    (2, 524288.5, -0.001_094_401_049_493_664),
+    (2, -524288.5, -0.001_094_401_049_493_664),
+    (2, 1.0e6, -0.000_331_044_465_676_587_36),
+    (2, -1.0e6, -0.000_331_044_465_676_587_36),
+    (2, 16777216.0, 2.110_759_579_749_694_7e-5),
+    (2, -16777216.0, 2.110_759_579_749_694_7e-5),
+    (2, 10000001.0, 0.000_246_262_259_077_992_05),
+    (2, -10000001.0, 0.000_246_262_259_077_992_05),
+    (2, 3.1e9, 1.387_742_179_218_939_5e-5),
+    (2, -3.1e9, 1.387_742_179_218_939_5e-5),
+    (2, 1.0e10, -2.175_591_751_782_193_2e-6),
+    (2, -1.0e10, -2.175_591_751_782_193_2e-6),
+    (2, 7.7e15, -6.492_434_350_145_502_5e-9),
+    (2, -7.7e15, -6.492_434_350_145_502_5e-9),
+    (2, 9.223_372e18, -1.879_511_653_192_781_5e-10),
+    (2, -9.223_372e18, -1.879_511_653_192_781_5e-10),
+    (2, 1.0e20, -7.959_789_452_722_445e-11),
+    (2, -1.0e20, -7.959_789_452_722_445e-11),
+    (2, 5.0e25, 1.125_935_107_001_079_5e-13),
+    (2, -5.0e25, 1.125_935_107_001_079_5e-13),
+    (2, 1.0e30, 7.914_272_141_241_796e-16),
+    (2, -1.0e30, 7.914_272_141_241_796e-16),
+    (2, 2.7e34, -3.363_206_900_222_151e-18),
+    (2, -2.7e34, -3.363_206_900_222_151e-18),
+    (2, 1.0e38, -6.409_059_189_637_007e-20),
+    (2, -1.0e38, -6.409_059_189_637_007e-20),
+    (2, 3.402_823_5e38, -1.012_798_202_640_822e-20),
+    (2, -3.402_823_5e38, -1.012_798_202_640_822e-20),
+    (3, 6.380_161, 3.243_307_367_988_067e-7),
+    (3, 6.380_161_3, 1.821_029_121_341_184_5e-7),
+    (3, 6.380_162, 3.987_509_809_914_633e-8),
+    (3, 6.380_162_2, -1.023_527_053_060_860_4e-7),
+    (3, 6.380_162_7, -2.445_804_980_815_551e-7),
+    (3, 9.761_022, -3.780_926_862_926_451_4e-7),
+    (3, 9.761_023, -1.402_302_690_020_947_7e-7),
+    (3, 9.761_023_5, 9.763_212_504_886_793e-8),
+    (3, 9.761_024, 3.354_944_958_600_516e-7),
+    (3, 9.761_025, 5.733_568_434_312_648e-7),
+    (3, 13.015_199, 4.396_397_474_907_435_4e-7),
+    (3, 13.015_2, 2.314_726_092_153_816_1e-7),
+    (3, 13.015_201, 2.330_548_619_303_594_2e-8),
+    (3, 13.015_202, -1.848_616_215_761_164_8e-7),
+    (3, 13.015_202_5, -3.930_287_140_918_986e-7),
+    (3, 100.5, 0.055_578_720_178_673_095),
+    (3, -100.5, -0.055_578_720_178_673_095),
+    (3, 1000.25, -0.010_802_998_455_842_728),
+    (3, -1000.25, 0.010_802_998_455_842_728),
+    (3, 12345.678, 0.007_180_893_321_505_361),
+    (3, -12345.678, -0.007_180_893_321_505_361),
+    (3, 1.0e5, -0.001_846_688_793_360_512_2),
+    (3, -1.0e5, 0.001_846_688_793_360_512_2),
+    (3, 524288.5, 0.000_128_606_745_628_168_69),
+    (3, -524288.5, -0.000_128_606_745_628_168_69),
+    (3, 1.0e6, 0.000_725_967_032_635_900_4),
+    (3, -1.0e6, -0.000_725_967_032_635_900_4),
+    (3, 16777216.0, 0.000_193_649_079_540_205_67),
+    (3, -16777216.0, -0.000_193_649_079_540_205_67),
+    (3, 10000001.0, -5.492_598_530_080_575e-5),
+    (3, -10000001.0, 5.492_598_530_080_575e-5),
+    (3, 3.1e9, -3.574_686_170_060_676_6e-6),
+    (3, -3.1e9, 3.574_686_170_060_676_6e-6),
+    (3, 1.0e10, 7.676_508_174_813_921e-6),
+    (3, -1.0e10, -7.676_508_174_813_921e-6),
+    (3, 7.7e15, -6.366_018_119_782_666e-9),
+    (3, -7.7e15, 6.366_018_119_782_666e-9),
+    (3, 9.223_372e18, -1.835_669_182_612_740_8e-10),
+    (3, -9.223_372e18, 1.835_669_182_612_740_8e-10),
+    (3, 1.0e20, 5.511_150_780_415_994e-12),
+    (3, -1.0e20, -5.511_150_780_415_994e-12),
+    (3, 5.0e25, 7.422_699_809_242_395e-15),
+    (3, -5.0e25, -7.422_699_809_242_395e-15),
+    (3, 1.0e30, 1.013_051_209_549_982_2e-16),
+    (3, -1.0e30, -1.013_051_209_549_982_2e-16),
+    (3, 2.7e34, 3.502_477_661_094_891e-18),
+    (3, -2.7e34, -3.502_477_661_094_891e-18),
+    (3, 1.0e38, -4.752_466_683_297_436e-20),
+    (3, -1.0e38, 4.752_466_683_297_436e-20),
+    (3, 3.402_823_5e38, 4.205_093_883_237_571e-20),
+    (3, -3.402_823_5e38, -4.205_093_883_237_571e-20),
+    (10, 14.475_499, 2.748_229_109_384_584_4e-7),
+    (10, 14.475_5, 1.039_029_981_895_500_7e-7),
+    (10, 14.475_501, -6.701_690_329_886_923e-8),
+    (10, 14.475_502, -2.379_367_935_267_197e-7),
+    (10, 14.475_503, -4.088_566_724_939_215_6e-7),
+    (10, 18.433_46, -5.851_031_969_280_198e-7),
+    (10, 18.433_462, -2.598_699_685_675_446e-7),
+    (10, 18.433_464, 6.536_322_614_104_163e-8),
+    (10, 18.433_466, 3.905_963_871_969_107_4e-7),
+    (10, 18.433_468, 7.158_295_145_992_347e-7),
+    (10, 22.046_982, 5.702_601_460_528_5e-7),
+    (10, 22.046_984, 2.641_437_444_980_256e-7),
+    (10, 22.046_986, -4.197_263_057_454_373e-8),
+    (10, 22.046_988, -3.480_889_791_639_779_5e-7),
+    (10, 22.046_99, -6.542_053_012_693_972e-7),
+    (10, 100.5, -0.075_744_336_599_541),
+    (10, -100.5, -0.075_744_336_599_541),
+    (10, 1000.25, -0.022_283_891_131_753_795),
+    (10, -1000.25, -0.022_283_891_131_753_795),
+    (10, 12345.678, -5.776_164_807_442_447e-5),
+    (10, -12345.678, -5.776_164_807_442_447e-5),
+    (10, 1.0e5, 0.001_720_124_288_677_825_3),
+    (10, -1.0e5, 0.001_720_124_288_677_825_3),
+    (10, 524288.5, -0.001_094_412_819_768_39),
+    (10, -524288.5, -0.001_094_412_819_768_39),
+    (10, 1.0e6, -0.000_331_079_311_760_448_86),
+    (10, -1.0e6, -0.000_331_079_311_760_448_86),
+    (10, 16777216.0, 2.110_704_176_296_069_7e-5),
+    (10, -16777216.0, 2.110_704_176_296_069_7e-5),
+    (10, 10000001.0, 0.000_246_262_522_720_212_8),
+    (10, -10000001.0, 0.000_246_262_522_720_212_8),
+    (10, 3.1e9, 1.387_742_184_753_937_2e-5),
+    (10, -3.1e9, 1.387_742_184_753_937_2e-5),
+    (10, 1.0e10, -2.175_591_788_629_432_6e-6),
+    (10, -1.0e10, -2.175_591_788_629_432_6e-6),
+    (10, 7.7e15, -6.492_434_350_145_463e-9),
+    (10, -7.7e15, -6.492_434_350_145_463e-9),
+    (10, 9.223_372e18, -1.879_511_653_192_781_5e-10),
+    (10, -9.223_372e18, -1.879_511_653_192_781_5e-10),
+    (10, 1.0e20, -7.959_789_452_722_445e-11),
+    (10, -1.0e20, -7.959_789_452_722_445e-11),
+    (10, 5.0e25, 1.125_935_107_001_079_5e-13),
+    (10, -5.0e25, 1.125_935_107_001_079_5e-13),
+    (10, 1.0e30, 7.914_272_141_241_796e-16),
+    (10, -1.0e30, 7.914_272_141_241_796e-16),
+    (10, 2.7e34, -3.363_206_900_222_151e-18),
+    (10, -2.7e34, -3.363_206_900_222_151e-18),
+    (10, 1.0e38, -6.409_059_189_637_007e-20),
+    (10, -1.0e38, -6.409_059_189_637_007e-20),
+    (10, 3.402_823_5e38, -1.012_798_202_640_822e-20),
+    (10, -3.402_823_5e38, -1.012_798_202_640_822e-20),
+];
+
+/// `(n, x, Yₙ(x))`
+pub const YN: &[(i32, f32, f64)] = &[
+    (-7, 8.919_604, 3.089_822_720_652_991_3e-7),
+    (-7, 8.919_605, 1.034_122_171_368_210_7e-7),
+    (-7, 8.919_606, -1.021_578_158_123_716_6e-7),
+    (-7, 8.919_607, -3.077_278_267_822_119e-7),
+    (-7, 8.919_608, -5.132_978_157_726_327e-7),
+    (-7, 13.007_709_5, -3.932_427_856_914_683_4e-7),
+    (-7, 13.007_71, -1.991_517_250_047_979_6e-7),
+    (-7, 13.007_711, -5.060_678_547_993_701e-9),
+    (-7, 13.007_712, 1.890_303_536_788_210_5e-7),
+    (-7, 13.007_713, 3.831_213_716_755_229_7e-7),
+    (-7, 16.573_912, 6.464_666_786_024_149e-7),
+    (-7, 16.573_914, 2.903_255_042_266_978_6e-7),
+    (-7, 16.573_915, -6.581_562_916_467_606e-8),
+    (-7, 16.573_917, -4.219_567_215_706_518e-7),
+    (-7, 16.573_92, -7.780_977_729_901_743e-7),
+    (-7, 100.5, -0.066_926_457_810_615_58),
+    (-7, 1000.25, -0.022_577_892_179_904_847),
+    (-7, 12345.678, -4.292_973_072_947_738e-5),
+    (-7, 1.0e5, 0.001_719_653_524_449_580_1),
+    (-7, 524288.5, -0.001_094_406_567_952_111_6),
+    (-7, 1.0e6, -0.000_331_060_799_873_370_44),
+    (-7, 16777216.0, 2.110_733_609_382_959_2e-5),
+    (-7, 10000001.0, 0.000_246_262_382_660_989_5),
+    (-7, 3.1e9, 1.387_742_181_813_469_8e-5),
+    (-7, 1.0e10, -2.175_591_769_054_337e-6),
+    (-7, 7.7e15, -6.492_434_350_145_483_5e-9),
+    (-7, 9.223_372e18, -1.879_511_653_192_781_5e-10),
+    (-7, 1.0e20, -7.959_789_452_722_445e-11),
+    (-7, 5.0e25, 1.125_935_107_001_079_5e-13),
+    (-7, 1.0e30, 7.914_272_141_241_796e-16),
+    (-7, 2.7e34, -3.363_206_900_222_151e-18),
+    (-7, 1.0e38, -6.409_059_189_637_007e-20),
+    (-7, 3.402_823_5e38, -1.012_798_202_640_822e-20),
+    (2, 3.384_241_3, -1.695_048_944_647_755_2e-7),
+    (2, 3.384_241_6, -7.432_675_571_894_38e-8),
+    (2, 3.384_241_8, 2.085_137_632_162_591_4e-8),
+    (2, 3.384_242, 1.160_295_016_569_310_4e-7),
+    (2, 3.384_242_3, 2.112_076_202_869_69e-7),
+    (2, 6.793_806_6, 2.880_368_637_149_479_3e-7),
+    (2, 6.793_807, 1.450_242_042_903_416_8e-7),
+    (2, 6.793_807_5, 2.011_554_903_339_202e-9),
+    (2, 6.793_808, -1.410_010_844_460_312e-7),
+    (2, 6.793_808_5, -2.840_137_137_577_412_6e-7),
+    (2, 10.023_476, -5.823_249_659_786_975e-7),
+    (2, 10.023_477, -3.442_219_791_422_456_6e-7),
+    (2, 10.023_478, -1.061_190_149_595_783_4e-7),
+    (2, 10.023_478_5, 1.319_839_265_691_007_9e-7),
+    (2, 10.023_479, 3.700_868_454_435_881_3e-7),
+    (2, 100.5, 0.056_972_150_911_755_08),
+    (2, 1000.25, -0.010_745_970_020_652_92),
+    (2, 12345.678, 0.007_180_899_452_969_731),
+    (2, 1.0e5, -0.001_846_731_774_658_062_4),
+    (2, 524288.5, 0.000_128_611_964_134_933),
+    (2, 1.0e6, 0.000_725_967_860_248_425_8),
+    (2, 16777216.0, 0.000_193_649_076_394_930_1),
+    (2, 10000001.0, -5.492_604_686_636_538_5e-5),
+    (2, 3.1e9, -3.574_686_181_252_145_6e-6),
+    (2, 1.0e10, 7.676_508_175_357_818e-6),
+    (2, 7.7e15, -6.366_018_119_782_664e-9),
+    (2, 9.223_372e18, -1.835_669_182_612_740_8e-10),
+    (2, 1.0e20, 5.511_150_780_415_994e-12),
+    (2, 5.0e25, 7.422_699_809_242_395e-15),
+    (2, 1.0e30, 1.013_051_209_549_982_2e-16),
+    (2, 2.7e34, 3.502_477_661_094_891e-18),
+    (2, 1.0e38, -4.752_466_683_297_436e-20),
+    (2, 3.402_823_5e38, 4.205_093_883_237_571e-20),
+    (3, 4.527_024, -2.889_569_742_574_485_7e-7),
+    (3, 4.527_024_3, -1.303_792_826_425_557_5e-7),
+    (3, 4.527_024_7, 2.819_839_226_916_697e-8),
+    (3, 4.527_025, 1.867_760_504_777_028_4e-7),
+    (3, 4.527_025_7, 3.453_536_919_830_351_5e-7),
+    (3, 8.097_552, 3.962_851_285_851_198_4e-7),
+    (3, 8.097_553, 1.380_255_696_475_358e-7),
+    (3, 8.097_554, -1.202_339_588_741_178e-7),
+    (3, 8.097_555, -3.784_934_569_796_454e-7),
+    (3, 8.097_556, -6.367_529_246_688_516e-7),
+    (3, 11.396_465, -3.340_905_198_621_996e-7),
+    (3, 11.396_466, -1.125_360_384_448_345_6e-7),
+    (3, 11.396_467, 1.090_184_244_325_971_5e-7),
+    (3, 11.396_468, 3.305_728_687_699_111_3e-7),
+    (3, 11.396_469, 5.521_272_945_669_23e-7),
+    (3, 100.5, 0.056_993_650_389_678_71),
+    (3, 1000.25, 0.022_798_216_261_454_802),
+    (3, 12345.678, 3.129_672_535_611_772e-5),
+    (3, 1.0e5, -0.001_719_284_219_359_242_5),
+    (3, 524288.5, 0.001_094_401_662_755_248_7),
+    (3, 1.0e6, 0.000_331_046_280_595_617_26),
+    (3, 16777216.0, -2.110_756_694_153_578e-5),
+    (3, 10000001.0, -0.000_246_262_272_809_497_8),
+    (3, 3.1e9, -1.387_742_179_507_220_7e-5),
+    (3, 1.0e10, 2.175_591_753_701_320_4e-6),
+    (3, 7.7e15, 6.492_434_350_145_5e-9),
+    (3, 9.223_372e18, 1.879_511_653_192_781_5e-10),
+    (3, 1.0e20, 7.959_789_452_722_445e-11),
+    (3, 5.0e25, -1.125_935_107_001_079_5e-13),
+    (3, 1.0e30, -7.914_272_141_241_796e-16),
+    (3, 2.7e34, 3.363_206_900_222_151e-18),
+    (3, 1.0e38, 6.409_059_189_637_007e-20),
+    (3, 3.402_823_5e38, 1.012_798_202_640_822e-20),
+    (10, 12.128_925, -4.205_759_020_249_208e-7),
+    (10, 12.128_926, -2.521_154_226_421_902e-7),
+    (10, 12.128_927, -8.365_495_650_511_15e-8),
+    (10, 12.128_928, 8.480_549_638_626_83e-8),
+    (10, 12.128_929, 2.532_659_360_319_022_4e-7),
+    (10, 16.522_28, 8.169_906_255_076_425e-7),
+    (10, 16.522_282, 4.822_643_804_279_653e-7),
+    (10, 16.522_284, 1.475_381_739_883_048_3e-7),
+    (10, 16.522_285, -1.871_879_938_105_760_6e-7),
+    (10, 16.522_287, -5.219_141_229_679_147e-7),
+    (10, 20.265_98, -7.830_976_292_034_93e-7),
+    (10, 20.265_982, -4.676_086_866_822_962e-7),
+    (10, 20.265_984, -1.521_197_738_522_982_7e-7),
+    (10, 20.265_985, 1.633_691_092_856_380_2e-7),
+    (10, 20.265_987, 4.788_579_627_306_5e-7),
+    (10, 100.5, 0.025_076_468_024_428_44),
+    (10, 1000.25, -0.011_828_803_116_375_073),
+    (10, 12345.678, 0.007_180_730_280_807_726),
+    (10, 1.0e5, -0.001_845_906_332_111_093_4),
+    (10, 524288.5, 0.000_128_511_768_294_254_73),
+    (10, 1.0e6, 0.000_725_951_969_295_187),
+    (10, 16777216.0, 0.000_193_649_136_783_470_02),
+    (10, 10000001.0, -5.492_486_480_702_046e-5),
+    (10, 3.1e9, -3.574_685_966_375_937e-6),
+    (10, 1.0e10, 7.676_508_164_914_977e-6),
+    (10, 7.7e15, -6.366_018_119_782_705e-9),
+    (10, 9.223_372e18, -1.835_669_182_612_740_8e-10),
+    (10, 1.0e20, 5.511_150_780_415_994e-12),
+    (10, 5.0e25, 7.422_699_809_242_395e-15),
+    (10, 1.0e30, 1.013_051_209_549_982_2e-16),
+    (10, 2.7e34, 3.502_477_661_094_891e-18),
+    (10, 1.0e38, -4.752_466_683_297_436e-20),
+    (10, 3.402_823_5e38, 4.205_093_883_237_571e-20),
+];
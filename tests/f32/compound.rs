@@ -0,0 +1,107 @@
+//! High-precision values of the compound interest function
+//!
+//! Each `(x, n, y)` has `y` as `(1 + x)^n` computed with mpmath and correctly
+//! rounded to `f32`.  The powers are exact for small `|n|` and evaluated at
+//! 3000-bit precision otherwise.  The first cases lie within 2<sup>-20</sup>
+//! ulp of a midpoint between `f32` values.  They are followed by tiny `x` with
+//! huge `n`, where `1 + x` is inexact even in `f64`, and then random cases.
+
+/// `(x, n, (1 + x)^n)`
+pub const COMPOUND: &[(f32, i64, f32)] = &[
+    (0.010_908_07, -13, 0.868_457_14),
+    (4.814_222_6e-8, -13, 0.999_999_4),
+    (-0.015_014_546, -13, 1.217_341_3),
+    (2.292_480_2e-9, -13, 1.0),
+    (1.231_075_8e-6, -13, 0.999_984),
+    (-8.972_228e-6, -13, 1.000_116_6),
+    (0.002_314_341, -3, 0.993_088_96),
+    (-0.280_832_68, -3, 2.688_501),
+    (0.000_014_891_671, -3, 0.999_955_3),
+    (3.169_000_4e-6, -3, 0.999_990_5),
+    (1.490_116_5e-7, -3, 0.999_999_6),
+    (0.001_411_569_2, -3, 0.995_777_25),
+    (-0.995_589_26, 2, 0.000_019_454_66),
+    (-0.989_038_47, 2, 0.000_120_155_19),
+    (0.119_384_766, 2, 1.253_022_2),
+    (0.239_013_67, 2, 1.535_154_8),
+    (-0.997_975_8, 2, 4.097_279e-6),
+    (-0.999_501_8, 2, 2.481_789_4e-7),
+    (-0.709_960_94, 3, 0.024_398_856),
+    (0.081_167_13, 3, 1.263_800_5),
+    (-0.128_849_03, 3, 0.661_12),
+    (-0.000_027_130_784, 3, 0.999_918_6),
+    (-0.000_869_642_77, 3, 0.997_393_37),
+    (-0.478_515_62, 3, 0.141_815_57),
+    (-0.000_185_009_03, 7, 0.998_705_7),
+    (0.000_146_962_82, 7, 1.001_029_3),
+    (-7.237_709e-8, 7, 0.999_999_46),
+    (9.281_269e-7, 7, 1.000_006_6),
+    (8.514_934e-9, 7, 1.0),
+    (0.026_069_457, 7, 1.197_394_6),
+    (-2.294_781_4e-8, 100, 0.999_997_7),
+    (-0.003_407_605_2, 100, 0.710_815_4),
+    (0.000_050_821_393, 100, 1.005_095),
+    (0.000_153_936_82, 100, 1.015_511_5),
+    (6.258_468e-8, 100, 1.000_006_2),
+    (-1.680_692_8e-6, 100, 0.999_831_9),
+    (1.817_943_4e-9, -1000, 0.999_998_15),
+    (-1.154_768_4e-6, -1000, 1.001_155_5),
+    (7.410_255_4e-6, -1000, 0.992_617_2),
+    (-1.251_696_7e-9, -1000, 1.000_001_2),
+    (7.869_421_7e-7, -1000, 0.999_213_4),
+    (-1.353_016_3e-8, -1000, 1.000_013_6),
+    (1.071_174e-7, 65_537, 1.007_044_9),
+    (-9.526_602e-9, 65_537, 0.999_375_8),
+    (-1.512_086_8e-9, 65_537, 0.999_900_9),
+    (-3.647_235_4e-8, 65_537, 0.997_612_6),
+    (-6.651_379e-6, 65_537, 0.646_674_1),
+    (0.000_099_582_7, 65_537, 682.6802),
+    (3.480_106_5e-11, 2_198_063_731_530, 1.664_708_9e33),
+    (2.210_255e-14, -1_330_095_193_332_428, 1.707_635_9e-13),
+    (-2.955_385_4e-14, -1_620_806_785_826_989, 6.355_907_6e20),
+    (-8.551_617e-13, -56_782_583_570_757, 1.226_302_6e21),
+    (9.402_398e-17, -782_040_370_233_246_336, 1.164_364_6e-32),
+    (-8.977_929e-11, -833_991_556_251, 3.295_129e32),
+    (6.775_065_8e-12, -2_781_154_830_834, 6.558_481e-9),
+    (-2.768_413e-11, -154_802_313_009, 72.643_745),
+    (3.393_521_2e-11, 253_578_287_734, 5460.158),
+    (-4.693_416e-15, -7_347_907_725_449_089, 9.493_39e14),
+    (3.491_17e-15, 21_911_523_948_416_524, 1.667_868e33),
+    (-6.552_680_6e-10, 2_611_593_669, 0.180_631_92),
+    (1.805_601_6e-10, 192_017_879_334, 1.141_112_1e15),
+    (5.544_011e-14, -248_503_510_895_863, 1.039_197_6e-6),
+    (1.354_156_3e-11, -5_300_460_154_342, 6.727_534_6e-32),
+    (-3.810_65e-14, -660_349_003_047_948, 8.480_243e10),
+    (-1.627_441_2e-9, 11_284_264_959, 1.057_812_7e-8),
+    (-2.668_826_7e-14, -265_543_481_062_396, 1196.1881),
+    (6.055_985e-12, 7_190_227_674_115, 8.144_805e18),
+    (-3.598_073e-12, 1_384_422_218_843, 0.006_865_46),
+    (-6.302_512e-15, 12_261_974_805_180_196, 2.736_414e-34),
+    (-9.555_006e-16, -32_746_737_288_510_580, 3.880_332_7e13),
+    (-4.072_171_5e-16, 105_580_101_410_745_008, 2.127_800_2e-19),
+    (-6.841_998e-13, 93_088_533_946_594, 2.184_144_7e-28),
+    (0.271_043_48, -13, 0.044_250_146),
+    (2.498_036_4, 3, 42.802_88),
+    (2.831_127_4, -5, 0.001_211_621_7),
+    (3.293_141_1, 7, 26_879.807),
+    (2.676_005_1, -13, 4.467_973_7e-8),
+    (1.834_309_3, 2, 8.033_309),
+    (1.724_886_7, -13, 2.190_132e-6),
+    (1.116_406_2, 2, 4.479_175),
+    (3.348_349, 20, 5.841_011_3e12),
+    (0.424_239_78, -13, 0.010_079_049),
+    (1.561_307_1, 7, 723.1553),
+    (-0.096_693_054, -13, 3.750_943),
+    (2.973_171_5, 20, 9.610_504_4e11),
+    (1.691_459_2, 2, 7.243_952_3),
+    (1.861_835, 3, 23.438_713),
+    (0.671_066_6, -13, 0.001_262_063_5),
+    (1.064_736_2, -13, 0.000_080_678_98),
+    (1.642_333_5, 7, 899.3183),
+    (-0.546_685_6, 2, 0.205_493_97),
+    (1.312_096_4, -13, 0.000_018_532_026),
+    (0.330_668_48, 7, 7.387_356_8),
+    (2.820_055_7, -13, 2.710_778e-8),
+    (3.861_366_5, 3, 114.888_115),
+    (-0.451_283_28, 3, 0.165_213_14),
+];
@@ -58,7 +58,7 @@ fn test_bivariate(
 ) {
     const LIMIT: usize = 250;
     let count = data
-        .filter(|&[x, y]| (!f(x, y).is(&g(x, y))))
+        .filter(|&[x, y]| !f(x, y).is(&g(x, y)))
         .map(|[x, y]| println!("{x:e}, {y:e}: {:e} != {:e}", f(x, y), g(x, y)))
         .take(LIMIT)
         .count();
@@ -86,8 +86,6 @@ fn test_hypot() {
 }
 
 #[test]
-// Signal when precision improves
-#[should_panic = "Too many (>= 250) mismatches!  Aborting..."]
 fn test_powf() {
     test_bivariate(metal::powf, core_math::powf, parse_pairs_from("powf.wc"));
 }
@@ -1,3 +1,5 @@
+mod bessel;
+mod compound;
 mod huge;
 mod ldexp;
 use core::num::FpCategory;
@@ -20,6 +22,12 @@ impl Identity for f32 {
     }
 }
 
+impl Identity for i32 {
+    fn is(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
 impl<T: Identity, U: Identity> Identity for (T, U) {
     fn is(&self, other: &Self) -> bool {
         self.0.is(&other.0) && self.1.is(&other.1)
@@ -63,6 +71,75 @@ fn test_cbrt() {
     test_identity(metal::cbrt, core_math::cbrtf);
 }
 
+#[test]
+fn test_rsqrt() {
+    test_identity(metal::rsqrt, core_math::rsqrtf);
+}
+
+/// Split a positive finite `f32` into an integer significand and an exponent
+fn split(x: f32) -> (u128, i32) {
+    let bits = x.to_bits();
+    let significand = bits & 0x7F_FFFF;
+
+    #[allow(clippy::cast_possible_wrap)]
+    match (bits >> 23) as i32 {
+        0 => (significand.into(), -149),
+        exponent => ((significand | 0x80_0000).into(), exponent - 150),
+    }
+}
+
+/// Exactly compare `x * m^3` with 1, where `m = significand * 2^exponent`
+fn cmp_cube(x: f32, significand: u128, exponent: i32) -> core::cmp::Ordering {
+    let (x, e) = split(x);
+    let product = x * significand.pow(3);
+
+    // `product < 2^102` because `x < 2^24` and `significand < 2^26`.
+    match -(e + 3 * exponent) {
+        shift if shift < 0 => core::cmp::Ordering::Greater,
+        shift if shift >= 128 => core::cmp::Ordering::Less,
+        shift => product.cmp(&(1 << shift)),
+    }
+}
+
+/// Check if `r` is the correctly rounded `x^(-1/3)` for positive finite `x`
+///
+/// The result is never subnormal, so its midpoints with its neighbors are
+/// `2 * significand ± 1` halves of an ulp.  The exact reciprocal cube root is
+/// between them iff `x * m^3` is at most 1 for the lower midpoint and at least
+/// 1 for the upper midpoint.  Neither can equal 1 because the midpoints have
+/// odd significands greater than 1.
+fn is_rcbrt(x: f32, r: f32) -> bool {
+    if !r.is_normal() || r.is_sign_negative() {
+        return false;
+    }
+
+    let (significand, exponent) = split(r);
+    let lower = if significand == 0x80_0000 {
+        cmp_cube(x, 4 * significand - 1, exponent - 2)
+    } else {
+        cmp_cube(x, 2 * significand - 1, exponent - 1)
+    };
+    let upper = cmp_cube(x, 2 * significand + 1, exponent - 1);
+
+    lower.is_lt() && upper.is_gt()
+}
+
+#[test]
+fn test_rcbrt() {
+    exhaustively_test_u32(|i| {
+        let x = f32::from_bits(i);
+        let y = metal::rcbrt(x);
+
+        let correct = match x.classify() {
+            FpCategory::Nan => y.is_nan(),
+            FpCategory::Zero | FpCategory::Infinite => y.is(&x.recip()),
+            _ => is_rcbrt(x.abs(), y.abs()) && y.is_sign_negative() == x.is_sign_negative(),
+        };
+
+        (!correct).then(|| println!("{x:e}: {y:e}"))
+    });
+}
+
 #[test]
 fn test_exp() {
     test_identity(metal::exp, core_math::expf);
@@ -83,6 +160,16 @@ fn test_exp_m1() {
     test_identity(metal::exp_m1, core_math::expm1f);
 }
 
+#[test]
+fn test_exp2m1() {
+    test_identity(metal::exp2m1, core_math::exp2m1f);
+}
+
+#[test]
+fn test_exp10m1() {
+    test_identity(metal::exp10m1, core_math::exp10m1f);
+}
+
 #[test]
 fn test_ln() {
     test_identity(metal::ln, core_math::logf);
@@ -93,6 +180,16 @@ fn test_ln_1p() {
     test_identity(metal::ln_1p, core_math::log1pf);
 }
 
+#[test]
+fn test_log2p1() {
+    test_identity(metal::log2p1, core_math::log2p1f);
+}
+
+#[test]
+fn test_log10p1() {
+    test_identity(metal::log10p1, core_math::log10p1f);
+}
+
 #[test]
 fn test_log2() {
     test_identity(metal::log2, core_math::log2f);
@@ -148,6 +245,21 @@ fn test_atan() {
     test_identity(metal::atan, core_math::atanf);
 }
 
+#[test]
+fn test_asinpi() {
+    test_identity(metal::asinpi, core_math::asinpif);
+}
+
+#[test]
+fn test_acospi() {
+    test_identity(metal::acospi, core_math::acospif);
+}
+
+#[test]
+fn test_atanpi() {
+    test_identity(metal::atanpi, core_math::atanpif);
+}
+
 #[test]
 fn test_cos() {
     test_identity(metal::cos, core_math::cosf);
@@ -158,6 +270,28 @@ fn test_sin() {
     test_identity(metal::sin, core_math::sinf);
 }
 
+#[test]
+fn test_sinpi() {
+    test_identity(metal::sinpi, core_math::sinpif);
+}
+
+#[test]
+fn test_cospi() {
+    test_identity(metal::cospi, core_math::cospif);
+}
+
+#[test]
+fn test_tanpi() {
+    test_identity(metal::tanpi, core_math::tanpif);
+}
+
+#[test]
+fn test_sin_cos_pi() {
+    test_identity(metal::sin_cos_pi, |x| {
+        (core_math::sinpif(x), core_math::cospif(x))
+    });
+}
+
 #[test]
 fn test_sin_cos() {
     test_identity(metal::sin_cos, core_math::sincosf);
@@ -168,6 +302,147 @@ fn test_tan() {
     test_identity(metal::tan, core_math::tanf);
 }
 
+#[test]
+fn test_erf() {
+    test_identity(metal::erf, core_math::erff);
+}
+
+#[test]
+fn test_erfc() {
+    test_identity(metal::erfc, core_math::erfcf);
+}
+
+#[test]
+fn test_erfcx() {
+    // Asymptotic expansion for large `x`, where `erfc` underflows in `f64`
+    let erfcx = |x: f64| {
+        if x < 26.0 {
+            return core_math::erfc(x) * (x * x).exp();
+        }
+        let y = (2.0 * x * x).recip();
+        let s = 1.0 - y * (1.0 - 3.0 * y * (1.0 - 5.0 * y * (1.0 - 7.0 * y * (1.0 - 9.0 * y))));
+        s / (x * core::f64::consts::PI.sqrt())
+    };
+
+    exhaustively_test_u32(|i| {
+        let x = f32::from_bits(i);
+        let f = metal::erfcx(x);
+        let g = erfcx(x.into());
+
+        (!is_faithful_rounding(f, g)).then(|| println!("{x:e}: {f:e} != {g:e}"))
+    });
+}
+
+#[test]
+fn test_tgamma() {
+    test_identity(metal::tgamma, core_math::tgammaf);
+}
+
+#[test]
+fn test_lgamma() {
+    test_identity(metal::lgamma, core_math::lgammaf);
+}
+
+#[test]
+fn test_lgamma_r() {
+    test_identity(metal::lgamma_r, |x| {
+        let gamma = core_math::tgammaf(x);
+        let sign = if gamma.is_sign_negative() && !gamma.is_nan() {
+            -1
+        } else {
+            1
+        };
+        (core_math::lgammaf(x), sign)
+    });
+}
+
+/// Check if a Bessel function is accurate to `expected` at `x`
+///
+/// Reference values from `libm` are only accurate in absolute error near zeros
+/// and for large arguments.  The result is also accepted if the error is tiny
+/// compared to the envelope `√(2 / π|x|)` of the Bessel functions.
+fn is_bessel_accurate(x: f32, result: f32, expected: f64) -> bool {
+    let envelope = (core::f64::consts::FRAC_2_PI / f64::from(x).abs()).sqrt();
+    is_faithful_rounding(result, expected)
+        || (f64::from(result) - expected).abs() < envelope * 1e-12
+}
+
+/// Test a Bessel function of order 0 or 1
+///
+/// The result must be faithfully rounded from the high-precision `table`, which
+/// covers arguments near zeros and large arguments.  Other arguments are
+/// exhaustively checked against `g` from `libm` with [`is_bessel_accurate`].
+fn test_bessel(f: impl Fn(f32) -> f32, g: impl Fn(f64) -> f64, table: &[(f32, f64)]) {
+    for &(x, y) in table {
+        let f = f(x);
+        assert!(is_faithful_rounding(f, y), "{x:e}: {f:e} != {y:e}");
+    }
+
+    exhaustively_test_u32(|i| {
+        let x = f32::from_bits(i);
+        let f = f(x);
+        let g = g(x.into());
+
+        (!is_bessel_accurate(x, f, g)).then(|| println!("{x:e}: {f:e} != {g:e}"))
+    });
+}
+
+#[test]
+fn test_j0() {
+    test_bessel(metal::j0, libm::j0, bessel::J0);
+}
+
+#[test]
+fn test_j1() {
+    test_bessel(metal::j1, libm::j1, bessel::J1);
+}
+
+#[test]
+fn test_y0() {
+    test_bessel(metal::y0, libm::y0, bessel::Y0);
+}
+
+#[test]
+fn test_y1() {
+    test_bessel(metal::y1, libm::y1, bessel::Y1);
+}
+
+/// Test Bessel functions of integer orders for some `n`
+///
+/// The recurrence for `|n| > 1` loses relative accuracy near zeros, so both
+/// the high-precision `table` and `g` from `libm` are checked with
+/// [`is_bessel_accurate`].
+fn test_bessel_n(
+    f: impl Fn(i32, f32) -> f32,
+    g: impl Fn(i32, f64) -> f64,
+    table: &[(i32, f32, f64)],
+) {
+    for &(n, x, y) in table {
+        let f = f(n, x);
+        assert!(is_bessel_accurate(x, f, y), "{n}, {x:e}: {f:e} != {y:e}");
+    }
+
+    for n in [-7, 2, 3, 10] {
+        exhaustively_test_u32(|i| {
+            let x = f32::from_bits(i);
+            let f = f(n, x);
+            let g = g(n, x.into());
+
+            (!is_bessel_accurate(x, f, g)).then(|| println!("{n}, {x:e}: {f:e} != {g:e}"))
+        });
+    }
+}
+
+#[test]
+fn test_jn() {
+    test_bessel_n(metal::jn, libm::jn, bessel::JN);
+}
+
+#[test]
+fn test_yn() {
+    test_bessel_n(metal::yn, libm::yn, bessel::YN);
+}
+
 #[test]
 fn frexp() {
     (0..u32::MAX).for_each(|i| {
@@ -189,6 +464,48 @@ fn frexp() {
     });
 }
 
+#[test]
+fn test_ilogb() {
+    test_identity(metal::ilogb, libm::ilogbf);
+}
+
+#[test]
+fn test_logb() {
+    test_identity(metal::logb, |x| match x.classify() {
+        FpCategory::Nan => x,
+        FpCategory::Infinite => f32::INFINITY,
+        FpCategory::Zero => f32::NEG_INFINITY,
+        _ => libm::ilogbf(x) as f32,
+    });
+}
+
+#[test]
+fn test_scalbn() {
+    for n in [-300, -150, -1, 0, 1, 150, 300] {
+        test_identity(|x| metal::scalbn(x, n), |x| libm::ldexpf(x, n));
+    }
+
+    assert_eq!(metal::scalbln(1.0, i64::MIN), 0.0);
+    assert_eq!(metal::scalbln(-1.0, 1 << 40), f32::NEG_INFINITY);
+}
+
+#[test]
+fn test_modf() {
+    test_identity(metal::modf, libm::modff);
+}
+
+#[test]
+fn test_nextafter() {
+    test_bivariate_correct(metal::nextafter, libm::nextafterf);
+}
+
+#[test]
+fn test_nexttoward() {
+    test_bivariate_correct(|x, y| metal::nexttoward(x, y.into()), libm::nextafterf);
+    assert_eq!(metal::nexttoward(1.0, 1.0 + 1e-12), metal::next_up(1.0));
+    assert_eq!(metal::nexttoward(0.0, -1e-300), -f32::from_bits(1));
+}
+
 /// Check if `result` is within the nearby `f32` representations of `expected`
 ///
 /// Due to [the Table Maker's Dilemma][dilemma], it is infeasible to implement a
@@ -223,14 +540,34 @@ fn test_bivariate_faithful(f: impl Fn(f32, f32) -> f32, g: impl Fn(f64, f64) ->
     });
 }
 
-fn test_bivariate_correct(f: impl Fn(f32, f32) -> f32, g: impl Fn(f32, f32) -> f32) {
+fn test_bivariate_correct<T: Identity + core::fmt::Debug>(
+    f: impl Fn(f32, f32) -> T,
+    g: impl Fn(f32, f32) -> T,
+) {
+    const SPECIAL: [f32; 7] = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+
+    for x in SPECIAL {
+        for y in SPECIAL {
+            let (f, g) = (f(x, y), g(x, y));
+            assert!(f.is(&g), "{x:e}, {y:e}: {f:?} != {g:?}");
+        }
+    }
+
     exhaustively_test_u32(|bits| {
         let x = f32::from_bits(0x10001 * (bits >> 16));
         let y = f32::from_bits(bits << 16);
         let f = f(x, y);
         let g = g(x, y);
 
-        (!f.is(&g)).then(|| println!("{x:e}, {y:e}: {f:e} != {g:e}"))
+        (!f.is(&g)).then(|| println!("{x:e}, {y:e}: {f:?} != {g:?}"))
     });
 }
 
@@ -239,7 +576,142 @@ fn test_hypot() {
     test_bivariate_correct(metal::hypot, core_math::hypotf);
 }
 
+#[test]
+fn test_fmod() {
+    test_bivariate_correct(metal::fmod, libm::fmodf);
+    assert_eq!(metal::fmod(f32::MAX, f32::from_bits(1)), 0.0);
+    assert_eq!(metal::fmod(-f32::MAX, 11.0), -9.0);
+}
+
+#[test]
+fn test_remainder() {
+    test_bivariate_correct(metal::remainder, libm::remainderf);
+}
+
+#[test]
+fn test_remquo() {
+    test_bivariate_correct(metal::remquo, libm::remquof);
+    assert_eq!(metal::remquo(f32::MAX, f32::from_bits(1)), (0.0, 0));
+    assert_eq!(metal::remquo(-7.0, 2.0), (1.0, -4));
+}
+
 #[test]
 fn test_powf() {
-    test_bivariate_faithful(metal::powf, core_math::pow);
+    test_bivariate_correct(metal::powf, core_math::powf);
+}
+
+#[test]
+fn test_powr() {
+    test_bivariate_correct(metal::powr, |x, y| {
+        let undefined = (x == 0.0 || x == f32::INFINITY) && y == 0.0 || x == 1.0 && y.is_infinite();
+
+        if x.is_nan() || y.is_nan() || x < 0.0 || undefined {
+            return f32::NAN;
+        }
+
+        core_math::powf(x.abs(), y)
+    });
+}
+
+#[test]
+fn test_pown() {
+    for n in [-3, 2, 5] {
+        test_identity(|x| metal::pown(x, n), |x| core_math::powf(x, n as f32));
+    }
+
+    assert_eq!(metal::pown(-1.0, i64::MAX), -1.0);
+    assert_eq!(metal::pown(-1.0, i64::MIN), 1.0);
+    assert_eq!(metal::pown(-0.0, -(1 << 60) - 1), f32::NEG_INFINITY);
+    assert_eq!(metal::pown(f32::NAN, 0), 1.0);
+}
+
+#[test]
+fn test_rootn() {
+    test_identity(|x| metal::rootn(x, 1), |x| x);
+    test_identity(|x| metal::rootn(x, -1), |x| 1.0 / x);
+    test_identity(
+        |x| metal::rootn(x, 2),
+        |x| if x == 0.0 { 0.0 } else { x.sqrt() },
+    );
+    test_identity(|x| metal::rootn(x, 3), core_math::cbrtf);
+
+    test_identity(
+        |x| metal::rootn(x, -2),
+        |x| {
+            if x == 0.0 {
+                f32::INFINITY
+            } else {
+                core_math::rsqrtf(x)
+            }
+        },
+    );
+
+    assert!(metal::rootn(1.0, 0).is_nan());
+    assert!(metal::rootn(-8.0, 2).is_nan());
+    assert_eq!(metal::rootn(-8.0, 3), -2.0);
+}
+
+/// Round `x` to `f32` unless it is a midpoint between `f32` values
+///
+/// A midpoint is a rounded `f64` result whose true value may lie on either
+/// side, so it does not determine the correctly rounded `f32`.
+fn round_unless_midpoint(x: f64) -> Option<f32> {
+    #[allow(clippy::cast_possible_truncation)]
+    let y = x as f32;
+
+    if x.is_infinite() {
+        return Some(y);
+    }
+
+    let other = if f64::from(y) < x {
+        metal::next_up(y)
+    } else {
+        metal::next_down(y)
+    };
+
+    (f64::from(y) + f64::from(other) != 2.0 * x).then_some(y)
+}
+
+#[test]
+fn test_compound() {
+    for &(x, n, y) in compound::COMPOUND {
+        let f = metal::compound(x, n);
+        assert!(f.is(&y), "{x:e}, {n}: {f:e} != {y:e}");
+    }
+
+    // `1 + x` is exact in `f64` unless `x` is tiny, where the table above
+    // takes over.  The correctly rounded `f64` power then rounds correctly to
+    // `f32` except at midpoints.
+    for n in [-13, 2, 7] {
+        exhaustively_test_u32(|i| {
+            let x = f32::from_bits(i);
+            let base = 1.0 + f64::from(x);
+
+            let g = if x < -1.0 {
+                f32::NAN
+            } else if base - 1.0 == x.into() {
+                round_unless_midpoint(metallic::f64::pow(base, n as f64))?
+            } else {
+                return None;
+            };
+
+            let f = metal::compound(x, n);
+            (!f.is(&g)).then(|| println!("{x:e}, {n}: {f:e} != {g:e}"))
+        });
+    }
+
+    assert_eq!(metal::compound(-1.0, -1), f32::INFINITY);
+    assert_eq!(metal::compound(-1.0, 1), 0.0);
+    assert_eq!(metal::compound(f32::NAN, 0), 1.0);
+    assert_eq!(metal::compound(1e-30, 1 << 62), 1.0);
+}
+
+#[test]
+fn test_atan2() {
+    test_bivariate_correct(metal::atan2, core_math::atan2f);
+}
+
+#[test]
+fn test_atan2pi() {
+    test_bivariate_correct(metal::atan2pi, core_math::atan2pif);
 }
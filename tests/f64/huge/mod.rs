@@ -0,0 +1,249 @@
+use super::Identity as _;
+use metallic::f64 as metal;
+use regex::Regex;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+fn parse_f64(s: &str) -> Result<f64, hexf_parse::ParseHexfError> {
+    hexf_parse::parse_hexf64(s, true)
+}
+
+fn strip_comment(line: &str) -> &str {
+    line[..line.find('#').unwrap_or(line.len())].trim_ascii()
+}
+
+fn parse_singles(stream: impl std::io::BufRead) -> impl Iterator<Item = f64> {
+    stream
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| parse_f64(strip_comment(&line)).ok())
+}
+
+fn parse_pairs(stream: impl std::io::BufRead) -> impl Iterator<Item = [f64; 2]> {
+    static SEPARATOR: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\s*,\s*").expect("Failed to compile SEPARATOR"));
+
+    stream.lines().map_while(Result::ok).filter_map(|line| {
+        let line = strip_comment(&line);
+        let mut fields = SEPARATOR.splitn(line, 2);
+        let x = fields.next().and_then(|s| parse_f64(s).ok())?;
+        let y = fields.next().and_then(|s| parse_f64(s).ok())?;
+        Some([x, y])
+    })
+}
+
+fn open(filename: impl AsRef<std::ffi::OsStr>) -> Option<std::io::BufReader<std::fs::File>> {
+    let path: PathBuf = file!().into();
+    let path = path.with_file_name(filename);
+    std::fs::File::open(path).map(std::io::BufReader::new).ok()
+}
+
+fn parse_singles_from(filename: impl AsRef<std::ffi::OsStr>) -> impl Iterator<Item = f64> {
+    open(filename).map(parse_singles).into_iter().flatten()
+}
+
+fn parse_pairs_from(filename: impl AsRef<std::ffi::OsStr>) -> impl Iterator<Item = [f64; 2]> {
+    open(filename).map(parse_pairs).into_iter().flatten()
+}
+
+fn test_univariate(
+    f: impl Fn(f64) -> f64,
+    g: impl Fn(f64) -> f64,
+    data: impl Iterator<Item = f64>,
+) {
+    const LIMIT: usize = 250;
+    let count = data
+        .filter(|&x| !f(x).is(&g(x)))
+        .map(|x| println!("{x:e}: {:e} != {:e}", f(x), g(x)))
+        .take(LIMIT)
+        .count();
+
+    assert!(
+        count < LIMIT,
+        "Too many (>= {LIMIT}) mismatches!  Aborting...",
+    );
+    assert!(count == 0, "There are {count} mismatches");
+}
+
+fn test_bivariate(
+    f: impl Fn(f64, f64) -> f64,
+    g: impl Fn(f64, f64) -> f64,
+    data: impl Iterator<Item = [f64; 2]>,
+) {
+    const LIMIT: usize = 250;
+    let count = data
+        .filter(|&[x, y]| !f(x, y).is(&g(x, y)))
+        .map(|[x, y]| println!("{x:e}, {y:e}: {:e} != {:e}", f(x, y), g(x, y)))
+        .take(LIMIT)
+        .count();
+
+    assert!(
+        count < LIMIT,
+        "Too many (>= {LIMIT}) mismatches!  Aborting...",
+    );
+    assert!(count == 0, "There are {count} mismatches");
+}
+
+#[test]
+fn test_parser() {
+    assert!(parse_singles_from("exp.wc").count() == 62);
+    assert!(parse_singles_from("exp2.wc").count() == 48);
+    assert!(parse_singles_from("exp10.wc").count() == 56);
+    assert!(parse_singles_from("exp_m1.wc").count() == 42);
+    assert!(parse_singles_from("ln.wc").count() == 36);
+    assert!(parse_singles_from("ln_1p.wc").count() == 35);
+    assert!(parse_singles_from("log2.wc").count() == 10);
+    assert!(parse_singles_from("log10.wc").count() == 10);
+    assert!(parse_singles_from("sin.wc").count() == 19);
+    assert!(parse_singles_from("cos.wc").count() == 23);
+    assert!(parse_singles_from("tan.wc").count() == 22);
+    assert!(parse_singles_from("asin.wc").count() == 23);
+    assert!(parse_singles_from("acos.wc").count() == 15);
+    assert!(parse_singles_from("atan.wc").count() == 41);
+    assert!(parse_singles_from("sinh.wc").count() == 114);
+    assert!(parse_singles_from("cosh.wc").count() == 56);
+    assert!(parse_singles_from("tanh.wc").count() == 37);
+    assert!(parse_singles_from("asinh.wc").count() == 85);
+    assert!(parse_singles_from("acosh.wc").count() == 15);
+    assert!(parse_singles_from("atanh.wc").count() == 27);
+    assert!(parse_pairs_from("pow.wc").count() == 726);
+    assert!(parse_pairs_from("atan2.wc").count() == 160);
+}
+
+#[test]
+fn test_exp() {
+    test_univariate(metal::exp, core_math::exp, parse_singles_from("exp.wc"));
+}
+
+#[test]
+fn test_exp2() {
+    test_univariate(metal::exp2, core_math::exp2, parse_singles_from("exp2.wc"));
+}
+
+#[test]
+fn test_exp10() {
+    test_univariate(
+        metal::exp10,
+        core_math::exp10,
+        parse_singles_from("exp10.wc"),
+    );
+}
+
+#[test]
+fn test_exp_m1() {
+    test_univariate(
+        metal::exp_m1,
+        core_math::expm1,
+        parse_singles_from("exp_m1.wc"),
+    );
+}
+
+#[test]
+fn test_pow() {
+    test_bivariate(metal::pow, core_math::pow, parse_pairs_from("pow.wc"));
+}
+
+#[test]
+fn test_ln() {
+    test_univariate(metal::ln, core_math::log, parse_singles_from("ln.wc"));
+}
+
+#[test]
+fn test_ln_1p() {
+    test_univariate(
+        metal::ln_1p,
+        core_math::log1p,
+        parse_singles_from("ln_1p.wc"),
+    );
+}
+
+#[test]
+fn test_log2() {
+    test_univariate(metal::log2, core_math::log2, parse_singles_from("log2.wc"));
+}
+
+#[test]
+fn test_log10() {
+    test_univariate(
+        metal::log10,
+        core_math::log10,
+        parse_singles_from("log10.wc"),
+    );
+}
+
+#[test]
+fn test_sin() {
+    test_univariate(metal::sin, core_math::sin, parse_singles_from("sin.wc"));
+}
+
+#[test]
+fn test_cos() {
+    test_univariate(metal::cos, core_math::cos, parse_singles_from("cos.wc"));
+}
+
+#[test]
+fn test_tan() {
+    test_univariate(metal::tan, core_math::tan, parse_singles_from("tan.wc"));
+}
+
+#[test]
+fn test_asin() {
+    test_univariate(metal::asin, core_math::asin, parse_singles_from("asin.wc"));
+}
+
+#[test]
+fn test_acos() {
+    test_univariate(metal::acos, core_math::acos, parse_singles_from("acos.wc"));
+}
+
+#[test]
+fn test_atan() {
+    test_univariate(metal::atan, core_math::atan, parse_singles_from("atan.wc"));
+}
+
+#[test]
+fn test_atan2() {
+    test_bivariate(metal::atan2, core_math::atan2, parse_pairs_from("atan2.wc"));
+}
+
+#[test]
+fn test_sinh() {
+    test_univariate(metal::sinh, core_math::sinh, parse_singles_from("sinh.wc"));
+}
+
+#[test]
+fn test_cosh() {
+    test_univariate(metal::cosh, core_math::cosh, parse_singles_from("cosh.wc"));
+}
+
+#[test]
+fn test_tanh() {
+    test_univariate(metal::tanh, core_math::tanh, parse_singles_from("tanh.wc"));
+}
+
+#[test]
+fn test_asinh() {
+    test_univariate(
+        metal::asinh,
+        core_math::asinh,
+        parse_singles_from("asinh.wc"),
+    );
+}
+
+#[test]
+fn test_acosh() {
+    test_univariate(
+        metal::acosh,
+        core_math::acosh,
+        parse_singles_from("acosh.wc"),
+    );
+}
+
+#[test]
+fn test_atanh() {
+    test_univariate(
+        metal::atanh,
+        core_math::atanh,
+        parse_singles_from("atanh.wc"),
+    );
+}
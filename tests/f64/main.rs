@@ -0,0 +1,332 @@
+mod huge;
+use core::num::FpCategory;
+use metallic::f64 as metal;
+use rand::{Rng as _, SeedableRng as _};
+
+/// Semantic identity like `Object.is` in JavaScript
+///
+/// This function works around comparison issues with NaNs and signed zeros.
+/// To be specific, `is(f64::NAN, f64::NAN)` but not `is(0.0, -0.0)`.
+trait Identity {
+    fn is(&self, other: &Self) -> bool;
+}
+
+impl Identity for f64 {
+    fn is(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits() || (self.is_nan() && other.is_nan())
+    }
+}
+
+impl<T: Identity, U: Identity> Identity for (T, U) {
+    fn is(&self, other: &Self) -> bool {
+        self.0.is(&other.0) && self.1.is(&other.1)
+    }
+}
+
+/// Special values with both signs tested for every function
+fn special() -> impl Iterator<Item = f64> + Clone {
+    [
+        0.0,
+        f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        f64::EPSILON,
+        0.5,
+        1.0,
+        2.0,
+        10.0,
+        f64::MAX,
+        // Closest approach to a multiple of π/2
+        6_381_956_970_095_103.0 * 2.0_f64.powi(797),
+        f64::INFINITY,
+        f64::NAN,
+    ]
+    .into_iter()
+    .flat_map(|x| [x, -x])
+}
+
+/// Sample `f64` values for testing
+///
+/// Exhaustive testing is infeasible for `f64`.  Instead, this function returns
+/// special values, random bit patterns, and random values uniformly
+/// distributed in `range`.  The random number generator is seeded so that
+/// failures are reproducible.
+fn samples(range: core::ops::Range<f64>) -> Vec<f64> {
+    const SAMPLES: usize = 1 << 22;
+
+    let rng = &mut rand::rngs::StdRng::seed_from_u64(0x6D65_7461_6C6C_6963);
+    let mut samples: Vec<_> = special().collect();
+    samples.extend((0..SAMPLES).map(|_| f64::from_bits(rng.gen())));
+    samples.extend((0..SAMPLES).map(|_| rng.gen_range(range.clone())));
+    samples
+}
+
+/// Test `samples` where an error is found by `error`
+///
+/// - `error`: function returning `Some` if there is an error
+fn test_samples<T>(samples: impl IntoIterator<Item = T>, error: impl Fn(T) -> Option<()>) {
+    const LIMIT: usize = 250;
+    let count = samples.into_iter().filter_map(error).take(LIMIT).count();
+
+    assert!(
+        count < LIMIT,
+        "Too many (>= {LIMIT}) mismatches!  Aborting...",
+    );
+    assert!(count == 0, "There are {count} mismatches");
+}
+
+/// Check if `f` returns the same result as `g` for sampled `f64` values
+///
+/// By "same result", I mean semantic identity as defined by [`is`].
+fn test_identity<T: Identity + core::fmt::Debug>(
+    f: impl Fn(f64) -> T,
+    g: impl Fn(f64) -> T,
+    range: core::ops::Range<f64>,
+) {
+    test_samples(samples(range), |x| {
+        let f = f(x);
+        let g = g(x);
+
+        (!f.is(&g)).then(|| println!("{x:e}: {f:?} != {g:?}"))
+    });
+}
+
+/// Bivariate version of [`test_identity`]
+///
+/// Every pair of special values is tested.  Other samples are drawn from `x`
+/// and `y` by [`samples`] and paired up in two ways.
+fn test_bivariate(
+    f: impl Fn(f64, f64) -> f64,
+    g: impl Fn(f64, f64) -> f64,
+    x: core::ops::Range<f64>,
+    y: core::ops::Range<f64>,
+) {
+    let x = samples(x);
+    let y = samples(y);
+    let special = special().flat_map(|x| special().map(move |y| (x, y)));
+    let neighbors = x
+        .iter()
+        .step_by(2)
+        .copied()
+        .zip(y.iter().skip(1).step_by(2).copied());
+    let reversed = x.iter().copied().zip(y.iter().copied().rev());
+
+    test_samples(special.chain(neighbors).chain(reversed), |(x, y)| {
+        let f = f(x, y);
+        let g = g(x, y);
+
+        (!f.is(&g)).then(|| println!("{x:e}, {y:e}: {f:e} != {g:e}"))
+    });
+}
+
+#[test]
+fn test_exp() {
+    test_identity(metal::exp, core_math::exp, -746.0..710.0);
+}
+
+#[test]
+fn test_exp2() {
+    test_identity(metal::exp2, core_math::exp2, -1076.0..1025.0);
+}
+
+#[test]
+fn test_exp10() {
+    test_identity(metal::exp10, core_math::exp10, -324.0..309.0);
+}
+
+#[test]
+fn test_exp_m1() {
+    test_identity(metal::exp_m1, core_math::expm1, -40.0..710.0);
+}
+
+#[test]
+fn test_ln() {
+    test_identity(metal::ln, core_math::log, 0.0..4.0);
+}
+
+#[test]
+fn test_ln_1p() {
+    test_identity(metal::ln_1p, core_math::log1p, -1.0..4.0);
+}
+
+#[test]
+fn test_log2() {
+    test_identity(metal::log2, core_math::log2, 0.0..4.0);
+}
+
+#[test]
+fn test_log10() {
+    test_identity(metal::log10, core_math::log10, 0.0..4.0);
+}
+
+#[test]
+fn test_cos() {
+    test_identity(metal::cos, core_math::cos, -10.0..10.0);
+}
+
+#[test]
+fn test_sin() {
+    test_identity(metal::sin, core_math::sin, -10.0..10.0);
+}
+
+#[test]
+fn test_sin_cos() {
+    test_identity(
+        metal::sin_cos,
+        |x| (core_math::sin(x), core_math::cos(x)),
+        -10.0..10.0,
+    );
+}
+
+#[test]
+fn test_tan() {
+    test_identity(metal::tan, core_math::tan, -10.0..10.0);
+}
+
+#[test]
+fn test_acos() {
+    test_identity(metal::acos, core_math::acos, -1.0..1.0);
+}
+
+#[test]
+fn test_asin() {
+    test_identity(metal::asin, core_math::asin, -1.0..1.0);
+}
+
+#[test]
+fn test_atan() {
+    test_identity(metal::atan, core_math::atan, -10.0..10.0);
+}
+
+#[test]
+fn test_atan2() {
+    test_bivariate(metal::atan2, core_math::atan2, -10.0..10.0, -10.0..10.0);
+}
+
+#[test]
+fn test_sinh() {
+    test_identity(metal::sinh, core_math::sinh, -20.0..20.0);
+}
+
+#[test]
+fn test_cosh() {
+    test_identity(metal::cosh, core_math::cosh, -20.0..20.0);
+}
+
+#[test]
+fn test_tanh() {
+    test_identity(metal::tanh, core_math::tanh, -20.0..20.0);
+}
+
+#[test]
+fn test_asinh() {
+    test_identity(metal::asinh, core_math::asinh, -20.0..20.0);
+}
+
+#[test]
+fn test_acosh() {
+    test_identity(metal::acosh, core_math::acosh, 1.0..20.0);
+}
+
+#[test]
+fn test_atanh() {
+    test_identity(metal::atanh, core_math::atanh, -1.0..1.0);
+}
+
+#[test]
+fn test_pow() {
+    test_bivariate(metal::pow, core_math::pow, 0.0..4.0, -100.0..100.0);
+}
+
+#[test]
+fn test_pow_exact() {
+    // Perfect powers of odd numbers, whose powers can be ties
+    let cases = (1..100_u32).step_by(2).flat_map(|w| {
+        (0..4).flat_map(move |k| {
+            (-64..=64).flat_map(move |n| {
+                let x = f64::from(w).powi(1 << k);
+                let y = f64::from(n) / f64::from(1 << k);
+                [-1074, -600, -20, 0, 20, 600].map(|e| (x * 2.0_f64.powi(e), y))
+            })
+        })
+    });
+
+    test_samples(cases, |(x, y)| {
+        let f = metal::pow(x, y);
+        let g = core_math::pow(x, y);
+
+        (!f.is(&g)).then(|| println!("{x:e}, {y:e}: {f:e} != {g:e}"))
+    });
+}
+
+#[test]
+fn test_next_up() {
+    test_identity(metal::next_up, f64::next_up, -10.0..10.0);
+}
+
+#[test]
+fn test_next_down() {
+    test_identity(metal::next_down, f64::next_down, -10.0..10.0);
+}
+
+#[test]
+fn test_round() {
+    test_identity(metal::round, f64::round, -1e6..1e6);
+}
+
+#[test]
+fn test_cbrt() {
+    test_identity(metal::cbrt, core_math::cbrt, -10.0..10.0);
+}
+
+#[test]
+fn test_hypot() {
+    test_bivariate(metal::hypot, core_math::hypot, -10.0..10.0, -10.0..10.0);
+}
+
+#[test]
+fn test_frexp() {
+    test_samples(samples(-10.0..10.0), |x| {
+        let (significand, exponent) = metal::frexp(x);
+
+        let correct = match x.classify() {
+            FpCategory::Nan => significand.is_nan(),
+            FpCategory::Infinite => significand.is(&x),
+            FpCategory::Zero => significand.is(&x) && exponent == 0,
+            _ => {
+                (0.5..1.0).contains(&significand.abs())
+                    && metal::ldexp(significand, exponent).is(&x)
+            }
+        };
+
+        (!correct).then(|| println!("{x:e}: ({significand:e}, {exponent})"))
+    });
+}
+
+#[test]
+fn test_ldexp() {
+    /// Exact 2^n for n in -1074..=1023
+    fn exp2i(n: i32) -> f64 {
+        #[allow(clippy::cast_sign_loss)]
+        match n {
+            ..-1022 => f64::from_bits(1 << (n + 1074)),
+            _ => f64::from_bits(((n + 1023) as u64) << 52),
+        }
+    }
+
+    let x = samples(-10.0..10.0);
+    let n = (-2200..2200).step_by(7);
+
+    test_samples(x.iter().zip(n.cycle()), |(&x, n)| {
+        let f = metal::ldexp(x, n);
+
+        // Scaling by 2^-600 is exact if the result is normal.
+        let g = match n {
+            -1674..-1074 if x.abs() >= exp2i(-422) => x * exp2i(-600) * exp2i(n + 600),
+            -1074..=1023 => x * exp2i(n),
+            1024..=2046 => x * exp2i(1023) * exp2i(n - 1023),
+            _ => return None,
+        };
+
+        (!f.is(&g)).then(|| println!("{x:e}, {n}: {f:e} != {g:e}"))
+    });
+}